@@ -0,0 +1,158 @@
+//! Backoff Module
+//!
+//! This module contains a set of methods to generate the absolute datetimes of the retries of a failed
+//! operation, following a backoff policy within a maximum elapsed time, optionally only within business
+//! hours.
+use time::{Duration, OffsetDateTime};
+
+use crate::DateTimeError;
+#[cfg(feature = "business")]
+use crate::{add_business_time, BusinessHours, HolidayCalendar, Weekend};
+
+/// Represents the policy computing the delay before each retry.
+///
+/// - `Backoff::Exponential`: It indicates delays doubling from `base` after each retry, up to `cap`
+/// - `Backoff::Fixed`: It indicates the same delay before every retry
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Backoff {
+    Exponential { base: Duration, cap: Duration },
+    Fixed(Duration),
+}
+
+impl Backoff {
+    /// Returns the delay before the given retry, counted from 0.
+    fn delay(&self, retry: u32) -> Duration {
+        match *self {
+            Backoff::Exponential { base, cap } => base
+                .checked_mul(2i32.saturating_pow(retry.min(30)))
+                .map_or(cap, |delay| delay.min(cap)),
+            Backoff::Fixed(delay) => delay,
+        }
+    }
+
+    /// Validates that the delays of the policy are positive.
+    fn validate(&self) -> Result<(), DateTimeError> {
+        let delays = match *self {
+            Backoff::Exponential { base, cap } => vec![("base", base), ("cap", cap)],
+            Backoff::Fixed(delay) => vec![("delay", delay)],
+        };
+        match delays.iter().find(|(_, delay)| !delay.is_positive()) {
+            Some((name, delay)) => Err(DateTimeError::InvalidComponent(
+                name,
+                format!("{delay} is not positive"),
+            )),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Generates the datetimes of the retries of an operation which first failed at the given datetime.
+///
+/// ## Arguments
+/// - `start: OffsetDateTime`: The datetime of the first failure.
+/// - `policy: Backoff`: The backoff policy.
+/// - `max_elapsed: Duration`: The maximum time from `start` to the last retry.
+///
+/// ## Returns
+/// - `Ok(Vec<OffsetDateTime>)`: The retry datetimes, in chronological order.
+/// - `Err(DateTimeError)`: If a delay of the policy is not positive.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::{backoff_schedule, Backoff};
+/// use time::{macros::datetime, Duration};
+///
+/// let policy = Backoff::Exponential { base: Duration::minutes(1), cap: Duration::minutes(5) };
+/// let retries = backoff_schedule(datetime!(2024-05-31 12:00 UTC), policy, Duration::minutes(15)).unwrap();
+/// assert_eq!(
+///     retries,
+///     [
+///         datetime!(2024-05-31 12:01 UTC),
+///         datetime!(2024-05-31 12:03 UTC),
+///         datetime!(2024-05-31 12:07 UTC),
+///         datetime!(2024-05-31 12:12 UTC),
+///     ]
+/// );
+/// ```
+pub fn backoff_schedule(
+    start: OffsetDateTime,
+    policy: Backoff,
+    max_elapsed: Duration,
+) -> Result<Vec<OffsetDateTime>, DateTimeError> {
+    schedule(start, policy, max_elapsed, Ok)
+}
+
+/// Generates the datetimes of the retries of an operation, as `backoff_schedule` does, moving the retries
+/// falling outside business hours to the next opening.
+///
+/// Each delay is counted from the previous retry, once moved.
+///
+/// ## Arguments
+/// - `start: OffsetDateTime`: The datetime of the first failure.
+/// - `policy: Backoff`: The backoff policy.
+/// - `max_elapsed: Duration`: The maximum time from `start` to the last retry.
+/// - `hours: &BusinessHours`: The opening hours in which retries happen.
+/// - `weekend: &Weekend`: The weekdays which are not business days.
+/// - `calendar: &HolidayCalendar`: The holidays which are not business days.
+///
+/// ## Returns
+/// - `Ok(Vec<OffsetDateTime>)`: The retry datetimes, expressed in the offset of the business hours.
+/// - `Err(DateTimeError)`: If a delay of the policy is not positive, or a retry falls outside the
+///   supported date range.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::{business_backoff_schedule, Backoff, BusinessHours, HolidayCalendar, Weekend};
+/// use time::{macros::{datetime, offset, time}, Duration};
+///
+/// let hours = BusinessHours::new(time!(9:00), time!(17:00), offset!(UTC)).unwrap();
+/// let retries = business_backoff_schedule(
+///     datetime!(2025-05-09 16:30 UTC),
+///     Backoff::Fixed(Duration::hours(1)),
+///     Duration::days(3),
+///     &hours,
+///     &Weekend::default(),
+///     &HolidayCalendar::new(),
+/// )
+/// .unwrap();
+/// // The Friday 17:30 retry moves to Monday at opening.
+/// assert_eq!(retries[0], datetime!(2025-05-12 9:00 UTC));
+/// assert_eq!(retries[1], datetime!(2025-05-12 10:00 UTC));
+/// ```
+#[cfg(feature = "business")]
+pub fn business_backoff_schedule(
+    start: OffsetDateTime,
+    policy: Backoff,
+    max_elapsed: Duration,
+    hours: &BusinessHours,
+    weekend: &Weekend,
+    calendar: &HolidayCalendar,
+) -> Result<Vec<OffsetDateTime>, DateTimeError> {
+    schedule(start, policy, max_elapsed, |retry| {
+        add_business_time(retry, Duration::ZERO, hours, weekend, calendar)
+    })
+}
+
+/// Generates the retries within the maximum elapsed time, adjusting each of them.
+fn schedule(
+    start: OffsetDateTime,
+    policy: Backoff,
+    max_elapsed: Duration,
+    adjust: impl Fn(OffsetDateTime) -> Result<OffsetDateTime, DateTimeError>,
+) -> Result<Vec<OffsetDateTime>, DateTimeError> {
+    policy.validate()?;
+    let mut retries = Vec::new();
+    let mut previous = start;
+    for retry in 0.. {
+        let Some(next) = previous.checked_add(policy.delay(retry)) else {
+            break;
+        };
+        let next = adjust(next)?;
+        if next - start > max_elapsed {
+            break;
+        }
+        retries.push(next);
+        previous = next;
+    }
+    Ok(retries)
+}
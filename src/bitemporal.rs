@@ -0,0 +1,114 @@
+//! Bitemporal Module
+//!
+//! This module contains the `BitemporalRange` type and a set of methods to query slowly-changing
+//! reference data along both the valid time and the recorded (transaction) time.
+use time::OffsetDateTime;
+
+use crate::DateTimeRange;
+
+/// Represents the validity of a record along two time axes.
+///
+/// - `valid`: The range during which the record is true in the real world.
+/// - `recorded`: The range during which the record was the current knowledge of the system, from its
+///   insertion to its supersession.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::{BitemporalRange, DateTimeRange};
+/// use time::macros::datetime;
+///
+/// let range = |start, end| DateTimeRange::new(start, end).unwrap();
+/// let record = BitemporalRange {
+///     valid: range(datetime!(2024-01-01 0:00 UTC), datetime!(2024-12-31 23:59:59.999999999 UTC)),
+///     recorded: range(datetime!(2024-02-15 9:00 UTC), datetime!(2024-06-30 18:00 UTC)),
+/// };
+/// assert!(record.contains(datetime!(2024-01-10 0:00 UTC), datetime!(2024-03-01 0:00 UTC)));
+/// assert!(!record.contains(datetime!(2024-01-10 0:00 UTC), datetime!(2024-02-01 0:00 UTC)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BitemporalRange {
+    pub valid: DateTimeRange,
+    pub recorded: DateTimeRange,
+}
+
+impl BitemporalRange {
+    /// Creates a bitemporal range from its valid and recorded ranges.
+    pub fn new(valid: DateTimeRange, recorded: DateTimeRange) -> Self {
+        Self { valid, recorded }
+    }
+
+    /// Checks whether the record was true at `valid_at`, according to the knowledge at `recorded_at`.
+    pub fn contains(&self, valid_at: OffsetDateTime, recorded_at: OffsetDateTime) -> bool {
+        self.valid.contains(valid_at) && self.recorded.contains(recorded_at)
+    }
+
+    /// Checks whether the two records overlap along both time axes.
+    ///
+    /// Two versions of the same record overlapping along both axes are conflicting: the system would
+    /// have known two different values for the same instant at the same time.
+    pub fn overlaps(&self, other: &BitemporalRange) -> bool {
+        self.valid.overlaps(&other.valid) && self.recorded.overlaps(&other.recorded)
+    }
+
+    /// Returns the bitemporal range shared by the two records, if any.
+    pub fn intersection(&self, other: &BitemporalRange) -> Option<BitemporalRange> {
+        Some(BitemporalRange {
+            valid: self.valid.intersection(&other.valid)?,
+            recorded: self.recorded.intersection(&other.recorded)?,
+        })
+    }
+
+    /// Returns the valid range of the record as known at `recorded_at`, if it was recorded by then and
+    /// not yet superseded.
+    pub fn valid_as_of(&self, recorded_at: OffsetDateTime) -> Option<DateTimeRange> {
+        self.recorded.contains(recorded_at).then_some(self.valid)
+    }
+}
+
+/// Finds the record true at `valid_at`, according to the knowledge at `recorded_at`.
+///
+/// The function answers "what did we believe on `recorded_at` the value was on `valid_at`", e.g. to
+/// reproduce a report with the instrument metadata known when it was produced. When several records
+/// match, which only happens with conflicting versions, the most recently recorded one is returned.
+///
+/// ## Arguments
+/// - `records: &[(BitemporalRange, T)]`: The versions of the record, in any order, with their values.
+/// - `valid_at: OffsetDateTime`: The instant of the real world being queried.
+/// - `recorded_at: OffsetDateTime`: The instant of the knowledge being queried.
+///
+/// ## Returns
+/// - `Option<&T>`: The value of the matching record, or `None` if no record matches.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::{bitemporal_as_of, BitemporalRange, DateTimeRange};
+/// use time::macros::datetime;
+///
+/// let range = |start, end| DateTimeRange::new(start, end).unwrap();
+/// let valid = range(datetime!(2024-01-01 0:00 UTC), datetime!(9999-12-31 0:00 UTC));
+/// let records = [
+///     (
+///         BitemporalRange::new(valid, range(datetime!(2024-01-01 0:00 UTC), datetime!(2024-03-01 0:00 UTC))),
+///         "ACME Corp",
+///     ),
+///     (
+///         BitemporalRange::new(valid, range(datetime!(2024-03-01 0:00 UTC), datetime!(9999-12-31 0:00 UTC))),
+///         "ACME Corporation",
+///     ),
+/// ];
+/// let name = bitemporal_as_of(&records, datetime!(2024-01-15 0:00 UTC), datetime!(2024-02-01 0:00 UTC));
+/// assert_eq!(name, Some(&"ACME Corp"));
+/// let name = bitemporal_as_of(&records, datetime!(2024-01-15 0:00 UTC), datetime!(2024-05-01 0:00 UTC));
+/// assert_eq!(name, Some(&"ACME Corporation"));
+/// ```
+pub fn bitemporal_as_of<T>(
+    records: &[(BitemporalRange, T)],
+    valid_at: OffsetDateTime,
+    recorded_at: OffsetDateTime,
+) -> Option<&T> {
+    records
+        .iter()
+        .filter(|(range, _)| range.contains(valid_at, recorded_at))
+        .max_by_key(|(range, _)| range.recorded.start())
+        .map(|(_, value)| value)
+}
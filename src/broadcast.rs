@@ -0,0 +1,169 @@
+//! Broadcast Calendar Module
+//!
+//! This module contains the `BroadcastPeriod` type, which maps dates to and from the periods of the
+//! standard broadcast calendar used by the media industry. Broadcast weeks run from Monday to Sunday,
+//! broadcast months end on the last Sunday of the calendar month, and week 1 of a broadcast year is
+//! the week containing January 1, so broadcast years have 52 or 53 weeks.
+use time::{Date, Duration, Month};
+
+use crate::{
+    fiscal::{first_of_month, months_since_year0},
+    period::days_range,
+    DateTimeError, DateTimeRange,
+};
+
+/// Represents a period of the broadcast calendar.
+///
+/// - `BroadcastPeriod::Year`: It indicates a broadcast year
+/// - `BroadcastPeriod::Quarter`: It indicates a quarter (from 1 to 4) of a broadcast year
+/// - `BroadcastPeriod::Month`: It indicates a broadcast month
+/// - `BroadcastPeriod::Week`: It indicates a week (from 1 to 52 or 53) of a broadcast year
+///
+/// ## Example
+/// ```rust
+/// use date_utils::BroadcastPeriod;
+/// use time::{macros::date, Month};
+///
+/// // December 30, 2024 is a Monday: it starts broadcast January 2025.
+/// assert_eq!(
+///     BroadcastPeriod::month_of(date!(2024 - 12 - 30)),
+///     BroadcastPeriod::Month(2025, Month::January)
+/// );
+/// assert_eq!(
+///     BroadcastPeriod::Month(2025, Month::January).dates().unwrap(),
+///     (date!(2024 - 12 - 30), date!(2025 - 01 - 26))
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BroadcastPeriod {
+    Year(i32),
+    Quarter(i32, u8),
+    Month(i32, Month),
+    Week(i32, u8),
+}
+
+impl BroadcastPeriod {
+    /// Returns the broadcast year containing the given date.
+    pub fn year_of(date: Date) -> Self {
+        BroadcastPeriod::Year(week_end(date).year())
+    }
+
+    /// Returns the broadcast quarter containing the given date.
+    pub fn quarter_of(date: Date) -> Self {
+        let end = week_end(date);
+        BroadcastPeriod::Quarter(end.year(), (end.month() as u8 - 1) / 3 + 1)
+    }
+
+    /// Returns the broadcast month containing the given date.
+    pub fn month_of(date: Date) -> Self {
+        let end = week_end(date);
+        BroadcastPeriod::Month(end.year(), end.month())
+    }
+
+    /// Returns the broadcast week containing the given date.
+    pub fn week_of(date: Date) -> Self {
+        let year = week_end(date).year();
+        let week = match year_start(year) {
+            Ok(start) => (date - start).whole_weeks() + 1,
+            // The year starts before the supported date range (i.e. in the first week of year -9999).
+            Err(_) => 1,
+        };
+        BroadcastPeriod::Week(year, week as u8)
+    }
+
+    /// Returns the number of weeks (52 or 53) of the broadcast year.
+    ///
+    /// ## Returns
+    /// - `Ok(u8)`: The number of weeks of the year.
+    /// - `Err(DateTimeError)`: If the year is out of the supported date range.
+    pub fn weeks_in_year(year: i32) -> Result<u8, DateTimeError> {
+        Ok((year_start(next_year(year)?)? - year_start(year)?).whole_weeks() as u8)
+    }
+
+    /// Returns the broadcast year of the period.
+    pub fn year(&self) -> i32 {
+        match self {
+            BroadcastPeriod::Year(year)
+            | BroadcastPeriod::Quarter(year, _)
+            | BroadcastPeriod::Month(year, _)
+            | BroadcastPeriod::Week(year, _) => *year,
+        }
+    }
+
+    /// Returns the first and last calendar dates of the broadcast period.
+    ///
+    /// ## Returns
+    /// - `Ok((Date, Date))`: The first date (a Monday) and the last date (a Sunday) of the period.
+    /// - `Err(DateTimeError)`: If the quarter is not between 1 and 4, the week does not exist in the
+    ///   year, or the period is out of the supported date range.
+    pub fn dates(&self) -> Result<(Date, Date), DateTimeError> {
+        let (first, next) = match *self {
+            BroadcastPeriod::Year(year) => (year_start(year)?, year_start(next_year(year)?)?),
+            BroadcastPeriod::Quarter(year, quarter @ 1..=4) => {
+                let months = months_since_year0(year, 3 * (quarter as i32 - 1))?;
+                (month_start(months)?, month_start(months_after(months, 3)?)?)
+            }
+            BroadcastPeriod::Quarter(_, quarter) => {
+                return Err(DateTimeError::InvalidTimeComponent(quarter.to_string()));
+            }
+            BroadcastPeriod::Month(year, month) => {
+                let months = months_since_year0(year, month as i32 - 1)?;
+                (month_start(months)?, month_start(months_after(months, 1)?)?)
+            }
+            BroadcastPeriod::Week(year, week) => {
+                if week == 0 || week > Self::weeks_in_year(year)? {
+                    return Err(DateTimeError::InvalidTimeComponent(week.to_string()));
+                }
+                let first = year_start(year)? + Duration::weeks(week as i64 - 1);
+                (first, first + Duration::weeks(1))
+            }
+        };
+        Ok((first, next.previous_day().unwrap_or(next)))
+    }
+
+    /// Expands the broadcast period into the range from its first instant to its last instant, in UTC.
+    ///
+    /// ## Returns
+    /// - `Ok(DateTimeRange)`: The range covered by the period.
+    /// - `Err(DateTimeError)`: If the period is invalid or out of the supported date range.
+    pub fn range(&self) -> Result<DateTimeRange, DateTimeError> {
+        let (first, last) = self.dates()?;
+        Ok(days_range(first, last))
+    }
+}
+
+/// Returns the Sunday ending the week of the given date, whose calendar month and year are the
+/// broadcast month and year of the date.
+fn week_end(date: Date) -> Date {
+    let days = 6 - date.weekday().number_days_from_monday() as i64;
+    date.checked_add(Duration::days(days)).unwrap_or(Date::MAX)
+}
+
+/// Returns the first day of the broadcast year.
+fn year_start(year: i32) -> Result<Date, DateTimeError> {
+    month_start(months_since_year0(year, 0)?)
+}
+
+/// Returns the year following the given one, checked since the years of periods are not validated.
+fn next_year(year: i32) -> Result<i32, DateTimeError> {
+    year.checked_add(1)
+        .ok_or_else(|| DateTimeError::OutOfRange(format!("year {year} is out of range")))
+}
+
+/// Adds a number of months to a count of months since January of year 0, checked.
+fn months_after(months: i32, count: i32) -> Result<i32, DateTimeError> {
+    months
+        .checked_add(count)
+        .ok_or_else(|| DateTimeError::OutOfRange(format!("{months} months is out of range")))
+}
+
+/// Returns the first day (the Monday of the week containing the 1st) of the broadcast month, counted
+/// in months since January of year 0.
+fn month_start(months: i32) -> Result<Date, DateTimeError> {
+    let first = first_of_month(months)?;
+    first
+        .checked_sub(Duration::days(
+            first.weekday().number_days_from_monday() as i64
+        ))
+        .ok_or_else(|| DateTimeError::OutOfRange(first.to_string()))
+}
@@ -0,0 +1,173 @@
+//! DateTime Builder Module
+//!
+//! This module contains the `DateTimeBuilder` type, which assembles a datetime from separate components
+//! (e.g. the columns of a database row) and validates each of them, and constructors of dates from ISO
+//! week dates and ordinal dates.
+use time::{Date, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset, Weekday};
+
+use crate::DateTimeError;
+
+/// Builds an `OffsetDateTime` from its components.
+///
+/// The year, month and day are required. The time defaults to midnight and the offset to UTC. Every
+/// component is validated when building, and the error names the invalid component.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::{DateTimeBuilder, DateTimeError};
+/// use time::macros::datetime;
+///
+/// let datetime = DateTimeBuilder::new()
+///     .year(2024)
+///     .month(5)
+///     .day(31)
+///     .hour(12)
+///     .offset_seconds(7200)
+///     .build()
+///     .unwrap();
+/// assert_eq!(datetime, datetime!(2024-05-31 12:00 +2));
+///
+/// let err = DateTimeBuilder::new().year(2024).month(4).day(31).build().unwrap_err();
+/// assert!(matches!(err, DateTimeError::InvalidComponent("day", _)));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct DateTimeBuilder {
+    year: Option<i32>,
+    month: Option<u8>,
+    day: Option<u8>,
+    hour: u8,
+    minute: u8,
+    second: u8,
+    nanosecond: u32,
+    offset_seconds: i32,
+}
+
+impl DateTimeBuilder {
+    /// Creates a builder with no date, a midnight time and a UTC offset.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the year.
+    pub fn year(mut self, year: i32) -> Self {
+        self.year = Some(year);
+        self
+    }
+
+    /// Sets the month, from 1 to 12.
+    pub fn month(mut self, month: u8) -> Self {
+        self.month = Some(month);
+        self
+    }
+
+    /// Sets the day of month, from 1 to 31.
+    pub fn day(mut self, day: u8) -> Self {
+        self.day = Some(day);
+        self
+    }
+
+    /// Sets the hour, from 0 to 23.
+    pub fn hour(mut self, hour: u8) -> Self {
+        self.hour = hour;
+        self
+    }
+
+    /// Sets the minute, from 0 to 59.
+    pub fn minute(mut self, minute: u8) -> Self {
+        self.minute = minute;
+        self
+    }
+
+    /// Sets the second, from 0 to 59.
+    pub fn second(mut self, second: u8) -> Self {
+        self.second = second;
+        self
+    }
+
+    /// Sets the nanosecond, from 0 to 999,999,999.
+    pub fn nanosecond(mut self, nanosecond: u32) -> Self {
+        self.nanosecond = nanosecond;
+        self
+    }
+
+    /// Sets the offset from UTC, in seconds.
+    pub fn offset_seconds(mut self, offset_seconds: i32) -> Self {
+        self.offset_seconds = offset_seconds;
+        self
+    }
+
+    /// Builds the datetime from the components.
+    ///
+    /// ## Returns
+    /// - `Ok(OffsetDateTime)`: The datetime.
+    /// - `Err(DateTimeError)`: If the year, month or day is missing, or if a component is out of range
+    ///   (`DateTimeError::InvalidComponent` names it), or if the offset is invalid.
+    pub fn build(&self) -> Result<OffsetDateTime, DateTimeError> {
+        let missing = |name| DateTimeError::InvalidComponent(name, "it is required".to_string());
+        let invalid = |err: time::error::ComponentRange| {
+            DateTimeError::InvalidComponent(err.name(), err.to_string())
+        };
+        let year = self.year.ok_or_else(|| missing("year"))?;
+        let month = self.month.ok_or_else(|| missing("month"))?;
+        let day = self.day.ok_or_else(|| missing("day"))?;
+        let month = Month::try_from(month).map_err(invalid)?;
+        let date = Date::from_calendar_date(year, month, day).map_err(invalid)?;
+        let time = Time::from_hms_nano(self.hour, self.minute, self.second, self.nanosecond)
+            .map_err(invalid)?;
+        let offset = UtcOffset::from_whole_seconds(self.offset_seconds)
+            .map_err(|err| DateTimeError::InvalidOffset(self.offset_seconds, err.to_string()))?;
+        Ok(PrimitiveDateTime::new(date, time).assume_offset(offset))
+    }
+}
+
+/// Builds a date from its ISO week date (ISO year, week and weekday).
+///
+/// ## Arguments
+/// - `year: i32`: The ISO week-numbering year, which differs from the calendar year around January 1.
+/// - `week: u8`: The ISO week, from 1 to 52 or 53 depending on the year.
+/// - `weekday: Weekday`: The day of the week.
+///
+/// ## Returns
+/// - `Ok(Date)`: The date.
+/// - `Err(DateTimeError)`: If the year or the week is out of range (`DateTimeError::InvalidComponent`
+///   names it).
+///
+/// ## Example
+/// ```rust
+/// use date_utils::{from_iso_week_date, DateTimeError};
+/// use time::{macros::date, Weekday};
+///
+/// assert_eq!(from_iso_week_date(2025, 1, Weekday::Monday).unwrap(), date!(2024 - 12 - 30));
+/// assert!(matches!(
+///     from_iso_week_date(2024, 53, Weekday::Monday).unwrap_err(),
+///     DateTimeError::InvalidComponent("week", _)
+/// ));
+/// ```
+pub fn from_iso_week_date(year: i32, week: u8, weekday: Weekday) -> Result<Date, DateTimeError> {
+    Date::from_iso_week_date(year, week, weekday)
+        .map_err(|err| DateTimeError::InvalidComponent(err.name(), err.to_string()))
+}
+
+/// Builds a date from its ordinal date (year and day of year).
+///
+/// ## Arguments
+/// - `year: i32`: The year.
+/// - `day_of_year: u16`: The day of the year, from 1 to 365 or 366 in leap years.
+///
+/// ## Returns
+/// - `Ok(Date)`: The date.
+/// - `Err(DateTimeError)`: If the year or the day of year is out of range
+///   (`DateTimeError::InvalidComponent` names it).
+///
+/// ## Example
+/// ```rust
+/// use date_utils::from_year_ordinal;
+/// use time::macros::date;
+///
+/// assert_eq!(from_year_ordinal(2024, 366).unwrap(), date!(2024 - 12 - 31));
+/// assert!(from_year_ordinal(2025, 366).is_err());
+/// ```
+pub fn from_year_ordinal(year: i32, day_of_year: u16) -> Result<Date, DateTimeError> {
+    Date::from_ordinal_date(year, day_of_year)
+        .map_err(|err| DateTimeError::InvalidComponent(err.name(), err.to_string()))
+}
@@ -0,0 +1,445 @@
+//! Business Days Module
+//!
+//! This module contains a set of methods to handle business days and business hours (weekends, holiday
+//! calendars, opening hours).
+use std::{collections::BTreeMap, ops::Bound};
+
+use time::{Date, Duration, OffsetDateTime, Time, UtcOffset, Weekday};
+
+use crate::{period::period_bounds, DateTimeError, Frequency};
+
+/// Represents the set of holidays observed by a market, a country or an organization, optionally named.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::HolidayCalendar;
+/// use time::macros::date;
+///
+/// let mut calendar: HolidayCalendar = [date!(2025 - 12 - 26)].into_iter().collect();
+/// calendar.add_named_holiday(date!(2025 - 12 - 25), "Christmas Day");
+/// assert!(calendar.is_holiday(date!(2025 - 12 - 25)));
+/// assert_eq!(calendar.holiday_name(date!(2025 - 12 - 25)), Some("Christmas Day"));
+/// assert_eq!(calendar.holiday_name(date!(2025 - 12 - 26)), None);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HolidayCalendar {
+    holidays: BTreeMap<Date, Option<String>>,
+}
+
+impl HolidayCalendar {
+    /// Creates an empty holiday calendar.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an unnamed holiday to the calendar, keeping the name of the date if it is already named.
+    pub fn add_holiday(&mut self, date: Date) {
+        self.holidays.entry(date).or_insert(None);
+    }
+
+    /// Adds a named holiday to the calendar, replacing the name of the date if it is already named.
+    pub fn add_named_holiday(&mut self, date: Date, name: impl Into<String>) {
+        self.holidays.insert(date, Some(name.into()));
+    }
+
+    /// Checks whether the given date is a holiday.
+    pub fn is_holiday(&self, date: Date) -> bool {
+        self.holidays.contains_key(&date)
+    }
+
+    /// Returns the name of the holiday falling on the given date, if the date is a named holiday.
+    pub fn holiday_name(&self, date: Date) -> Option<&str> {
+        self.holidays.get(&date)?.as_deref()
+    }
+
+    /// Returns the next holidays strictly after the given date, with their names.
+    ///
+    /// ## Arguments
+    /// - `after: Date`: The date after which holidays are listed.
+    /// - `n: usize`: The maximum number of holidays to list.
+    ///
+    /// ## Returns
+    /// - `Vec<(Date, Option<&str>)>`: Up to `n` holidays in chronological order, with their names if any.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use date_utils::HolidayCalendar;
+    /// use time::macros::date;
+    ///
+    /// let calendar: HolidayCalendar = [
+    ///     (date!(2025 - 12 - 25), "Christmas Day"),
+    ///     (date!(2025 - 12 - 26), "Boxing Day"),
+    ///     (date!(2026 - 01 - 01), "New Year's Day"),
+    /// ]
+    /// .into_iter()
+    /// .collect();
+    /// assert_eq!(
+    ///     calendar.upcoming_holidays(date!(2025 - 12 - 25), 2),
+    ///     [(date!(2025 - 12 - 26), Some("Boxing Day")), (date!(2026 - 01 - 01), Some("New Year's Day"))]
+    /// );
+    /// ```
+    pub fn upcoming_holidays(&self, after: Date, n: usize) -> Vec<(Date, Option<&str>)> {
+        self.holidays
+            .range((Bound::Excluded(after), Bound::Unbounded))
+            .take(n)
+            .map(|(date, name)| (*date, name.as_deref()))
+            .collect()
+    }
+}
+
+impl FromIterator<Date> for HolidayCalendar {
+    fn from_iter<I: IntoIterator<Item = Date>>(iter: I) -> Self {
+        Self {
+            holidays: iter.into_iter().map(|date| (date, None)).collect(),
+        }
+    }
+}
+
+impl<S: Into<String>> FromIterator<(Date, S)> for HolidayCalendar {
+    fn from_iter<I: IntoIterator<Item = (Date, S)>>(iter: I) -> Self {
+        Self {
+            holidays: iter
+                .into_iter()
+                .map(|(date, name)| (date, Some(name.into())))
+                .collect(),
+        }
+    }
+}
+
+/// Represents the weekdays which are not business days.
+///
+/// - `Weekend::SATURDAY_SUNDAY`: The default weekend
+/// - `Weekend::FRIDAY_SATURDAY`: The weekend observed in several Middle Eastern countries
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Weekend {
+    days: u8,
+}
+
+impl Weekend {
+    pub const SATURDAY_SUNDAY: Weekend = Weekend { days: 0b110_0000 };
+    pub const FRIDAY_SATURDAY: Weekend = Weekend { days: 0b011_0000 };
+
+    /// Creates a weekend from the given weekdays.
+    ///
+    /// ## Returns
+    /// - `Ok(Weekend)`: The weekend made of the given weekdays.
+    /// - `Err(DateTimeError)`: If every weekday is part of the weekend.
+    pub fn new(weekdays: &[Weekday]) -> Result<Self, DateTimeError> {
+        let days = weekdays
+            .iter()
+            .fold(0, |days, weekday| days | weekday_bit(*weekday));
+        if days == 0b111_1111 {
+            return Err(DateTimeError::InvalidBusinessHours(
+                "the weekend cannot contain every weekday".to_string(),
+            ));
+        }
+        Ok(Self { days })
+    }
+
+    /// Checks whether the given weekday is part of the weekend.
+    pub fn contains(&self, weekday: Weekday) -> bool {
+        self.days & weekday_bit(weekday) != 0
+    }
+}
+
+impl Default for Weekend {
+    fn default() -> Self {
+        Self::SATURDAY_SUNDAY
+    }
+}
+
+fn weekday_bit(weekday: Weekday) -> u8 {
+    1 << weekday.number_days_from_monday()
+}
+
+/// Represents the daily opening hours of a business, expressed in a given offset.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::BusinessHours;
+/// use time::{macros::{offset, time}, Duration};
+///
+/// let hours = BusinessHours::new(time!(9:00), time!(17:00), offset!(+1)).unwrap();
+/// assert_eq!(hours.daily_duration(), Duration::hours(8));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BusinessHours {
+    open: Time,
+    close: Time,
+    offset: UtcOffset,
+}
+
+impl BusinessHours {
+    /// Creates the opening hours from the opening and closing times.
+    ///
+    /// ## Returns
+    /// - `Ok(BusinessHours)`: The opening hours.
+    /// - `Err(DateTimeError)`: If the opening time does not precede the closing time.
+    pub fn new(open: Time, close: Time, offset: UtcOffset) -> Result<Self, DateTimeError> {
+        if open >= close {
+            return Err(DateTimeError::InvalidBusinessHours(format!(
+                "opening time '{open}' must precede closing time '{close}'"
+            )));
+        }
+        Ok(Self {
+            open,
+            close,
+            offset,
+        })
+    }
+
+    /// Returns the opening time.
+    pub fn open(&self) -> Time {
+        self.open
+    }
+
+    /// Returns the closing time.
+    pub fn close(&self) -> Time {
+        self.close
+    }
+
+    /// Returns the offset in which opening and closing times are expressed.
+    pub fn offset(&self) -> UtcOffset {
+        self.offset
+    }
+
+    /// Returns the duration of a business day.
+    pub fn daily_duration(&self) -> Duration {
+        self.close - self.open
+    }
+
+    fn opening_at(&self, date: Date) -> OffsetDateTime {
+        OffsetDateTime::new_in_offset(date, self.open, self.offset)
+    }
+
+    fn closing_at(&self, date: Date) -> OffsetDateTime {
+        OffsetDateTime::new_in_offset(date, self.close, self.offset)
+    }
+}
+
+/// Checks whether the given date is a business day.
+///
+/// A date is a business day if it is neither part of the weekend nor a holiday.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::{is_business_day, HolidayCalendar, Weekend};
+/// use time::macros::date;
+///
+/// let calendar = HolidayCalendar::new();
+/// assert!(is_business_day(date!(2025 - 05 - 09), &Weekend::default(), &calendar));
+/// assert!(!is_business_day(date!(2025 - 05 - 10), &Weekend::default(), &calendar));
+/// ```
+pub fn is_business_day(date: Date, weekend: &Weekend, calendar: &HolidayCalendar) -> bool {
+    !weekend.contains(date.weekday()) && !calendar.is_holiday(date)
+}
+
+/// Adds a number of business days to the given date.
+///
+/// The function moves forward (positive `days`) or backward (negative `days`) skipping weekends and
+/// holidays. Adding zero days returns the date itself, even if it is not a business day.
+///
+/// ## Arguments
+/// - `date: Date`: The starting date.
+/// - `days: i64`: The number of business days to add.
+/// - `weekend: &Weekend`: The weekdays which are not business days.
+/// - `calendar: &HolidayCalendar`: The holidays which are not business days.
+///
+/// ## Returns
+/// - `Ok(Date)`: The resulting business day.
+/// - `Err(DateTimeError)`: If the result falls outside the supported date range.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::{add_business_days, HolidayCalendar, Weekend};
+/// use time::macros::date;
+///
+/// let date = add_business_days(date!(2025 - 05 - 09), 1, &Weekend::default(), &HolidayCalendar::new());
+/// assert_eq!(date.unwrap(), date!(2025 - 05 - 12));
+/// ```
+pub fn add_business_days(
+    date: Date,
+    days: i64,
+    weekend: &Weekend,
+    calendar: &HolidayCalendar,
+) -> Result<Date, DateTimeError> {
+    let mut result = date;
+    let mut remaining = days.unsigned_abs();
+    while remaining > 0 {
+        result = if days > 0 {
+            result.next_day()
+        } else {
+            result.previous_day()
+        }
+        .ok_or_else(|| DateTimeError::OutOfRange(format!("{date} + {days} business days")))?;
+        if is_business_day(result, weekend, calendar) {
+            remaining -= 1;
+        }
+    }
+    Ok(result)
+}
+
+/// Computes the business time elapsed between two datetimes.
+///
+/// The function sums the portions of the `[start, end]` interval falling within the opening hours of
+/// business days. It returns a zero duration if `end` is not after `start`.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::{business_time_between, BusinessHours, HolidayCalendar, Weekend};
+/// use time::{macros::{datetime, offset, time}, Duration};
+///
+/// let hours = BusinessHours::new(time!(9:00), time!(17:00), offset!(UTC)).unwrap();
+/// let elapsed = business_time_between(
+///     datetime!(2025-05-09 16:00 UTC),
+///     datetime!(2025-05-12 10:00 UTC),
+///     &hours,
+///     &Weekend::default(),
+///     &HolidayCalendar::new(),
+/// );
+/// assert_eq!(elapsed, Duration::hours(2));
+/// ```
+pub fn business_time_between(
+    start: OffsetDateTime,
+    end: OffsetDateTime,
+    hours: &BusinessHours,
+    weekend: &Weekend,
+    calendar: &HolidayCalendar,
+) -> Duration {
+    if end <= start {
+        return Duration::ZERO;
+    }
+    let start = start.to_offset(hours.offset);
+    let end = end.to_offset(hours.offset);
+    let mut total = Duration::ZERO;
+    let mut date = start.date();
+    while date <= end.date() {
+        if is_business_day(date, weekend, calendar) {
+            let from = hours.opening_at(date).max(start);
+            let to = hours.closing_at(date).min(end);
+            if to > from {
+                total += to - from;
+            }
+        }
+        match date.next_day() {
+            Some(next) => date = next,
+            None => break,
+        }
+    }
+    total
+}
+
+/// Adds an amount of business time to the given datetime.
+///
+/// The function consumes the duration only within the opening hours of business days. If the starting
+/// datetime is outside opening hours, counting starts at the next opening.
+///
+/// ## Returns
+/// - `Ok(OffsetDateTime)`: The resulting datetime, expressed in the offset of the business hours.
+/// - `Err(DateTimeError)`: If the result falls outside the supported date range.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::{add_business_time, BusinessHours, HolidayCalendar, Weekend};
+/// use time::{macros::{datetime, offset, time}, Duration};
+///
+/// let hours = BusinessHours::new(time!(9:00), time!(17:00), offset!(UTC)).unwrap();
+/// let deadline = add_business_time(
+///     datetime!(2025-05-09 16:00 UTC),
+///     Duration::hours(2),
+///     &hours,
+///     &Weekend::default(),
+///     &HolidayCalendar::new(),
+/// );
+/// assert_eq!(deadline.unwrap(), datetime!(2025-05-12 10:00 UTC));
+/// ```
+pub fn add_business_time(
+    start: OffsetDateTime,
+    duration: Duration,
+    hours: &BusinessHours,
+    weekend: &Weekend,
+    calendar: &HolidayCalendar,
+) -> Result<OffsetDateTime, DateTimeError> {
+    let start = start.to_offset(hours.offset);
+    let mut remaining = duration.max(Duration::ZERO);
+    let mut date = start.date();
+    loop {
+        if is_business_day(date, weekend, calendar) {
+            let from = hours.opening_at(date).max(start);
+            let to = hours.closing_at(date);
+            if from < to {
+                if remaining <= to - from {
+                    return Ok(from + remaining);
+                }
+                remaining -= to - from;
+            }
+        }
+        date = date.next_day().ok_or_else(|| {
+            DateTimeError::OutOfRange(format!("{start} + {duration} of business time"))
+        })?;
+    }
+}
+
+/// Generates the last business day of each period between two dates (both included).
+///
+/// The function walks the periods of the given frequency overlapping the two dates, and returns the last
+/// business day of each of them, when it falls between the two dates. Periods without any business day
+/// are skipped. This is the observation index used by most monthly and quarterly economic series.
+///
+/// ## Arguments
+/// - `start: Date`: The first date of the series.
+/// - `end: Date`: The last date of the series.
+/// - `frequency: Frequency`: The frequency of the observations (e.g. `Frequency::Monthly`).
+/// - `weekend: &Weekend`: The weekdays which are not business days.
+/// - `calendar: &HolidayCalendar`: The holidays which are not business days.
+///
+/// ## Returns
+/// - `Ok(Vec<Date>)`: The observation dates, in chronological order.
+/// - `Err(DateTimeError)`: If a period falls outside the supported date range.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::{period_end_business_days, Frequency, HolidayCalendar, Weekend};
+/// use time::macros::date;
+///
+/// let dates = period_end_business_days(
+///     date!(2024 - 01 - 01),
+///     date!(2024 - 12 - 31),
+///     Frequency::Quarterly,
+///     &Weekend::default(),
+///     &HolidayCalendar::new(),
+/// )
+/// .unwrap();
+/// assert_eq!(
+///     dates,
+///     vec![date!(2024 - 03 - 29), date!(2024 - 06 - 28), date!(2024 - 09 - 30), date!(2024 - 12 - 31)]
+/// );
+/// ```
+pub fn period_end_business_days(
+    start: Date,
+    end: Date,
+    frequency: Frequency,
+    weekend: &Weekend,
+    calendar: &HolidayCalendar,
+) -> Result<Vec<Date>, DateTimeError> {
+    let mut dates = Vec::new();
+    let mut date = start;
+    while date <= end {
+        let (first, last) = period_bounds(frequency, date)
+            .ok_or_else(|| DateTimeError::OutOfRange(format!("{frequency:?} period of {date}")))?;
+        let observation = (0..)
+            .map_while(|days| last.checked_sub(Duration::days(days)))
+            .take_while(|date| *date >= first)
+            .find(|date| is_business_day(*date, weekend, calendar));
+        if let Some(observation) = observation
+            && (start..=end).contains(&observation)
+        {
+            dates.push(observation);
+        }
+        match last.next_day() {
+            Some(next) => date = next,
+            None => break,
+        }
+    }
+    Ok(dates)
+}
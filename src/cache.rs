@@ -0,0 +1,82 @@
+//! Cache Module
+//!
+//! This module contains a set of methods to compute the expiry of cached values, optionally jittered to
+//! spread refreshes over time, and to check their staleness.
+use time::{Duration, OffsetDateTime};
+
+#[cfg(feature = "rand")]
+use crate::{stats::duration_from_nanos, DateTimeError, Rng};
+
+/// Computes the expiry of a value cached now, shortened by a random fraction of its time-to-live.
+///
+/// The time-to-live is reduced by up to `jitter_fraction` of itself, so values cached together are not
+/// all refreshed at the same instant, and the expiry is never later than `now + ttl`.
+///
+/// ## Arguments
+/// - `now: OffsetDateTime`: The instant the value is cached.
+/// - `ttl: Duration`: The time-to-live of the value.
+/// - `jitter_fraction: f64`: The maximum fraction of the time-to-live removed, from 0 to 1.
+/// - `rng: &mut Rng`: The random number generator.
+///
+/// ## Returns
+/// - `Ok(OffsetDateTime)`: The expiry, between `now + ttl * (1 - jitter_fraction)` and `now + ttl`.
+/// - `Err(DateTimeError)`: If the time-to-live is negative, the fraction is not between 0 and 1, or the
+///   expiry falls outside the supported date range.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::{expiry_with_jitter, Rng};
+/// use time::{macros::datetime, Duration};
+///
+/// let now = datetime!(2024-05-31 12:00 UTC);
+/// let expiry = expiry_with_jitter(now, Duration::hours(1), 0.1, &mut Rng::new(7)).unwrap();
+/// assert!(expiry > now + Duration::minutes(54) && expiry <= now + Duration::hours(1));
+/// ```
+#[cfg(feature = "rand")]
+pub fn expiry_with_jitter(
+    now: OffsetDateTime,
+    ttl: Duration,
+    jitter_fraction: f64,
+    rng: &mut Rng,
+) -> Result<OffsetDateTime, DateTimeError> {
+    if ttl.is_negative() {
+        return Err(DateTimeError::InvalidComponent(
+            "ttl",
+            format!("{ttl} is negative"),
+        ));
+    }
+    if !(0.0..=1.0).contains(&jitter_fraction) {
+        return Err(DateTimeError::InvalidComponent(
+            "jitter_fraction",
+            format!("{jitter_fraction} is not between 0 and 1"),
+        ));
+    }
+    // Uniform in [0, 1), from the 53 high bits of the random number.
+    let unit = (rng.next_u64() >> 11) as f64 / (1u64 << 53) as f64;
+    let jitter = (ttl.whole_nanoseconds() as f64 * jitter_fraction * unit) as i128;
+    now.checked_add(ttl - duration_from_nanos(jitter))
+        .ok_or_else(|| DateTimeError::OutOfRange(format!("{now} + {ttl}")))
+}
+
+/// Checks whether a value fetched at the given instant is stale.
+///
+/// ## Arguments
+/// - `fetched_at: OffsetDateTime`: The instant the value was fetched.
+/// - `ttl: Duration`: The time-to-live of the value.
+/// - `now: OffsetDateTime`: The current instant.
+///
+/// ## Returns
+/// - `bool`: Whether the time-to-live has elapsed since the value was fetched.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::is_stale;
+/// use time::{macros::datetime, Duration};
+///
+/// let fetched_at = datetime!(2024-05-31 12:00 UTC);
+/// assert!(!is_stale(fetched_at, Duration::hours(1), datetime!(2024-05-31 12:59 UTC)));
+/// assert!(is_stale(fetched_at, Duration::hours(1), datetime!(2024-05-31 13:00 UTC)));
+/// ```
+pub fn is_stale(fetched_at: OffsetDateTime, ttl: Duration, now: OffsetDateTime) -> bool {
+    now - fetched_at >= ttl
+}
@@ -0,0 +1,56 @@
+//! Clock Module
+//!
+//! This module contains the `Clock` trait, which abstracts the source of the current time, and its
+//! system and fixed implementations, so that functions depending on the current time can be tested
+//! deterministically.
+use time::OffsetDateTime;
+
+/// Represents a source of the current time.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::{Clock, FixedClock};
+/// use time::macros::datetime;
+///
+/// let clock = FixedClock::new(datetime!(2024-05-31 12:00 UTC));
+/// assert_eq!(clock.now(), datetime!(2024-05-31 12:00 UTC));
+/// ```
+pub trait Clock {
+    /// Returns the current datetime.
+    fn now(&self) -> OffsetDateTime;
+}
+
+impl<C: Clock + ?Sized> Clock for &C {
+    fn now(&self) -> OffsetDateTime {
+        (**self).now()
+    }
+}
+
+/// Represents the system clock, returning the current datetime in UTC.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> OffsetDateTime {
+        OffsetDateTime::now_utc()
+    }
+}
+
+/// Represents a clock always returning the same datetime.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FixedClock {
+    now: OffsetDateTime,
+}
+
+impl FixedClock {
+    /// Creates a clock always returning the given datetime.
+    pub fn new(now: OffsetDateTime) -> Self {
+        Self { now }
+    }
+}
+
+impl Clock for FixedClock {
+    fn now(&self) -> OffsetDateTime {
+        self.now
+    }
+}
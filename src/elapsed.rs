@@ -0,0 +1,72 @@
+//! Elapsed Module
+//!
+//! This module contains the `Elapsed` type, a stopwatch reading the time from a `Clock`, to time the
+//! stages of a pipeline with the same clock abstraction and duration formatting as the rest of the crate.
+#[cfg(feature = "formatting")]
+use std::fmt;
+
+use time::{Duration, OffsetDateTime};
+
+#[cfg(feature = "formatting")]
+use crate::write_duration;
+use crate::Clock;
+
+/// Represents a stopwatch started at the current time of a clock.
+///
+/// With the `formatting` feature, `Display` writes the elapsed time as `format_duration` does (e.g.
+/// `2m 5s`), negative durations (if the clock goes backward) being written as `0s`.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::{Elapsed, SystemClock};
+///
+/// let mut stopwatch = Elapsed::start(SystemClock);
+/// let load = stopwatch.lap();
+/// let transform = stopwatch.lap();
+/// assert!(load + transform <= stopwatch.elapsed());
+/// println!("pipeline took {stopwatch}");
+/// ```
+#[derive(Debug, Clone)]
+pub struct Elapsed<C: Clock> {
+    clock: C,
+    started_at: OffsetDateTime,
+    lap_started_at: OffsetDateTime,
+}
+
+impl<C: Clock> Elapsed<C> {
+    /// Starts a stopwatch at the current time of the clock.
+    pub fn start(clock: C) -> Self {
+        let now = clock.now();
+        Self {
+            clock,
+            started_at: now,
+            lap_started_at: now,
+        }
+    }
+
+    /// Returns the datetime at which the stopwatch started.
+    pub fn started_at(&self) -> OffsetDateTime {
+        self.started_at
+    }
+
+    /// Returns the time elapsed since the stopwatch started.
+    pub fn elapsed(&self) -> Duration {
+        self.clock.now() - self.started_at
+    }
+
+    /// Returns the time elapsed since the previous lap (or the start), and starts a new lap.
+    pub fn lap(&mut self) -> Duration {
+        let now = self.clock.now();
+        let lap = now - self.lap_started_at;
+        self.lap_started_at = now;
+        lap
+    }
+}
+
+#[cfg(feature = "formatting")]
+impl<C: Clock> fmt::Display for Elapsed<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let elapsed = self.elapsed().try_into().unwrap_or_default();
+        write_duration(f, elapsed)
+    }
+}
@@ -0,0 +1,216 @@
+//! Fiscal Period Module
+//!
+//! This module contains the `FiscalPeriod` and `FiscalCalendar` types, which parse fiscal year and
+//! fiscal quarter strings (e.g. `FY2024Q3`, `FY24`) and map them to and from calendar dates, given the
+//! month in which the fiscal year starts.
+use std::{fmt, str::FromStr};
+
+use time::{Date, Month};
+
+use crate::{period::days_range, DateTimeError, DateTimeRange};
+
+/// Represents a fiscal year or a fiscal quarter.
+///
+/// Fiscal years are labelled by the calendar year in which they end: with a fiscal year starting in
+/// October, `FY2024` runs from October 2023 to September 2024.
+///
+/// `Display` uses the `FY2024` and `FY2024Q3` notations. `FromStr` accepts, case-insensitively, two- or
+/// four-digit years and the quarter either before or after the year (e.g. `FY24`, `FY2024-Q3`,
+/// `FY24 Q3`, `Q3 FY24`). Two-digit years are interpreted as years of the 21st century.
+///
+/// - `FiscalPeriod::Year`: It indicates a fiscal year
+/// - `FiscalPeriod::Quarter`: It indicates a quarter (from 1 to 4) of a fiscal year
+///
+/// ## Example
+/// ```rust
+/// use date_utils::FiscalPeriod;
+///
+/// assert_eq!("Q3 FY24".parse::<FiscalPeriod>().unwrap(), FiscalPeriod::Quarter(2024, 3));
+/// assert_eq!(FiscalPeriod::Year(2024).to_string(), "FY2024");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FiscalPeriod {
+    Year(i32),
+    Quarter(i32, u8),
+}
+
+impl FiscalPeriod {
+    /// Returns the fiscal year of the period.
+    pub fn year(&self) -> i32 {
+        match self {
+            FiscalPeriod::Year(year) | FiscalPeriod::Quarter(year, _) => *year,
+        }
+    }
+}
+
+impl fmt::Display for FiscalPeriod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FiscalPeriod::Year(year) => write!(f, "FY{year:04}"),
+            FiscalPeriod::Quarter(year, quarter) => write!(f, "FY{year:04}Q{quarter}"),
+        }
+    }
+}
+
+impl FromStr for FiscalPeriod {
+    type Err = DateTimeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = || {
+            DateTimeError::InvalidDateFormat(
+                s.to_string(),
+                "expected a fiscal period such as FY2024, FY24, FY2024Q3 or Q3 FY24".to_string(),
+            )
+        };
+        const SEPARATORS: [char; 3] = [' ', '-', '/'];
+        let upper = s.trim().to_ascii_uppercase();
+        let (before, after) = upper.split_once("FY").ok_or_else(error)?;
+        let (year, rest) = after.split_at(after.bytes().take_while(u8::is_ascii_digit).count());
+        let year = match year.len() {
+            2 => 2000 + year.parse::<i32>().map_err(|_| error())?,
+            4 => year.parse::<i32>().map_err(|_| error())?,
+            _ => return Err(error()),
+        };
+        let quarter = match (
+            before.trim_matches(SEPARATORS),
+            rest.trim_matches(SEPARATORS),
+        ) {
+            ("", "") => return Ok(FiscalPeriod::Year(year)),
+            (quarter, "") | ("", quarter) => quarter,
+            _ => return Err(error()),
+        };
+        match quarter.as_bytes() {
+            [b'Q', digit @ b'1'..=b'4'] => Ok(FiscalPeriod::Quarter(year, digit - b'0')),
+            [b'Q', digit @ b'0'..=b'9'] => Err(DateTimeError::InvalidTimeComponent(
+                (digit - b'0').to_string(),
+            )),
+            _ => Err(error()),
+        }
+    }
+}
+
+/// Represents a fiscal calendar, defined by the month in which the fiscal year starts.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::{FiscalCalendar, FiscalPeriod};
+/// use time::{macros::{date, datetime}, Month};
+///
+/// let calendar = FiscalCalendar::new(Month::October);
+/// let range = calendar.parse_range("FY2024Q1").unwrap();
+/// assert_eq!(range.start(), datetime!(2023-10-01 0:00 UTC));
+/// assert_eq!(range.end(), datetime!(2023-12-31 23:59:59.999999999 UTC));
+/// assert_eq!(calendar.period_of(&range), Some(FiscalPeriod::Quarter(2024, 1)));
+/// assert_eq!(calendar.quarter_of(date!(2024 - 09 - 30)).to_string(), "FY2024Q4");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FiscalCalendar {
+    start_month: Month,
+}
+
+impl FiscalCalendar {
+    /// Creates a fiscal calendar whose fiscal years start on the first day of the given month.
+    pub fn new(start_month: Month) -> Self {
+        Self { start_month }
+    }
+
+    /// Returns the month in which the fiscal year starts.
+    pub fn start_month(&self) -> Month {
+        self.start_month
+    }
+
+    /// Returns the fiscal year containing the given date.
+    pub fn year_of(&self, date: Date) -> FiscalPeriod {
+        FiscalPeriod::Year(self.fiscal_year(date))
+    }
+
+    /// Returns the fiscal quarter containing the given date.
+    pub fn quarter_of(&self, date: Date) -> FiscalPeriod {
+        let months_into_year = (date.month() as u8 + 12 - self.start_month as u8) % 12;
+        FiscalPeriod::Quarter(self.fiscal_year(date), months_into_year / 3 + 1)
+    }
+
+    /// Returns the first and last calendar dates of the fiscal period.
+    ///
+    /// ## Returns
+    /// - `Ok((Date, Date))`: The first and last dates of the period.
+    /// - `Err(DateTimeError)`: If the quarter is not between 1 and 4, or the period is out of the
+    ///   supported date range.
+    pub fn dates(&self, period: FiscalPeriod) -> Result<(Date, Date), DateTimeError> {
+        let (first_month, months) = match period {
+            FiscalPeriod::Year(_) => (0, 12),
+            FiscalPeriod::Quarter(_, quarter @ 1..=4) => (3 * (quarter as i32 - 1), 3),
+            FiscalPeriod::Quarter(_, quarter) => {
+                return Err(DateTimeError::InvalidTimeComponent(quarter.to_string()));
+            }
+        };
+        let out_of_range = || DateTimeError::OutOfRange(period.to_string());
+        // The fiscal year starts in the previous calendar year, unless it starts in January.
+        let year = period
+            .year()
+            .checked_sub((self.start_month != Month::January) as i32)
+            .ok_or_else(out_of_range)?;
+        let start = months_since_year0(year, self.start_month as i32 - 1 + first_month)?;
+        let first = first_of_month(start)?;
+        let end = start.checked_add(months).ok_or_else(out_of_range)?;
+        let last = first_of_month(end)?
+            .previous_day()
+            .ok_or_else(out_of_range)?;
+        Ok((first, last))
+    }
+
+    /// Expands the fiscal period into the range from its first instant to its last instant, in UTC.
+    ///
+    /// ## Returns
+    /// - `Ok(DateTimeRange)`: The range covered by the period.
+    /// - `Err(DateTimeError)`: If the quarter is not between 1 and 4, or the period is out of the
+    ///   supported date range.
+    pub fn range(&self, period: FiscalPeriod) -> Result<DateTimeRange, DateTimeError> {
+        let (first, last) = self.dates(period)?;
+        Ok(days_range(first, last))
+    }
+
+    /// Parses a fiscal period string and expands it into the calendar range it covers, in UTC.
+    ///
+    /// ## Returns
+    /// - `Ok(DateTimeRange)`: The range covered by the period.
+    /// - `Err(DateTimeError)`: If the string is not a valid fiscal period.
+    pub fn parse_range(&self, input: &str) -> Result<DateTimeRange, DateTimeError> {
+        self.range(input.parse()?)
+    }
+
+    /// Returns the fiscal year or quarter exactly covering the calendar dates of the range, if any.
+    ///
+    /// Calendar dates are evaluated in the offset of the start of the range.
+    pub fn period_of(&self, range: &DateTimeRange) -> Option<FiscalPeriod> {
+        let (first, last) = range.dates();
+        [self.quarter_of(first), self.year_of(first)]
+            .into_iter()
+            .find(|period| self.dates(*period).ok() == Some((first, last)))
+    }
+
+    fn fiscal_year(&self, date: Date) -> i32 {
+        if self.start_month != Month::January && date.month() as u8 >= self.start_month as u8 {
+            date.year() + 1
+        } else {
+            date.year()
+        }
+    }
+}
+
+/// Counts the months since January of year 0 up to the given month offset from January of the year.
+///
+/// The count is checked, since the years of public period types are not validated.
+pub(crate) fn months_since_year0(year: i32, months: i32) -> Result<i32, DateTimeError> {
+    year.checked_mul(12)
+        .and_then(|total| total.checked_add(months))
+        .ok_or_else(|| DateTimeError::OutOfRange(format!("year {year} is out of range")))
+}
+
+/// Returns the first day of the month, counted in months since January of year 0.
+pub(crate) fn first_of_month(months: i32) -> Result<Date, DateTimeError> {
+    let month = Month::try_from((months.rem_euclid(12) + 1) as u8)
+        .map_err(|err| DateTimeError::OutOfRange(err.to_string()))?;
+    Date::from_calendar_date(months.div_euclid(12), month, 1)
+        .map_err(|err| DateTimeError::OutOfRange(err.to_string()))
+}
@@ -0,0 +1,394 @@
+//! Formatting Module
+//!
+//! This module contains a set of methods to format datetimes in the formats supported by this crate,
+//! either into new `String`s or into existing writers. Writer-based variants reuse a thread-local buffer,
+//! so formatting many datetimes does not allocate once the buffer has grown.
+use std::{cell::RefCell, fmt, io::Write};
+
+use time::{
+    format_description::{well_known, BorrowedFormatItem},
+    macros::format_description,
+    OffsetDateTime, UtcOffset,
+};
+
+use crate::{period::write_period, DateTimeError, Frequency, Period, PeriodNotation};
+
+const DATE_FORMAT: &[BorrowedFormatItem<'_>] = format_description!("[year]-[month]-[day]");
+const DATETIME_FORMAT: &[BorrowedFormatItem<'_>] =
+    format_description!("[year]-[month]-[day] [hour]:[minute]:[second]");
+const LONG_FORMAT: &[BorrowedFormatItem<'_>] =
+    format_description!("[month repr:long] [day padding:none], [year]");
+const RFC3339_STRICT_FORMAT: &[BorrowedFormatItem<'_>] = format_description!(
+    "[year]-[month]-[day]T[hour]:[minute]:[second][offset_hour sign:mandatory]:[offset_minute]"
+);
+const RFC3339_MILLIS_FORMAT: &[BorrowedFormatItem<'_>] = format_description!(
+    "[year]-[month]-[day]T[hour]:[minute]:[second].[subsecond digits:3][offset_hour sign:mandatory]:[offset_minute]"
+);
+const RFC3339_Z_FORMAT: &[BorrowedFormatItem<'_>] =
+    format_description!("[year]-[month]-[day]T[hour]:[minute]:[second].[subsecond digits:3]Z");
+
+thread_local! {
+    static BUFFER: RefCell<Vec<u8>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Represents the output format of a datetime.
+///
+/// - `Format::Date`: `YYYY-MM-DD` (e.g. `2024-05-31`)
+/// - `Format::DateTime`: `YYYY-MM-DD HH:MM:SS` (e.g. `2024-05-31 12:00:00`)
+/// - `Format::Rfc3339`: RFC 3339 (e.g. `2024-05-31T12:00:00.5+02:00`)
+/// - `Format::Rfc3339Strict`: RFC 3339 without fractional seconds, with a numeric offset even for UTC
+///   (e.g. `2024-05-31T12:00:00+00:00`)
+/// - `Format::Rfc3339Millis`: RFC 3339 with exactly three fractional digits, truncated, and a numeric
+///   offset (e.g. `2024-05-31T12:00:00.500+02:00`)
+/// - `Format::Rfc3339Z`: RFC 3339 converted to UTC, with exactly three fractional digits, truncated, and a
+///   `Z` offset (e.g. `2024-05-31T10:00:00.500Z`)
+/// - `Format::Long`: English month name, day and year (e.g. `May 31, 2024`)
+/// - `Format::LongOrdinal`: Same as `Format::Long` with an ordinal day suffix (e.g. `May 31st, 2024`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Format {
+    Date,
+    DateTime,
+    Rfc3339,
+    Rfc3339Strict,
+    Rfc3339Millis,
+    Rfc3339Z,
+    Long,
+    LongOrdinal,
+}
+
+/// Returns the English ordinal suffix of a day of month (`st`, `nd`, `rd` or `th`).
+///
+/// ## Example
+/// ```rust
+/// use date_utils::ordinal_suffix;
+///
+/// assert_eq!(ordinal_suffix(1), "st");
+/// assert_eq!(ordinal_suffix(12), "th");
+/// assert_eq!(ordinal_suffix(22), "nd");
+/// ```
+pub fn ordinal_suffix(day: u8) -> &'static str {
+    match (day % 10, day % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    }
+}
+
+/// Formats a datetime into a new `String`.
+///
+/// ## Arguments
+/// - `datetime: OffsetDateTime`: The datetime to format.
+/// - `format: Format`: The output format.
+///
+/// ## Returns
+/// - `Ok(String)`: The formatted datetime.
+/// - `Err(DateTimeError)`: If the datetime cannot be represented in the format.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::{format_datetime, Format};
+/// use time::macros::datetime;
+///
+/// let formatted = format_datetime(datetime!(2024-05-31 12:00 UTC), Format::DateTime).unwrap();
+/// assert_eq!(formatted, "2024-05-31 12:00:00");
+/// ```
+pub fn format_datetime(datetime: OffsetDateTime, format: Format) -> Result<String, DateTimeError> {
+    let mut formatted = String::new();
+    write_datetime(&mut formatted, datetime, format)?;
+    Ok(formatted)
+}
+
+/// Formats a datetime into the given writer.
+///
+/// The function formats the datetime into a thread-local buffer and copies it into the writer, so it
+/// does not allocate once the buffer has grown. Passing the same `String` to successive calls, after
+/// clearing it, formats any number of datetimes without allocating.
+///
+/// ## Arguments
+/// - `out: &mut impl fmt::Write`: The writer (e.g. a `String` or a `fmt::Formatter`).
+/// - `datetime: OffsetDateTime`: The datetime to format.
+/// - `format: Format`: The output format.
+///
+/// ## Returns
+/// - `Ok(())`: If the datetime has been written.
+/// - `Err(DateTimeError)`: If the datetime cannot be represented in the format or the writer fails.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::{write_datetime, Format};
+/// use time::macros::datetime;
+///
+/// let mut line = String::from("date=");
+/// write_datetime(&mut line, datetime!(2024-05-31 12:00 UTC), Format::Date).unwrap();
+/// assert_eq!(line, "date=2024-05-31");
+/// ```
+pub fn write_datetime(
+    out: &mut impl fmt::Write,
+    datetime: OffsetDateTime,
+    format: Format,
+) -> Result<(), DateTimeError> {
+    with_buffer(|buffer| write_formatted(out, buffer, datetime, format))
+}
+
+/// Formats datetimes into new `String`s.
+///
+/// The function reuses the same formatting buffer across all the datetimes, so it only allocates the
+/// returned strings.
+///
+/// ## Arguments
+/// - `datetimes: &[OffsetDateTime]`: The datetimes to format.
+/// - `format: Format`: The output format.
+///
+/// ## Returns
+/// - `Ok(Vec<String>)`: The formatted datetimes, in the same order.
+/// - `Err(DateTimeError)`: If a datetime cannot be represented in the format.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::{format_many, Format};
+/// use time::macros::datetime;
+///
+/// let formatted = format_many(
+///     &[datetime!(2024-05-30 0:00 UTC), datetime!(2024-05-31 0:00 UTC)],
+///     Format::Date,
+/// )
+/// .unwrap();
+/// assert_eq!(formatted, ["2024-05-30", "2024-05-31"]);
+/// ```
+pub fn format_many(
+    datetimes: &[OffsetDateTime],
+    format: Format,
+) -> Result<Vec<String>, DateTimeError> {
+    with_buffer(|buffer| {
+        datetimes
+            .iter()
+            .map(|datetime| {
+                let mut formatted = String::new();
+                write_formatted(&mut formatted, buffer, *datetime, format)?;
+                Ok(formatted)
+            })
+            .collect()
+    })
+}
+
+/// Formats datetimes into the given writer, separated by the given separator.
+///
+/// The function reuses the same formatting buffer across all the datetimes, so it does not allocate
+/// once the buffer and the writer have grown (e.g. when writing a column of a CSV export).
+///
+/// ## Arguments
+/// - `out: &mut impl fmt::Write`: The writer.
+/// - `datetimes: &[OffsetDateTime]`: The datetimes to format.
+/// - `format: Format`: The output format.
+/// - `separator: &str`: The separator written between datetimes (e.g. `"\n"`).
+///
+/// ## Returns
+/// - `Ok(())`: If the datetimes have been written.
+/// - `Err(DateTimeError)`: If a datetime cannot be represented in the format or the writer fails.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::{write_many, Format};
+/// use time::macros::datetime;
+///
+/// let mut csv = String::new();
+/// let datetimes = [datetime!(2024-05-30 0:00 UTC), datetime!(2024-05-31 0:00 UTC)];
+/// write_many(&mut csv, &datetimes, Format::Date, "\n").unwrap();
+/// assert_eq!(csv, "2024-05-30\n2024-05-31");
+/// ```
+pub fn write_many(
+    out: &mut impl fmt::Write,
+    datetimes: &[OffsetDateTime],
+    format: Format,
+    separator: &str,
+) -> Result<(), DateTimeError> {
+    with_buffer(|buffer| {
+        for (index, datetime) in datetimes.iter().enumerate() {
+            if index > 0 {
+                out.write_str(separator).map_err(|err| {
+                    DateTimeError::FormatError(datetime.to_string(), err.to_string())
+                })?;
+            }
+            write_formatted(out, buffer, *datetime, format)?;
+        }
+        Ok(())
+    })
+}
+
+/// Runs the function with the thread-local formatting buffer.
+fn with_buffer<T>(f: impl FnOnce(&mut Vec<u8>) -> T) -> T {
+    BUFFER.with(|buffer| match buffer.try_borrow_mut() {
+        Ok(mut buffer) => f(&mut buffer),
+        // Re-entrant call (e.g. from a writer formatting datetimes itself): use a temporary buffer.
+        Err(_) => f(&mut Vec::new()),
+    })
+}
+
+/// Formats a datetime into the buffer and copies it into the writer.
+fn write_formatted(
+    out: &mut impl fmt::Write,
+    buffer: &mut Vec<u8>,
+    datetime: OffsetDateTime,
+    format: Format,
+) -> Result<(), DateTimeError> {
+    buffer.clear();
+    match format {
+        Format::Date => datetime.format_into(buffer, DATE_FORMAT),
+        Format::DateTime => datetime.format_into(buffer, DATETIME_FORMAT),
+        Format::Rfc3339 => datetime.format_into(buffer, &well_known::Rfc3339),
+        Format::Rfc3339Strict => rfc3339_profile(buffer, datetime, RFC3339_STRICT_FORMAT),
+        Format::Rfc3339Millis => rfc3339_profile(buffer, datetime, RFC3339_MILLIS_FORMAT),
+        Format::Rfc3339Z => {
+            rfc3339_profile(buffer, datetime.to_offset(UtcOffset::UTC), RFC3339_Z_FORMAT)
+        }
+        Format::Long => datetime.format_into(buffer, LONG_FORMAT),
+        Format::LongOrdinal => write!(
+            buffer,
+            "{} {}{}, {}",
+            datetime.month(),
+            datetime.day(),
+            ordinal_suffix(datetime.day()),
+            datetime.year()
+        )
+        .map(|()| buffer.len())
+        .map_err(time::error::Format::from),
+    }
+    .map_err(|err| DateTimeError::FormatError(datetime.to_string(), err.to_string()))?;
+    // The formatter only emits ASCII.
+    let formatted = std::str::from_utf8(buffer).unwrap_or_default();
+    out.write_str(formatted)
+        .map_err(|err| DateTimeError::FormatError(datetime.to_string(), err.to_string()))
+}
+
+/// Displays a datetime as a `YYYY-MM-DD` date, in its offset.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::IsoDate;
+/// use time::macros::datetime;
+///
+/// assert_eq!(format!("as of {}", IsoDate(datetime!(2024-05-31 23:00 -2))), "as of 2024-05-31");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct IsoDate(pub OffsetDateTime);
+
+impl fmt::Display for IsoDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_datetime(f, self.0, Format::Date).map_err(|_| fmt::Error)
+    }
+}
+
+/// Displays a datetime as an RFC 3339 timestamp, as `Format::Rfc3339` does.
+///
+/// Datetimes RFC 3339 cannot represent are still displayed, as close to it as possible: an offset with
+/// seconds is replaced by UTC, keeping the instant, and years before `0000` use the ISO 8601 extended
+/// form (e.g. `-0001-01-01T00:00:00Z`). Use `format_datetime` to reject them instead.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::Rfc3339;
+/// use time::macros::datetime;
+///
+/// assert_eq!(Rfc3339(datetime!(2024-05-31 12:00 +2)).to_string(), "2024-05-31T12:00:00+02:00");
+/// assert_eq!(Rfc3339(datetime!(2024-05-31 12:00 +0:00:30)).to_string(), "2024-05-31T11:59:30Z");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rfc3339(pub OffsetDateTime);
+
+impl fmt::Display for Rfc3339 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // The datetime is formatted before anything is written, so a failure leaves the writer untouched.
+        match write_datetime(f, self.0, Format::Rfc3339) {
+            Ok(()) => Ok(()),
+            Err(_) => write_extended_rfc3339(f, self.0),
+        }
+    }
+}
+
+/// Writes a datetime RFC 3339 cannot represent in the closest ISO 8601 extended form.
+fn write_extended_rfc3339(f: &mut fmt::Formatter<'_>, datetime: OffsetDateTime) -> fmt::Result {
+    let datetime = match datetime.offset().seconds_past_minute() {
+        0 => datetime,
+        _ => datetime
+            .checked_to_offset(UtcOffset::UTC)
+            .unwrap_or(datetime),
+    };
+    let (year, month, day) = datetime.to_calendar_date();
+    let (hour, minute, second, nanosecond) = datetime.to_hms_nano();
+    if (0..=9999).contains(&year) {
+        write!(f, "{year:04}")?;
+    } else {
+        write!(f, "{year:+05}")?;
+    }
+    write!(
+        f,
+        "-{:02}-{day:02}T{hour:02}:{minute:02}:{second:02}",
+        month as u8
+    )?;
+    if nanosecond != 0 {
+        let digits = format!("{nanosecond:09}");
+        write!(f, ".{}", digits.trim_end_matches('0'))?;
+    }
+    let offset = datetime.offset();
+    let (hours, minutes, seconds) = offset.as_hms();
+    let sign = if offset.is_negative() { '-' } else { '+' };
+    match (offset.is_utc(), seconds) {
+        (true, _) => f.write_str("Z"),
+        (false, 0) => write!(f, "{sign}{:02}:{:02}", hours.abs(), minutes.abs()),
+        // Only reached when the instant cannot be expressed in UTC within the supported range.
+        (false, _) => write!(
+            f,
+            "{sign}{:02}:{:02}:{:02}",
+            hours.abs(),
+            minutes.abs(),
+            seconds.abs()
+        ),
+    }
+}
+
+/// Displays the period of the given frequency containing a datetime, in the SDMX time period notation.
+///
+/// The period is `Period::containing` the date of the datetime, in its offset, rendered in the canonical
+/// notation of `Period`:
+/// - `Frequency::Daily`: `2024-05-31`
+/// - `Frequency::Weekly`: `2024-W22` (ISO week)
+/// - `Frequency::Monthly`: `2024-05`
+/// - `Frequency::Quarterly`: `2024-Q2`
+/// - `Frequency::Semiannual`: `2024-H1` (the SDMX semester notation `2024-S1` is accepted when parsing)
+/// - `Frequency::Annual`: `2024`
+///
+/// ## Example
+/// ```rust
+/// use date_utils::{Frequency, SdmxPeriod};
+/// use time::macros::datetime;
+///
+/// let period = SdmxPeriod(datetime!(2024-05-31 12:00 UTC), Frequency::Quarterly);
+/// assert_eq!(format!("TIME_PERIOD={period}"), "TIME_PERIOD=2024-Q2");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SdmxPeriod(pub OffsetDateTime, pub Frequency);
+
+impl fmt::Display for SdmxPeriod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let period = Period::containing(self.1, self.0.date());
+        write_period(f, period, PeriodNotation::Canonical)
+    }
+}
+
+/// Formats a datetime with an RFC 3339 profile, rejecting the datetimes RFC 3339 cannot represent (years
+/// outside `0000`-`9999` and offsets with seconds).
+fn rfc3339_profile(
+    buffer: &mut Vec<u8>,
+    datetime: OffsetDateTime,
+    format: &[BorrowedFormatItem<'_>],
+) -> Result<usize, time::error::Format> {
+    if !(0..=9999).contains(&datetime.year()) {
+        return Err(time::error::Format::InvalidComponent("year"));
+    }
+    if datetime.offset().seconds_past_minute() != 0 {
+        return Err(time::error::Format::InvalidComponent("offset_second"));
+    }
+    datetime.format_into(buffer, format)
+}
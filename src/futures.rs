@@ -0,0 +1,244 @@
+//! Futures Module
+//!
+//! This module contains a set of methods to generate the roll schedule of futures contract series, used
+//! to build continuous contracts.
+use std::fmt;
+
+use time::{Date, Month, Weekday};
+
+use crate::{
+    add_business_days, is_business_day, period::nth_weekday_of_month, DateTimeError,
+    HolidayCalendar, Weekend,
+};
+
+/// Represents the rule used to compute the expiry date of a contract.
+///
+/// - `ExpiryRule::ThirdWednesday`: IMM expiry, the third Wednesday of the contract month
+/// - `ExpiryRule::ThirdFriday`: The third Friday of the contract month (e.g. equity index futures)
+///
+/// If the expiry falls on a non-business day, it is moved to the previous business day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExpiryRule {
+    ThirdWednesday,
+    ThirdFriday,
+}
+
+/// Represents a futures contract, identified by its contract month.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FuturesContract {
+    pub year: i32,
+    pub month: Month,
+}
+
+impl FuturesContract {
+    /// Returns the standard month code of the contract (`F` for January to `Z` for December).
+    pub fn month_code(&self) -> char {
+        b"FGHJKMNQUVXZ"[self.month as usize - 1] as char
+    }
+}
+
+impl fmt::Display for FuturesContract {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.month_code(), self.year)
+    }
+}
+
+/// Represents the specification of a futures contract series.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::{ContractSpec, ExpiryRule};
+/// use time::Month;
+///
+/// // Quarterly IMM contracts, rolled 5 business days before expiry.
+/// let spec = ContractSpec::new(
+///     &[Month::March, Month::June, Month::September, Month::December],
+///     ExpiryRule::ThirdWednesday,
+///     5,
+/// )
+/// .unwrap();
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContractSpec {
+    months: Vec<Month>,
+    expiry: ExpiryRule,
+    roll_days_before_expiry: u32,
+    weekend: Weekend,
+    calendar: HolidayCalendar,
+}
+
+impl ContractSpec {
+    /// Creates the specification of a contract series, with the default weekend and no holidays.
+    ///
+    /// ## Arguments
+    /// - `months: &[Month]`: The listed contract months.
+    /// - `expiry: ExpiryRule`: The rule used to compute the expiry date.
+    /// - `roll_days_before_expiry: u32`: The number of business days before expiry at which the position
+    ///   is rolled to the next contract.
+    ///
+    /// ## Returns
+    /// - `Ok(ContractSpec)`: The specification of the contract series.
+    /// - `Err(DateTimeError)`: If no contract month is listed.
+    pub fn new(
+        months: &[Month],
+        expiry: ExpiryRule,
+        roll_days_before_expiry: u32,
+    ) -> Result<Self, DateTimeError> {
+        let mut months = months.to_vec();
+        months.sort_unstable_by_key(|month| *month as u8);
+        months.dedup();
+        if months.is_empty() {
+            return Err(DateTimeError::InvalidContractSpec(
+                "at least one contract month must be listed".to_string(),
+            ));
+        }
+        Ok(Self {
+            months,
+            expiry,
+            roll_days_before_expiry,
+            weekend: Weekend::default(),
+            calendar: HolidayCalendar::new(),
+        })
+    }
+
+    /// Sets the weekend and holiday calendar of the exchange.
+    pub fn with_calendar(mut self, weekend: Weekend, calendar: HolidayCalendar) -> Self {
+        self.weekend = weekend;
+        self.calendar = calendar;
+        self
+    }
+
+    /// Computes the expiry date of the given contract.
+    ///
+    /// ## Returns
+    /// - `Ok(Date)`: The expiry date, adjusted to the previous business day if needed.
+    /// - `Err(DateTimeError)`: If the expiry falls outside the supported date range.
+    pub fn expiry(&self, contract: FuturesContract) -> Result<Date, DateTimeError> {
+        let weekday = match self.expiry {
+            ExpiryRule::ThirdWednesday => Weekday::Wednesday,
+            ExpiryRule::ThirdFriday => Weekday::Friday,
+        };
+        let expiry = nth_weekday_of_month(contract.year, contract.month, weekday, 3)
+            .ok_or_else(|| DateTimeError::OutOfRange(format!("expiry of {contract}")))?;
+        if is_business_day(expiry, &self.weekend, &self.calendar) {
+            return Ok(expiry);
+        }
+        add_business_days(expiry, -1, &self.weekend, &self.calendar)
+    }
+
+    /// Computes the roll date of the given contract.
+    ///
+    /// ## Returns
+    /// - `Ok(Date)`: The date at which the position moves to the next contract.
+    /// - `Err(DateTimeError)`: If the roll date falls outside the supported date range.
+    pub fn roll_date(&self, contract: FuturesContract) -> Result<Date, DateTimeError> {
+        add_business_days(
+            self.expiry(contract)?,
+            -(self.roll_days_before_expiry as i64),
+            &self.weekend,
+            &self.calendar,
+        )
+    }
+
+    /// Returns the first listed contract with a contract month equal or following the given month.
+    fn contract_from(&self, year: i32, month: Month) -> FuturesContract {
+        match self
+            .months
+            .iter()
+            .find(|listed| **listed as u8 >= month as u8)
+        {
+            Some(listed) => FuturesContract {
+                year,
+                month: *listed,
+            },
+            None => FuturesContract {
+                year: year + 1,
+                month: self.months[0],
+            },
+        }
+    }
+
+    /// Returns the listed contract following the given one.
+    fn next_contract(&self, contract: FuturesContract) -> FuturesContract {
+        match contract.month {
+            Month::December => self.contract_from(contract.year + 1, Month::January),
+            month => self.contract_from(contract.year, month.next()),
+        }
+    }
+}
+
+/// Represents a roll of the position from the expiring contract to the next one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Roll {
+    pub date: Date,
+    pub from: FuturesContract,
+    pub to: FuturesContract,
+}
+
+/// Returns the contract held at the given date.
+///
+/// The position holds a contract up to the day before its roll date, and the next contract from the roll
+/// date onwards.
+///
+/// ## Returns
+/// - `Ok(FuturesContract)`: The active contract.
+/// - `Err(DateTimeError)`: If a roll date falls outside the supported date range.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::{active_contract, ContractSpec, ExpiryRule, FuturesContract};
+/// use time::{macros::date, Month};
+///
+/// let spec = ContractSpec::new(&[Month::March, Month::June], ExpiryRule::ThirdWednesday, 5).unwrap();
+/// // June 2024 IMM expiry is 2024-06-19, rolled 5 business days before on 2024-06-12.
+/// let active = active_contract(&spec, date!(2024 - 06 - 12)).unwrap();
+/// assert_eq!(active, FuturesContract { year: 2025, month: Month::March });
+/// ```
+pub fn active_contract(spec: &ContractSpec, date: Date) -> Result<FuturesContract, DateTimeError> {
+    let mut contract = spec.contract_from(date.year(), date.month());
+    while spec.roll_date(contract)? <= date {
+        contract = spec.next_contract(contract);
+    }
+    Ok(contract)
+}
+
+/// Generates the roll schedule of a contract series between two dates (both included).
+///
+/// ## Returns
+/// - `Ok(Vec<Roll>)`: The rolls occurring between the two dates, in chronological order.
+/// - `Err(DateTimeError)`: If a roll date falls outside the supported date range.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::{roll_dates, ContractSpec, ExpiryRule};
+/// use time::{macros::date, Month};
+///
+/// let spec = ContractSpec::new(
+///     &[Month::March, Month::June, Month::September, Month::December],
+///     ExpiryRule::ThirdWednesday,
+///     5,
+/// )
+/// .unwrap();
+/// let rolls = roll_dates(&spec, date!(2024 - 01 - 01), date!(2024 - 12 - 31)).unwrap();
+/// assert_eq!(rolls.len(), 4);
+/// assert_eq!(rolls[0].date, date!(2024 - 03 - 13));
+/// ```
+pub fn roll_dates(spec: &ContractSpec, start: Date, end: Date) -> Result<Vec<Roll>, DateTimeError> {
+    let mut rolls = Vec::new();
+    let mut contract = spec.contract_from(start.year(), start.month());
+    loop {
+        let date = spec.roll_date(contract)?;
+        if date > end {
+            return Ok(rolls);
+        }
+        let next = spec.next_contract(contract);
+        if date >= start {
+            rolls.push(Roll {
+                date,
+                from: contract,
+                to: next,
+            });
+        }
+        contract = next;
+    }
+}
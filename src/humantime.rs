@@ -0,0 +1,252 @@
+//! Humantime Compatibility Module
+//!
+//! This module contains a set of methods formatting and parsing durations and RFC 3339 timestamps the
+//! same way the `humantime` crate does, so values produced by CLI tools built on it round-trip through
+//! this crate.
+#[cfg(feature = "formatting")]
+use std::fmt;
+use std::time::Duration as StdDuration;
+
+#[cfg(feature = "parsing")]
+use time::format_description::well_known::Rfc3339;
+use time::OffsetDateTime;
+#[cfg(feature = "formatting")]
+use time::UtcOffset;
+
+#[cfg(feature = "parsing")]
+use crate::DateTimeError;
+
+#[cfg(feature = "parsing")]
+const NANOS_PER_SECOND: u128 = 1_000_000_000;
+const SECONDS_PER_MINUTE: u64 = 60;
+const SECONDS_PER_HOUR: u64 = 3_600;
+const SECONDS_PER_DAY: u64 = 86_400;
+#[cfg(feature = "parsing")]
+const SECONDS_PER_WEEK: u64 = 604_800;
+/// A month is 30.44 days, as in `humantime`.
+const SECONDS_PER_MONTH: u64 = 2_630_016;
+/// A year is 365.25 days, as in `humantime`.
+const SECONDS_PER_YEAR: u64 = 31_557_600;
+
+/// Formats a duration the way `humantime::format_duration` does.
+///
+/// The duration is split into years, months, days, hours, minutes, seconds, milliseconds, microseconds
+/// and nanoseconds, omitting zero components (e.g. `"1day 2h 30m"`). A zero duration is formatted as
+/// `"0s"`.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::format_duration;
+/// use std::time::Duration;
+///
+/// assert_eq!(format_duration(Duration::new(9420, 1_000_000)), "2h 37m 1ms");
+/// ```
+#[cfg(feature = "formatting")]
+pub fn format_duration(duration: StdDuration) -> String {
+    let mut formatted = String::new();
+    // Writing into a `String` cannot fail.
+    let _ = write_duration(&mut formatted, duration);
+    formatted
+}
+
+/// Writes a duration into the given writer, formatted as `format_duration` does.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::write_duration;
+/// use std::time::Duration;
+///
+/// let mut line = String::from("took ");
+/// write_duration(&mut line, Duration::from_millis(1500)).unwrap();
+/// assert_eq!(line, "took 1s 500ms");
+/// ```
+#[cfg(feature = "formatting")]
+pub fn write_duration(out: &mut impl fmt::Write, duration: StdDuration) -> fmt::Result {
+    if duration.is_zero() {
+        return out.write_str("0s");
+    }
+    let mut secs = duration.as_secs();
+    let nanos = duration.subsec_nanos();
+    let mut separator = "";
+    for (unit_secs, singular, plural) in [
+        (SECONDS_PER_YEAR, "year", "years"),
+        (SECONDS_PER_MONTH, "month", "months"),
+        (SECONDS_PER_DAY, "day", "days"),
+        (SECONDS_PER_HOUR, "h", "h"),
+        (SECONDS_PER_MINUTE, "m", "m"),
+        (1, "s", "s"),
+    ] {
+        let value = secs / unit_secs;
+        secs %= unit_secs;
+        if value > 0 {
+            let unit = if value == 1 { singular } else { plural };
+            write!(out, "{separator}{value}{unit}")?;
+            separator = " ";
+        }
+    }
+    for (value, unit) in [
+        (nanos / 1_000_000, "ms"),
+        (nanos / 1_000 % 1_000, "us"),
+        (nanos % 1_000, "ns"),
+    ] {
+        if value > 0 {
+            write!(out, "{separator}{value}{unit}")?;
+            separator = " ";
+        }
+    }
+    Ok(())
+}
+
+/// Parses a duration the way `humantime::parse_duration` does.
+///
+/// The input is a sequence of numbers followed by a unit, optionally separated by spaces (e.g.
+/// `"1h 30m"`, `"2days 4h"`). Supported units are:
+/// - `nanos`, `nsec`, `ns`; `usec`, `us`, `µs`; `millis`, `msec`, `ms`
+/// - `seconds`, `second`, `secs`, `sec`, `s`; `minutes`, `minute`, `mins`, `min`, `m`
+/// - `hours`, `hour`, `hrs`, `hr`, `h`; `days`, `day`, `d`; `weeks`, `week`, `wks`, `wk`, `w`
+/// - `months`, `month`, `M` (30.44 days); `years`, `year`, `yrs`, `yr`, `y` (365.25 days)
+///
+/// ## Returns
+/// - `Ok(std::time::Duration)`: The parsed duration.
+/// - `Err(DateTimeError)`: If the input is empty, contains an unknown unit or overflows.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::parse_duration;
+/// use std::time::Duration;
+///
+/// assert_eq!(parse_duration("2h 37m 1ms").unwrap(), Duration::new(9420, 1_000_000));
+/// assert_eq!(parse_duration("1day").unwrap(), Duration::from_secs(86_400));
+/// ```
+#[cfg(feature = "parsing")]
+pub fn parse_duration(input: &str) -> Result<StdDuration, DateTimeError> {
+    let error =
+        |reason: &str| DateTimeError::InvalidDuration(input.to_string(), reason.to_string());
+    let mut rest = input.trim();
+    if rest.is_empty() {
+        return Err(error("empty duration"));
+    }
+    let mut total_nanos: u128 = 0;
+    while !rest.is_empty() {
+        let digits = rest
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len());
+        if digits == 0 {
+            return Err(error("expected number"));
+        }
+        let value: u64 = rest[..digits]
+            .parse()
+            .map_err(|_| error("number is too large"))?;
+        rest = rest[digits..].trim_start();
+        let letters = rest
+            .find(|c: char| !c.is_alphabetic())
+            .unwrap_or(rest.len());
+        let unit_nanos: u128 = match &rest[..letters] {
+            "nanos" | "nsec" | "ns" => 1,
+            "usec" | "us" | "µs" => 1_000,
+            "millis" | "msec" | "ms" => 1_000_000,
+            "seconds" | "second" | "secs" | "sec" | "s" => NANOS_PER_SECOND,
+            "minutes" | "minute" | "mins" | "min" | "m" => {
+                SECONDS_PER_MINUTE as u128 * NANOS_PER_SECOND
+            }
+            "hours" | "hour" | "hrs" | "hr" | "h" => SECONDS_PER_HOUR as u128 * NANOS_PER_SECOND,
+            "days" | "day" | "d" => SECONDS_PER_DAY as u128 * NANOS_PER_SECOND,
+            "weeks" | "week" | "wks" | "wk" | "w" => SECONDS_PER_WEEK as u128 * NANOS_PER_SECOND,
+            "months" | "month" | "M" => SECONDS_PER_MONTH as u128 * NANOS_PER_SECOND,
+            "years" | "year" | "yrs" | "yr" | "y" => SECONDS_PER_YEAR as u128 * NANOS_PER_SECOND,
+            "" => return Err(error("missing unit")),
+            _ => return Err(error("unknown unit")),
+        };
+        total_nanos = total_nanos
+            .checked_add(value as u128 * unit_nanos)
+            .ok_or_else(|| error("duration is too large"))?;
+        rest = rest[letters..].trim_start();
+    }
+    let secs = u64::try_from(total_nanos / NANOS_PER_SECOND)
+        .map_err(|_| error("duration is too large"))?;
+    Ok(StdDuration::new(
+        secs,
+        (total_nanos % NANOS_PER_SECOND) as u32,
+    ))
+}
+
+/// Formats a datetime as an RFC 3339 timestamp the way `humantime::format_rfc3339` does.
+///
+/// The datetime is converted to UTC and formatted with a `Z` suffix. As with the smart precision of
+/// `humantime`, whole seconds have no fractional part, and any other instant has nine fractional
+/// digits. An instant which cannot be expressed in UTC (e.g. `9999-12-31 23:00 -1`, past the end of year
+/// 9999 in UTC) is formatted as `Rfc3339` does, keeping its own offset.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::format_rfc3339;
+/// use time::macros::datetime;
+///
+/// assert_eq!(format_rfc3339(datetime!(2018-02-14 01:28:07 +1)), "2018-02-14T00:28:07Z");
+/// assert_eq!(format_rfc3339(datetime!(2018-02-14 01:28:07.5 +1)), "2018-02-14T00:28:07.500000000Z");
+/// ```
+#[cfg(feature = "formatting")]
+pub fn format_rfc3339(datetime: OffsetDateTime) -> String {
+    let mut formatted = String::new();
+    // Writing into a `String` only fails when the instant cannot be expressed in UTC.
+    match write_rfc3339(&mut formatted, datetime) {
+        Ok(()) => formatted,
+        Err(_) => crate::format::Rfc3339(datetime).to_string(),
+    }
+}
+
+/// Writes a datetime into the given writer, formatted as `format_rfc3339` does.
+///
+/// ## Returns
+/// - `Ok(())`: If the datetime has been written.
+/// - `Err(fmt::Error)`: If the writer fails, or if the instant cannot be expressed in UTC, in which case
+///   nothing is written.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::write_rfc3339;
+/// use time::macros::datetime;
+///
+/// let mut line = String::from("at=");
+/// write_rfc3339(&mut line, datetime!(2018-02-14 00:28:07 UTC)).unwrap();
+/// assert_eq!(line, "at=2018-02-14T00:28:07Z");
+/// ```
+#[cfg(feature = "formatting")]
+pub fn write_rfc3339(out: &mut impl fmt::Write, datetime: OffsetDateTime) -> fmt::Result {
+    let datetime = datetime
+        .checked_to_offset(UtcOffset::UTC)
+        .ok_or(fmt::Error)?;
+    let (year, month, day) = datetime.to_calendar_date();
+    let (hour, minute, second) = datetime.to_hms();
+    write!(
+        out,
+        "{year:04}-{:02}-{day:02}T{hour:02}:{minute:02}:{second:02}",
+        month as u8
+    )?;
+    match datetime.nanosecond() {
+        0 => out.write_str("Z"),
+        nanos => write!(out, ".{nanos:09}Z"),
+    }
+}
+
+/// Parses an RFC 3339 timestamp, as produced by `humantime::format_rfc3339` and its variants.
+///
+/// Any offset and any fractional-seconds precision are accepted, and the offset of the input is kept.
+///
+/// ## Returns
+/// - `Ok(OffsetDateTime)`: The parsed datetime.
+/// - `Err(DateTimeError)`: If the input is not a valid RFC 3339 timestamp.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::parse_rfc3339;
+/// use time::macros::datetime;
+///
+/// let datetime = parse_rfc3339("2018-02-14T00:28:07.123Z").unwrap();
+/// assert_eq!(datetime, datetime!(2018-02-14 00:28:07.123 UTC));
+/// ```
+#[cfg(feature = "parsing")]
+pub fn parse_rfc3339(input: &str) -> Result<OffsetDateTime, DateTimeError> {
+    OffsetDateTime::parse(input, &Rfc3339)
+        .map_err(|err| DateTimeError::InvalidDateFormat(input.to_string(), err.to_string()))
+}
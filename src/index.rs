@@ -0,0 +1,138 @@
+//! Range Index Module
+//!
+//! This module contains the `RangeIndex` type, which indexes many ranges to answer containment and
+//! overlap queries without scanning all of them.
+use time::OffsetDateTime;
+
+use crate::DateTimeRange;
+
+/// Represents an index over a set of ranges, answering which of them contain an instant or overlap a
+/// range.
+///
+/// The ranges are stored sorted by start, as an implicit binary search tree in which each node also
+/// records the latest end of its subtree. Queries skip the subtrees ending before the queried instant
+/// and stop at the first range starting after it, so they run in `O(log n + k)` for `k` results.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::{DateTimeRange, RangeIndex};
+/// use time::macros::datetime;
+///
+/// let windows: RangeIndex = [
+///     DateTimeRange::new(datetime!(2024-05-01 2:00 UTC), datetime!(2024-05-01 4:00 UTC)).unwrap(),
+///     DateTimeRange::new(datetime!(2024-05-01 3:00 UTC), datetime!(2024-05-01 3:30 UTC)).unwrap(),
+///     DateTimeRange::new(datetime!(2024-05-08 2:00 UTC), datetime!(2024-05-08 4:00 UTC)).unwrap(),
+/// ]
+/// .into_iter()
+/// .collect();
+/// assert_eq!(windows.ranges_containing(datetime!(2024-05-01 3:15 UTC)).len(), 2);
+/// assert!(!windows.any_containing(datetime!(2024-05-02 3:00 UTC)));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RangeIndex {
+    ranges: Vec<DateTimeRange>,
+    max_end: Vec<OffsetDateTime>,
+}
+
+impl RangeIndex {
+    /// Creates an index over the given ranges.
+    pub fn new(ranges: impl IntoIterator<Item = DateTimeRange>) -> Self {
+        let mut ranges: Vec<_> = ranges.into_iter().collect();
+        ranges.sort_by_key(|range| (range.start(), range.end()));
+        let mut max_end: Vec<_> = ranges.iter().map(|range| range.end()).collect();
+        build(&mut max_end, 0, ranges.len());
+        Self { ranges, max_end }
+    }
+
+    /// Returns the number of ranges in the index.
+    pub fn len(&self) -> usize {
+        self.ranges.len()
+    }
+
+    /// Checks whether the index holds no ranges.
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Returns the indexed ranges containing the instant, sorted by start.
+    pub fn ranges_containing(&self, instant: OffsetDateTime) -> Vec<&DateTimeRange> {
+        self.overlapping(instant, instant)
+    }
+
+    /// Returns the indexed ranges sharing at least one instant with the range, sorted by start.
+    pub fn ranges_overlapping(&self, range: &DateTimeRange) -> Vec<&DateTimeRange> {
+        self.overlapping(range.start(), range.end())
+    }
+
+    /// Checks whether at least one indexed range contains the instant.
+    pub fn any_containing(&self, instant: OffsetDateTime) -> bool {
+        let mut found = false;
+        self.visit(0, self.ranges.len(), instant, instant, &mut |_| {
+            found = true;
+            false
+        });
+        found
+    }
+
+    fn overlapping(&self, start: OffsetDateTime, end: OffsetDateTime) -> Vec<&DateTimeRange> {
+        let mut found = Vec::new();
+        self.visit(0, self.ranges.len(), start, end, &mut |range| {
+            found.push(range);
+            true
+        });
+        found
+    }
+
+    /// Visits, in start order, the ranges of the subtree `[low, high)` overlapping `[start, end]`, until
+    /// the visitor returns `false`. Returns `false` if the visit was stopped.
+    fn visit<'a>(
+        &'a self,
+        low: usize,
+        high: usize,
+        start: OffsetDateTime,
+        end: OffsetDateTime,
+        visitor: &mut impl FnMut(&'a DateTimeRange) -> bool,
+    ) -> bool {
+        if low >= high {
+            return true;
+        }
+        let mid = low + (high - low) / 2;
+        if self.max_end[mid] < start {
+            return true;
+        }
+        if !self.visit(low, mid, start, end, visitor) {
+            return false;
+        }
+        let range = &self.ranges[mid];
+        if range.start() > end {
+            return true;
+        }
+        if range.end() >= start && !visitor(range) {
+            return false;
+        }
+        self.visit(mid + 1, high, start, end, visitor)
+    }
+}
+
+impl FromIterator<DateTimeRange> for RangeIndex {
+    fn from_iter<I: IntoIterator<Item = DateTimeRange>>(iter: I) -> Self {
+        Self::new(iter)
+    }
+}
+
+/// Turns the ends of the subtree `[low, high)` into the latest end of each node's subtree, returning the
+/// latest end of the whole subtree.
+fn build(max_end: &mut [OffsetDateTime], low: usize, high: usize) -> Option<OffsetDateTime> {
+    if low >= high {
+        return None;
+    }
+    let mid = low + (high - low) / 2;
+    let left = build(max_end, low, mid);
+    let right = build(max_end, mid + 1, high);
+    let max = [left, right]
+        .into_iter()
+        .flatten()
+        .fold(max_end[mid], OffsetDateTime::max);
+    max_end[mid] = max;
+    Some(max)
+}
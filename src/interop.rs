@@ -0,0 +1,95 @@
+//! Std Interop Module
+//!
+//! This module contains a set of methods to convert datetimes from and to the `std::time` types
+//! (`SystemTime`, `Duration` since the Unix epoch), e.g. to handle file modification times.
+use std::time::{Duration as StdDuration, SystemTime};
+
+use time::OffsetDateTime;
+
+use crate::DateTimeError;
+
+/// Converts a `SystemTime` into a datetime.
+///
+/// The function supports system times before the Unix epoch.
+///
+/// ## Arguments
+/// - `system_time: SystemTime`: The system time to convert (e.g. a file modification time).
+///
+/// ## Returns
+/// - `Ok(OffsetDateTime)`: The datetime representing the system time, in UTC.
+/// - `Err(DateTimeError)`: If the system time falls outside the supported date range.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::datetime_from_system_time;
+/// use std::time::{Duration, UNIX_EPOCH};
+/// use time::macros::datetime;
+///
+/// let datetime = datetime_from_system_time(UNIX_EPOCH + Duration::from_secs(86_400)).unwrap();
+/// assert_eq!(datetime, datetime!(1970-01-02 0:00 UTC));
+/// ```
+pub fn datetime_from_system_time(system_time: SystemTime) -> Result<OffsetDateTime, DateTimeError> {
+    let out_of_range = || DateTimeError::OutOfRange(format!("{system_time:?}"));
+    match system_time.duration_since(SystemTime::UNIX_EPOCH) {
+        Ok(since_epoch) => OffsetDateTime::UNIX_EPOCH
+            .checked_add(since_epoch.try_into().map_err(|_| out_of_range())?),
+        Err(err) => OffsetDateTime::UNIX_EPOCH
+            .checked_sub(err.duration().try_into().map_err(|_| out_of_range())?),
+    }
+    .ok_or_else(out_of_range)
+}
+
+/// Converts a datetime into a `SystemTime`.
+///
+/// The range of `SystemTime` depends on the platform (e.g. it starts in 1601 on Windows), so the
+/// conversion is checked.
+///
+/// ## Arguments
+/// - `datetime: OffsetDateTime`: The datetime to convert.
+///
+/// ## Returns
+/// - `Ok(SystemTime)`: The system time representing the datetime.
+/// - `Err(DateTimeError)`: If the datetime falls outside the range of `SystemTime` on the platform.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::system_time_from_datetime;
+/// use std::time::UNIX_EPOCH;
+/// use time::macros::datetime;
+///
+/// assert_eq!(system_time_from_datetime(datetime!(1970-01-01 0:00 UTC)).unwrap(), UNIX_EPOCH);
+/// ```
+pub fn system_time_from_datetime(datetime: OffsetDateTime) -> Result<SystemTime, DateTimeError> {
+    let out_of_range = || DateTimeError::OutOfRange(datetime.to_string());
+    let since_epoch = datetime - OffsetDateTime::UNIX_EPOCH;
+    let magnitude = StdDuration::try_from(since_epoch.abs()).map_err(|_| out_of_range())?;
+    if since_epoch.is_negative() {
+        SystemTime::UNIX_EPOCH.checked_sub(magnitude)
+    } else {
+        SystemTime::UNIX_EPOCH.checked_add(magnitude)
+    }
+    .ok_or_else(out_of_range)
+}
+
+/// Computes the duration elapsed between the Unix epoch and the datetime.
+///
+/// ## Arguments
+/// - `datetime: OffsetDateTime`: The datetime, which must not precede the Unix epoch.
+///
+/// ## Returns
+/// - `Ok(std::time::Duration)`: The duration elapsed since the Unix epoch.
+/// - `Err(DateTimeError)`: If the datetime precedes the Unix epoch.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::duration_since_epoch;
+/// use time::macros::datetime;
+///
+/// let duration = duration_since_epoch(datetime!(1970-01-01 0:01 UTC)).unwrap();
+/// assert_eq!(duration.as_secs(), 60);
+/// assert!(duration_since_epoch(datetime!(1969-12-31 23:59 UTC)).is_err());
+/// ```
+pub fn duration_since_epoch(datetime: OffsetDateTime) -> Result<StdDuration, DateTimeError> {
+    StdDuration::try_from(datetime - OffsetDateTime::UNIX_EPOCH)
+        .map_err(|_| DateTimeError::BeforeEpoch(datetime.to_string()))
+}
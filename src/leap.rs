@@ -0,0 +1,137 @@
+//! Leap Day Module
+//!
+//! This module contains the `Feb29Policy` type, which defines how February 29 is handled when moved to a
+//! non-leap year, and the yearly date arithmetic relying on it.
+use std::ops::RangeInclusive;
+
+use time::{Date, Month};
+
+use crate::DateTimeError;
+
+/// Represents the handling of February 29 when it is moved to a non-leap year.
+///
+/// - `Feb29Policy::MoveToFeb28`: It moves the date to February 28 (default)
+/// - `Feb29Policy::MoveToMar1`: It moves the date to March 1
+/// - `Feb29Policy::Error`: It returns a `DateTimeError::InvalidLeapDay`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Feb29Policy {
+    #[default]
+    MoveToFeb28,
+    MoveToMar1,
+    Error,
+}
+
+impl Feb29Policy {
+    /// Builds the date of the given year falling on the given month and day, applying the policy if the
+    /// date is February 29 of a non-leap year.
+    pub(crate) fn date(self, year: i32, month: Month, day: u8) -> Result<Date, DateTimeError> {
+        match Date::from_calendar_date(year, month, day) {
+            Ok(date) => return Ok(date),
+            Err(err) if err.name() != "day" => {
+                return Err(DateTimeError::OutOfRange(err.to_string()));
+            }
+            Err(_) => {}
+        }
+        let (month, day) = match (month, day, self) {
+            (Month::February, 29, Feb29Policy::MoveToFeb28) => (Month::February, 28),
+            (Month::February, 29, Feb29Policy::MoveToMar1) => (Month::March, 1),
+            (Month::February, 29, Feb29Policy::Error) => {
+                return Err(DateTimeError::InvalidLeapDay(year));
+            }
+            _ => {
+                return Err(DateTimeError::InvalidTimeComponent(format!(
+                    "{month} {day} does not exist"
+                )));
+            }
+        };
+        Date::from_calendar_date(year, month, day)
+            .map_err(|err| DateTimeError::OutOfRange(err.to_string()))
+    }
+}
+
+/// Adds a number of years to the given date.
+///
+/// The function keeps month and day, and applies the policy when the date is February 29 and the
+/// resulting year is not a leap year.
+///
+/// ## Arguments
+/// - `date: Date`: The starting date.
+/// - `years: i32`: The number of years to add (negative to subtract).
+/// - `policy: Feb29Policy`: The handling of February 29 in non-leap years.
+///
+/// ## Returns
+/// - `Ok(Date)`: The resulting date.
+/// - `Err(DateTimeError)`: If the policy rejects the date or the year is out of range.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::{add_years, Feb29Policy};
+/// use time::macros::date;
+///
+/// assert_eq!(
+///     add_years(date!(2024 - 02 - 29), 1, Feb29Policy::MoveToMar1).unwrap(),
+///     date!(2025 - 03 - 01)
+/// );
+/// assert!(add_years(date!(2024 - 02 - 29), 1, Feb29Policy::Error).is_err());
+/// ```
+pub fn add_years(date: Date, years: i32, policy: Feb29Policy) -> Result<Date, DateTimeError> {
+    let year = date
+        .year()
+        .checked_add(years)
+        .ok_or_else(|| DateTimeError::OutOfRange(format!("{date} + {years} years")))?;
+    policy.date(year, date.month(), date.day())
+}
+
+/// Computes the anniversary of the given date in the given year.
+///
+/// ## Returns
+/// - `Ok(Date)`: The anniversary date.
+/// - `Err(DateTimeError)`: If the policy rejects the date or the year is out of range.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::{anniversary, Feb29Policy};
+/// use time::macros::date;
+///
+/// assert_eq!(
+///     anniversary(date!(2000 - 02 - 29), 2023, Feb29Policy::MoveToFeb28).unwrap(),
+///     date!(2023 - 02 - 28)
+/// );
+/// ```
+pub fn anniversary(date: Date, year: i32, policy: Feb29Policy) -> Result<Date, DateTimeError> {
+    policy.date(year, date.month(), date.day())
+}
+
+/// Generates one date per year, falling on a fixed month and day (e.g. every June 30).
+///
+/// February 29 anchors are moved in non-leap years according to the policy.
+///
+/// ## Arguments
+/// - `anchor_month_day: (Month, u8)`: The month and day of the dates.
+/// - `years: RangeInclusive<i32>`: The years of the dates.
+/// - `policy: Feb29Policy`: The handling of February 29 in non-leap years.
+///
+/// ## Returns
+/// - `Ok(Vec<Date>)`: The dates, in chronological order.
+/// - `Err(DateTimeError)`: If the month and day never exist, the policy rejects a date, or a year is out
+///   of range.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::{yearly_dates, Feb29Policy};
+/// use time::{macros::date, Month};
+///
+/// let dates = yearly_dates((Month::February, 29), 2023..=2025, Feb29Policy::MoveToMar1).unwrap();
+/// assert_eq!(dates, [date!(2023 - 03 - 01), date!(2024 - 02 - 29), date!(2025 - 03 - 01)]);
+/// ```
+pub fn yearly_dates(
+    anchor_month_day: (Month, u8),
+    years: RangeInclusive<i32>,
+    policy: Feb29Policy,
+) -> Result<Vec<Date>, DateTimeError> {
+    let (month, day) = anchor_month_day;
+    // 2000 is a leap year, so every valid month and day exists in it.
+    Date::from_calendar_date(2000, month, day)
+        .map_err(|err| DateTimeError::InvalidComponent(err.name(), err.to_string()))?;
+    years.map(|year| policy.date(year, month, day)).collect()
+}
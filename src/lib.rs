@@ -7,6 +7,139 @@ use time::{
     macros::format_description, Date, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset,
 };
 
+mod backoff;
+mod bitemporal;
+mod broadcast;
+mod builder;
+#[cfg(feature = "business")]
+mod business;
+mod cache;
+mod clock;
+mod elapsed;
+mod fiscal;
+#[cfg(feature = "formatting")]
+mod format;
+#[cfg(feature = "business")]
+mod futures;
+#[cfg(any(feature = "parsing", feature = "formatting"))]
+mod humantime;
+mod index;
+#[cfg(feature = "interop")]
+mod interop;
+mod leap;
+#[cfg(feature = "locales")]
+mod locale;
+mod lookup;
+mod nullable;
+mod parsed;
+mod parts;
+mod pattern;
+mod period;
+#[cfg(feature = "business")]
+mod publication;
+mod range;
+mod release;
+mod retention;
+#[cfg(feature = "rand")]
+mod sample;
+#[cfg(feature = "parsing")]
+mod scanner;
+mod season;
+#[cfg(feature = "business")]
+mod sla;
+mod slug;
+mod stats;
+#[cfg(feature = "parsing")]
+mod text;
+mod ticks;
+#[cfg(feature = "tz")]
+mod tz;
+mod vintage;
+mod week;
+mod window;
+
+#[cfg(feature = "business")]
+pub use backoff::business_backoff_schedule;
+pub use backoff::{backoff_schedule, Backoff};
+pub use bitemporal::{bitemporal_as_of, BitemporalRange};
+pub use broadcast::BroadcastPeriod;
+pub use builder::{from_iso_week_date, from_year_ordinal, DateTimeBuilder};
+#[cfg(feature = "business")]
+pub use business::{
+    add_business_days, add_business_time, business_time_between, is_business_day,
+    period_end_business_days, BusinessHours, HolidayCalendar, Weekend,
+};
+#[cfg(feature = "rand")]
+pub use cache::expiry_with_jitter;
+pub use cache::is_stale;
+pub use clock::{Clock, FixedClock, SystemClock};
+pub use elapsed::Elapsed;
+pub use fiscal::{FiscalCalendar, FiscalPeriod};
+#[cfg(feature = "formatting")]
+pub use format::{
+    format_datetime, format_many, ordinal_suffix, write_datetime, write_many, Format, IsoDate,
+    Rfc3339, SdmxPeriod,
+};
+#[cfg(feature = "business")]
+pub use futures::{active_contract, roll_dates, ContractSpec, ExpiryRule, FuturesContract, Roll};
+#[cfg(feature = "formatting")]
+pub use humantime::{format_duration, format_rfc3339, write_duration, write_rfc3339};
+#[cfg(feature = "parsing")]
+pub use humantime::{parse_duration, parse_rfc3339};
+pub use index::RangeIndex;
+#[cfg(feature = "interop")]
+pub use interop::{datetime_from_system_time, duration_since_epoch, system_time_from_datetime};
+pub use leap::{add_years, anniversary, yearly_dates, Feb29Policy};
+#[cfg(feature = "locales")]
+pub use locale::{Locale, LocalizedPeriod};
+pub use lookup::{asof_join, nearest, Direction};
+pub use nullable::{is_null_sentinel, parse_optional, parse_optional_with, NULL_SENTINELS};
+pub use parsed::{DetectedFormat, ParsedDateTime};
+pub use parts::{decompose, DateParts};
+pub use pattern::{find_dates, Pattern};
+pub use period::{
+    canonicalize_period, day_of_quarter, lag_periods, lead_periods, period_length, period_pacing,
+    periods, periods_since, quarter_progress, until_end_of, until_next, week_of_quarter,
+    CompactPeriod, Frequency, HalfYear, Pacing, Period, PeriodNotation, Periods, Quarter, Unit,
+};
+#[cfg(feature = "business")]
+pub use publication::{apply_publication_lag, latest_published_period, PublicationLag};
+pub use range::{
+    count_weekdays, exclusive_end, fraction_elapsed, inclusive_end, prorate, stitch_ranges,
+    truncate_range_to, weekday_distribution, Anchor, BoundKind, DateTimeRange, StitchError,
+};
+pub use release::{ReleaseDay, ReleaseSchedule, Releases};
+pub use retention::RetentionPolicy;
+#[cfg(feature = "rand")]
+pub use sample::{sample_dates, sample_dates_stratified, Rng, Strata};
+#[cfg(feature = "parsing")]
+pub use scanner::{LogFormat, TimestampScanner};
+pub use season::{season_of, solar_event, Hemisphere, Season, SeasonRule, SolarEvent};
+#[cfg(feature = "business")]
+pub use sla::Sla;
+pub use slug::{parse_timestamp_slug, timestamp_slug, Resolution};
+pub use stats::{
+    bucket_by_time_of_day, estimate_offset, histogram, mean_datetime, series_stats,
+    weighted_mean_datetime, Dimension, SeriesStats,
+};
+#[cfg(feature = "parsing")]
+pub use text::{parse_range, parse_range_with, parse_textual_date};
+#[cfg(feature = "business")]
+pub use ticks::business_day_ticks;
+#[cfg(feature = "rand")]
+pub use ticks::jittered_ticks;
+pub use ticks::ticks;
+#[cfg(all(feature = "tz", feature = "business"))]
+pub use tz::is_business_day_in_tz;
+#[cfg(feature = "tz")]
+pub use tz::{local_date_in_tz, weekday_in_tz, with_same_instant, with_same_wall_clock};
+pub use vintage::VintagePoint;
+pub use week::{parse_week_label, week_label, LabelStyle, WeekRule};
+pub use window::{current_window, current_window_at, Window, WindowSpec};
+
+/// Julian day of the Unix epoch (1970-01-01).
+const UNIX_EPOCH_JULIAN_DAY: i32 = 2_440_588;
+
 /// Represents error related to dates parsing.
 #[derive(Debug, Error)]
 pub enum DateTimeError {
@@ -20,10 +153,36 @@ pub enum DateTimeError {
     InvalidTimeComponent(String),
     #[error("Provided date '{0}' is in the future: '{0}' > '{1}'.")]
     DateInFuture(String, String),
+    #[error("Provided date '{0}' is in the past: '{0}' < '{1}'.")]
+    DateInPast(String, String),
     #[error("Failed to convert offset timestamp '{0}' into offset: {1}")]
     InvalidOffset(i32, String),
     #[error("Parsing failed: {0}")]
     ParseError(String),
+    #[error("Cannot compute statistics of an empty series.")]
+    EmptySeries,
+    #[error("Invalid business hours configuration: {0}.")]
+    InvalidBusinessHours(String),
+    #[error("Date computation out of supported range: {0}.")]
+    OutOfRange(String),
+    #[error("Invalid futures contract specification: {0}.")]
+    InvalidContractSpec(String),
+    #[error("Invalid range: start '{0}' is after end '{1}'.")]
+    InvalidRange(String, String),
+    #[error("February 29 does not exist in year {0}.")]
+    InvalidLeapDay(i32),
+    #[error("Datetime '{0}' is before the Unix epoch.")]
+    BeforeEpoch(String),
+    #[error("Failed to parse duration '{0}': {1}.")]
+    InvalidDuration(String, String),
+    #[error("I/O error: {0}.")]
+    Io(String),
+    #[error("Failed to format datetime '{0}': {1}.")]
+    FormatError(String, String),
+    #[error("Ambiguous range '{0}': it can be read as {1}.")]
+    AmbiguousRange(String, String),
+    #[error("Invalid {0} component: {1}.")]
+    InvalidComponent(&'static str, String),
 }
 
 impl From<time::error::IndeterminateOffset> for DateTimeError {
@@ -32,6 +191,12 @@ impl From<time::error::IndeterminateOffset> for DateTimeError {
     }
 }
 
+impl From<std::io::Error> for DateTimeError {
+    fn from(err: std::io::Error) -> Self {
+        DateTimeError::Io(err.to_string())
+    }
+}
+
 /// Represents the date type.
 ///
 /// - `DateType::Start`: It indicates the starting date
@@ -50,12 +215,44 @@ pub enum OffsetType {
     Utc,
 }
 
+/// Represents how the offset written in a datetime string is handled.
+///
+/// - `OffsetHandling::Preserve`: It indicates that the datetime keeps the offset of the input (e.g. for
+///   auditing the source)
+/// - `OffsetHandling::ConvertToUtc`: It indicates that the datetime is converted to UTC
+/// - `OffsetHandling::ConvertToLocal`: It indicates that the datetime is converted to the local offset
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OffsetHandling {
+    Preserve,
+    ConvertToUtc,
+    ConvertToLocal,
+}
+
+/// Represents which datetimes are accepted relative to the current time.
+///
+/// - `TemporalPolicy::RejectFuture`: It rejects the datetimes after the current time (default), e.g. for
+///   observation dates
+/// - `TemporalPolicy::RejectPast`: It rejects the datetimes before the current time, e.g. for expiry
+///   dates, scheduled jobs and deadlines
+/// - `TemporalPolicy::Any`: It accepts any datetime
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum TemporalPolicy {
+    #[default]
+    RejectFuture,
+    RejectPast,
+    Any,
+}
+
 /// Converts the start or end date into datetime.
 ///
 /// The function takes a date as argument and converts it into a datetime object.
 /// It returns an OffsetDateTime object representing the date passed as argument. If the conversion fails
 /// it returns a `DateTimeError`.
 ///
+/// Besides `YYYY-MM-DD` dates, the function accepts the keywords `today`, `yesterday`, `eom` (last day of
+/// the current month), `eoy` (last day of the current year) and `now`, resolved against the system clock.
+/// See `parse_to_datetime_at` for details.
+///
 /// ## Returns
 /// - `Ok(OffsetDateTime)`: An `OffsetDateTime` representing the date passed as argument.
 /// - `Err(DateTimeError)`: If the conversion fails.
@@ -71,21 +268,221 @@ pub fn parse_to_datetime(
     date: &str,
     date_type: DateType,
     offset_type: OffsetType,
+) -> Result<OffsetDateTime, DateTimeError> {
+    parse_to_datetime_at(date, date_type, offset_type, &SystemClock)
+}
+
+/// Converts the start or end date into datetime, resolving keywords and the future check against the
+/// given clock.
+///
+/// Besides `YYYY-MM-DD` dates, the function accepts the following case-insensitive keywords:
+/// - `today`, `yesterday`: the current and previous dates
+/// - `eom`, `eoy`: the last day of the current month and of the current year
+/// - `now`: the current instant, regardless of the date type
+///
+/// Dates are evaluated in UTC, as explicit dates are. Unlike explicit dates, keywords are not rejected
+/// when they resolve to the future (e.g. `eom` or `today` as an end date), since they are relative to the
+/// current time by definition.
+///
+/// ## Arguments
+/// - `date: &str`: The date or keyword.
+/// - `date_type: DateType`: Whether to return the start or the end of the date.
+/// - `offset_type: OffsetType`: The offset of the returned datetime.
+/// - `clock: &impl Clock`: The source of the current time.
+///
+/// ## Returns
+/// - `Ok(OffsetDateTime)`: An `OffsetDateTime` representing the date passed as argument.
+/// - `Err(DateTimeError)`: If the conversion fails.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::{parse_to_datetime_at, DateType, FixedClock, OffsetType};
+/// use time::macros::datetime;
+///
+/// let clock = FixedClock::new(datetime!(2024-02-10 15:30 UTC));
+/// let eom = parse_to_datetime_at("eom", DateType::End, OffsetType::Utc, &clock).unwrap();
+/// assert_eq!(eom, datetime!(2024-02-29 23:59:59.999999999 UTC));
+/// let yesterday = parse_to_datetime_at("yesterday", DateType::Start, OffsetType::Utc, &clock).unwrap();
+/// assert_eq!(yesterday, datetime!(2024-02-09 0:00 UTC));
+/// ```
+pub fn parse_to_datetime_at(
+    date: &str,
+    date_type: DateType,
+    offset_type: OffsetType,
+    clock: &impl Clock,
+) -> Result<OffsetDateTime, DateTimeError> {
+    parse_to_datetime_with(
+        date,
+        date_type,
+        offset_type,
+        TemporalPolicy::RejectFuture,
+        clock,
+    )
+}
+
+/// Converts the start or end date into datetime, as `parse_to_datetime_at` does, validating explicit
+/// dates against the given policy instead of rejecting the future.
+///
+/// ## Arguments
+/// - `date: &str`: The date or keyword.
+/// - `date_type: DateType`: Whether to return the start or the end of the date.
+/// - `offset_type: OffsetType`: The offset of the returned datetime.
+/// - `policy: TemporalPolicy`: Which datetimes are accepted relative to the current time.
+/// - `clock: &impl Clock`: The source of the current time.
+///
+/// ## Returns
+/// - `Ok(OffsetDateTime)`: An `OffsetDateTime` representing the date passed as argument.
+/// - `Err(DateTimeError)`: If the conversion fails, or the datetime is rejected by the policy.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::{parse_to_datetime_with, DateType, FixedClock, OffsetType, TemporalPolicy};
+/// use time::macros::datetime;
+///
+/// let clock = FixedClock::new(datetime!(2024-02-10 15:30 UTC));
+/// let parse = |date, policy| parse_to_datetime_with(date, DateType::End, OffsetType::Utc, policy, &clock);
+/// assert!(parse("2024-12-31", TemporalPolicy::RejectPast).is_ok());
+/// assert!(parse("2024-12-31", TemporalPolicy::RejectFuture).is_err());
+/// assert!(parse("2023-12-31", TemporalPolicy::RejectPast).is_err());
+/// assert!(parse("2023-12-31", TemporalPolicy::Any).is_ok());
+/// ```
+pub fn parse_to_datetime_with(
+    date: &str,
+    date_type: DateType,
+    offset_type: OffsetType,
+    policy: TemporalPolicy,
+    clock: &impl Clock,
+) -> Result<OffsetDateTime, DateTimeError> {
+    let now = clock.now().to_offset(UtcOffset::UTC);
+    let today = now.date();
+    let out_of_range =
+        |err: time::error::ComponentRange| DateTimeError::OutOfRange(err.to_string());
+    let keyword_date = match date.trim().to_ascii_lowercase().as_str() {
+        "now" => return with_offset_type(now, offset_type),
+        "today" => Some(today),
+        "yesterday" => Some(
+            today
+                .previous_day()
+                .ok_or_else(|| DateTimeError::OutOfRange(date.to_string()))?,
+        ),
+        "eom" => Some(
+            today
+                .replace_day(today.month().length(today.year()))
+                .map_err(out_of_range)?,
+        ),
+        "eoy" => Some(
+            Date::from_calendar_date(today.year(), Month::December, 31).map_err(out_of_range)?,
+        ),
+        _ => None,
+    };
+    let Some(keyword_date) = keyword_date else {
+        let result = parse_date_to_datetime(date, date_type, offset_type)?;
+        match policy {
+            TemporalPolicy::RejectFuture => validate_not_in_future(result, now)?,
+            TemporalPolicy::RejectPast => validate_in_future(result, now)?,
+            TemporalPolicy::Any => {}
+        }
+        return Ok(result);
+    };
+    with_offset_type(day_bound(keyword_date, date_type).assume_utc(), offset_type)
+}
+
+/// Converts a `YYYY-MM-DD` date into the datetime of its start or end, without resolving keywords nor
+/// validating it against the current time.
+fn parse_date_to_datetime(
+    date: &str,
+    date_type: DateType,
+    offset_type: OffsetType,
 ) -> Result<OffsetDateTime, DateTimeError> {
     let date_fmt = format_description!("[year]-[month]-[day]");
-    let date = Date::parse(date, date_fmt)
+    let parsed_date = Date::parse(date, date_fmt)
         .map_err(|err| DateTimeError::InvalidDateFormat(date.to_string(), err.to_string()))?;
-    let primitive_datetime = match date_type {
+    with_offset_type(day_bound(parsed_date, date_type).assume_utc(), offset_type)
+}
+
+/// Returns the first or the last instant of the date, according to the date type.
+fn day_bound(date: Date, date_type: DateType) -> PrimitiveDateTime {
+    match date_type {
         DateType::Start => PrimitiveDateTime::new(date, Time::MIDNIGHT),
         DateType::End => PrimitiveDateTime::new(date, Time::MAX),
+    }
+}
+
+/// Parses a datetime string, optionally followed by an offset, into a datetime.
+///
+/// The function accepts `YYYY-MM-DD HH:MM:SS` datetimes, with a `T` or a space between date and time,
+/// optional fractional seconds and an optional offset (`Z`, `+02:00`, `+0200` or `+02`, possibly after a
+/// space). Datetimes without an offset are assumed to be in UTC.
+///
+/// ## Arguments
+/// - `input: &str`: The datetime string.
+/// - `offset_handling: OffsetHandling`: Whether to keep the offset of the input or to convert the datetime.
+///
+/// ## Returns
+/// - `Ok(OffsetDateTime)`: The parsed datetime.
+/// - `Err(DateTimeError)`: If the input is not a valid datetime, or the local offset cannot be determined.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::{parse_datetime, OffsetHandling};
+/// use time::macros::datetime;
+///
+/// let input = "2024-05-31 12:00:00+02:00";
+/// let preserved = parse_datetime(input, OffsetHandling::Preserve).unwrap();
+/// assert_eq!(preserved, datetime!(2024-05-31 12:00 +2));
+/// assert_eq!(preserved.offset().whole_hours(), 2);
+/// let utc = parse_datetime(input, OffsetHandling::ConvertToUtc).unwrap();
+/// assert_eq!(utc.to_string(), "2024-05-31 10:00:00.0 +00:00:00");
+/// ```
+pub fn parse_datetime(
+    input: &str,
+    offset_handling: OffsetHandling,
+) -> Result<OffsetDateTime, DateTimeError> {
+    let invalid = || DateTimeError::InvalidDateTimeFormat(input.to_string());
+    let trimmed = input.trim();
+    // The offset starts after the date and the time, at the first sign or `Z`.
+    let offset_start = trimmed
+        .char_indices()
+        .skip("YYYY-MM-DD HH:MM:SS".len())
+        .find(|(_, c)| matches!(c, '+' | '-' | 'Z' | 'z'))
+        .map_or(trimmed.len(), |(index, _)| index);
+    let (datetime, offset) = trimmed.split_at(offset_start);
+
+    let datetime_fmt = format_description!(
+        "[year]-[month]-[day][first [T][ ]][hour]:[minute]:[second][optional [.[subsecond]]]"
+    );
+    let datetime =
+        PrimitiveDateTime::parse(datetime.trim_end(), datetime_fmt).map_err(|_| invalid())?;
+    let offset_fmts = [
+        format_description!("[offset_hour sign:mandatory]:[offset_minute]"),
+        format_description!("[offset_hour sign:mandatory][offset_minute]"),
+        format_description!("[offset_hour sign:mandatory]"),
+    ];
+    let offset = match offset {
+        "" | "Z" | "z" => UtcOffset::UTC,
+        offset => offset_fmts
+            .iter()
+            .find_map(|offset_fmt| UtcOffset::parse(offset, offset_fmt).ok())
+            .ok_or_else(invalid)?,
     };
-    let datetime_utc = primitive_datetime.assume_utc();
-    let result = match offset_type {
+
+    let datetime = datetime.assume_offset(offset);
+    Ok(match offset_handling {
+        OffsetHandling::Preserve => datetime,
+        OffsetHandling::ConvertToUtc => datetime.to_offset(UtcOffset::UTC),
+        OffsetHandling::ConvertToLocal => datetime.to_offset(UtcOffset::local_offset_at(datetime)?),
+    })
+}
+
+/// Converts a UTC datetime to the given offset type.
+fn with_offset_type(
+    datetime_utc: OffsetDateTime,
+    offset_type: OffsetType,
+) -> Result<OffsetDateTime, DateTimeError> {
+    Ok(match offset_type {
         OffsetType::Utc => datetime_utc,
         OffsetType::Local => datetime_utc.to_offset(UtcOffset::local_offset_at(datetime_utc)?),
-    };
-    validate_not_in_future(result)?;
-    Ok(result)
+    })
 }
 
 /// Validates that given `OffsetDateTime` is not in the future
@@ -96,8 +493,10 @@ pub fn parse_to_datetime(
 /// ## Returns
 /// - `OK(())`: The `OffsetDateTime` is validated correctly and it is not in the future.
 /// - `Err(DateTimeError)`: If the `OffsetDateTime` is in the future.
-fn validate_not_in_future(datetime: OffsetDateTime) -> Result<(), DateTimeError> {
-    let now = OffsetDateTime::now_utc();
+pub fn validate_not_in_future(
+    datetime: OffsetDateTime,
+    now: OffsetDateTime,
+) -> Result<(), DateTimeError> {
     if datetime > now {
         return Err(DateTimeError::DateInFuture(
             datetime.to_string(),
@@ -107,6 +506,37 @@ fn validate_not_in_future(datetime: OffsetDateTime) -> Result<(), DateTimeError>
     Ok(())
 }
 
+/// Validates that given `OffsetDateTime` is not in the past.
+///
+/// The function checks whether the `OffsetDateTime` object is before the current time, e.g. for expiry
+/// dates and deadlines.
+///
+/// ## Returns
+/// - `Ok(())`: The `OffsetDateTime` is not before `now`.
+/// - `Err(DateTimeError)`: If the `OffsetDateTime` is in the past.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::validate_in_future;
+/// use time::macros::datetime;
+///
+/// let now = datetime!(2024-05-31 12:00 UTC);
+/// assert!(validate_in_future(datetime!(2024-06-30 0:00 UTC), now).is_ok());
+/// assert!(validate_in_future(datetime!(2024-05-01 0:00 UTC), now).is_err());
+/// ```
+pub fn validate_in_future(
+    datetime: OffsetDateTime,
+    now: OffsetDateTime,
+) -> Result<(), DateTimeError> {
+    if datetime < now {
+        return Err(DateTimeError::DateInPast(
+            datetime.to_string(),
+            now.to_string(),
+        ));
+    }
+    Ok(())
+}
+
 /// Converts the timestamp into datetime.
 ///
 /// The function takes a timestamp as argument and converts it into a datetime object.
@@ -160,6 +590,48 @@ pub fn datetime_to_date(date: OffsetDateTime) -> Result<Date, DateTimeError> {
     Ok(date.date())
 }
 
+/// Converts the date into the number of days since the Unix epoch (1970-01-01).
+///
+/// This is the representation of dates used by Arrow `Date32`, Parquet and PostgreSQL, among others.
+///
+/// ## Returns
+/// - `i64`: The number of days since 1970-01-01, negative for earlier dates.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::date_to_epoch_days;
+/// use time::macros::date;
+///
+/// assert_eq!(date_to_epoch_days(date!(2024 - 05 - 31)), 19874);
+/// ```
+pub fn date_to_epoch_days(date: Date) -> i64 {
+    (date.to_julian_day() - UNIX_EPOCH_JULIAN_DAY) as i64
+}
+
+/// Converts a number of days since the Unix epoch (1970-01-01) into the date.
+///
+/// ## Returns
+/// - `Ok(Date)`: The date the number of days after (or before, if negative) 1970-01-01.
+/// - `Err(DateTimeError)`: If the date is out of the supported date range.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::epoch_days_to_date;
+/// use time::macros::date;
+///
+/// assert_eq!(epoch_days_to_date(19874).unwrap(), date!(2024 - 05 - 31));
+/// ```
+pub fn epoch_days_to_date(days: i64) -> Result<Date, DateTimeError> {
+    i32::try_from(days)
+        .ok()
+        .and_then(|days| days.checked_add(UNIX_EPOCH_JULIAN_DAY))
+        .ok_or_else(|| DateTimeError::OutOfRange(format!("{days} days since the Unix epoch")))
+        .and_then(|julian_day| {
+            Date::from_julian_day(julian_day)
+                .map_err(|err| DateTimeError::OutOfRange(err.to_string()))
+        })
+}
+
 /// Converts the offset (in timestamp notation) into offset.
 ///
 /// The function takes an offset, expressed in seconds, as argument and converts it into an offset object.
@@ -190,6 +662,8 @@ pub fn timestamp_to_offset(offset_secs: i32) -> Result<UtcOffset, DateTimeError>
 /// - **full date**: `"YYYY-MM-DD"` (e.g. `"2024-05-31`)
 /// - **year-month**: `"YYYY-MM"` (e.g. `"2024-05"`), defaults to the first day of the month at midnight UTC.
 /// - **quarterly**: `"YYYY-QN"` (e.g. `"2024-Q2"`), maps to the first day of the starting month of the quarter at midnight UTC.
+///   The alternative notations accepted by `Quarter` (e.g. `"Q2 2024"`, `"2024Q2"`, `"2Q24"`) are supported as well.
+/// - **half-yearly**: `"YYYY-HN"` or `"YYYY-SN"` (e.g. `"2024-H2"`), maps to the first day of the half-year at midnight UTC, in any notation accepted by `HalfYear`.
 ///
 /// ## Arguments
 /// - `time_period`: A string slice representing a time period in one of the supported formats.
@@ -216,46 +690,56 @@ pub fn parse_response_string_to_datetime(
     time_period: &str,
     offset_type: OffsetType,
 ) -> Result<OffsetDateTime, DateTimeError> {
-    // Handle full date: YYYY-MM-DD
-    if let Ok(date) = parse_to_datetime(time_period, DateType::End, offset_type) {
-        return Ok(date);
+    detect_response_string(time_period, offset_type).map(|(datetime, _)| datetime)
+}
+
+/// Parses a time period string as `parse_response_string_to_datetime` does, returning the detected format
+/// along with the datetime.
+pub(crate) fn detect_response_string(
+    time_period: &str,
+    offset_type: OffsetType,
+) -> Result<(OffsetDateTime, DetectedFormat), DateTimeError> {
+    // Handle full date: YYYY-MM-DD, without the keywords accepted by `parse_to_datetime`
+    if let Ok(date) = parse_date_to_datetime(time_period, DateType::End, offset_type)
+        && validate_not_in_future(date, OffsetDateTime::now_utc()).is_ok()
+    {
+        return Ok((date, DetectedFormat::Date));
     }
 
     // Handle year-month: YYYY-MM
-    if let Some((year_str, month_str)) = time_period.split_once("-") {
-        if let (Ok(year), Ok(month)) = (year_str.parse::<i32>(), month_str.parse::<u8>()) {
-            let date = Date::from_calendar_date(
-                year,
-                Month::try_from(month).map_err(|err| {
-                    DateTimeError::InvalidDateFormat(time_period.to_string(), err.to_string())
-                })?,
-                1,
-            )
-            .map_err(|err| DateTimeError::ParseError(err.to_string()))?;
-            let datetime = PrimitiveDateTime::new(date, Time::MIDNIGHT).assume_utc();
-            return Ok(datetime);
+    if let Some((year_str, month_str)) = time_period.split_once("-")
+        && let (Ok(year), Ok(month)) = (year_str.parse::<i32>(), month_str.parse::<u8>())
+    {
+        let date = Date::from_calendar_date(
+            year,
+            Month::try_from(month).map_err(|err| {
+                DateTimeError::InvalidDateFormat(time_period.to_string(), err.to_string())
+            })?,
+            1,
+        )
+        .map_err(|err| DateTimeError::ParseError(err.to_string()))?;
+        let datetime = PrimitiveDateTime::new(date, Time::MIDNIGHT).assume_utc();
+        return Ok((datetime, DetectedFormat::YearMonth));
+    }
+
+    // Handle half-year formats: "2023-H1", "2023-S1", "H1 2023", "1H23"
+    match time_period.parse::<HalfYear>() {
+        Ok(half) => {
+            let datetime = PrimitiveDateTime::new(half.first_day(), Time::MIDNIGHT).assume_utc();
+            return Ok((datetime, DetectedFormat::HalfYear));
         }
+        Err(DateTimeError::InvalidDateFormat(..)) => {}
+        Err(err) => return Err(err),
     }
 
-    // Handle quarterly format: "2023-Q1"
-    if time_period.len() == 7 && &time_period[5..6] == "Q" {
-        let year = time_period[0..4].parse::<i32>().map_err(|err| {
-            DateTimeError::InvalidDateFormat(time_period.to_string(), err.to_string())
-        })?;
-        let quarter = time_period[6..7].parse::<u8>().map_err(|err| {
-            DateTimeError::InvalidDateFormat(time_period.to_string(), err.to_string())
-        })?;
-        let month = match quarter {
-            1 => Month::January,
-            2 => Month::April,
-            3 => Month::July,
-            4 => Month::October,
-            _ => return Err(DateTimeError::InvalidTimeComponent(quarter.to_string())),
-        };
-        let date = Date::from_calendar_date(year, month, 1)
-            .map_err(|err| DateTimeError::ParseError(err.to_string()))?;
-        let datetime = PrimitiveDateTime::new(date, Time::MIDNIGHT).assume_utc();
-        return Ok(datetime);
+    // Handle quarterly formats: "2023-Q1", "Q1 2023", "2023Q1", "1Q23"
+    match time_period.parse::<Quarter>() {
+        Ok(quarter) => {
+            let datetime = PrimitiveDateTime::new(quarter.first_day(), Time::MIDNIGHT).assume_utc();
+            return Ok((datetime, DetectedFormat::Quarter));
+        }
+        Err(DateTimeError::InvalidDateFormat(..)) => {}
+        Err(err) => return Err(err),
     }
     Err(DateTimeError::ParseError(format!(
         "Unsupported date format: {time_period}"
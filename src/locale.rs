@@ -0,0 +1,142 @@
+//! Locale Module
+//!
+//! This module contains the localized labels of periods, used as headings of reports written in
+//! several languages.
+use std::fmt;
+
+use time::Month;
+
+use crate::Period;
+
+/// Represents the language of the localized labels.
+///
+/// - `Locale::English`: It indicates English labels (e.g. `Q2 2024`, `May 2024`)
+/// - `Locale::Italian`: It indicates Italian labels (e.g. `2° trimestre 2024`, `maggio 2024`)
+/// - `Locale::Japanese`: It indicates Japanese labels (e.g. `2024年第2四半期`, `2024年5月`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Locale {
+    English,
+    Italian,
+    Japanese,
+}
+
+/// Displays a period as a localized label.
+///
+/// | Period       | `Locale::English` | `Locale::Italian`   | `Locale::Japanese` |
+/// | ------------ | ----------------- | ------------------- | ------------------ |
+/// | `2024`       | `2024`            | `2024`              | `2024年`           |
+/// | `2024-H1`    | `H1 2024`         | `1° semestre 2024`  | `2024年上半期`     |
+/// | `2024-Q2`    | `Q2 2024`         | `2° trimestre 2024` | `2024年第2四半期`  |
+/// | `2024-05`    | `May 2024`        | `maggio 2024`       | `2024年5月`        |
+/// | `2024-W05`   | `Week 5, 2024`    | `settimana 5, 2024` | `2024年第5週`      |
+/// | `2024-05-31` | `May 31, 2024`    | `31 maggio 2024`    | `2024年5月31日`    |
+///
+/// ## Example
+/// ```rust
+/// use date_utils::{Locale, LocalizedPeriod, Period, Quarter};
+///
+/// let quarter = Quarter::new(2024, 2).unwrap();
+/// assert_eq!(LocalizedPeriod(quarter.into(), Locale::Italian).to_string(), "2° trimestre 2024");
+/// assert_eq!(LocalizedPeriod(Period::Quarter(2024, 2), Locale::English).to_string(), "Q2 2024");
+/// assert_eq!(LocalizedPeriod(Period::Quarter(2024, 2), Locale::Japanese).to_string(), "2024年第2四半期");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LocalizedPeriod(pub Period, pub Locale);
+
+impl fmt::Display for LocalizedPeriod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.0, self.1) {
+            (Period::Year(year), Locale::English | Locale::Italian) => write!(f, "{year}"),
+            (Period::Year(year), Locale::Japanese) => write!(f, "{year}年"),
+
+            (Period::HalfYear(year, half), Locale::English) => write!(f, "H{half} {year}"),
+            (Period::HalfYear(year, half), Locale::Italian) => write!(f, "{half}° semestre {year}"),
+            (Period::HalfYear(year, half), Locale::Japanese) => {
+                let half = if half == 1 { "上" } else { "下" };
+                write!(f, "{year}年{half}半期")
+            }
+
+            (Period::Quarter(year, quarter), Locale::English) => write!(f, "Q{quarter} {year}"),
+            (Period::Quarter(year, quarter), Locale::Italian) => {
+                write!(f, "{quarter}° trimestre {year}")
+            }
+            (Period::Quarter(year, quarter), Locale::Japanese) => {
+                write!(f, "{year}年第{quarter}四半期")
+            }
+
+            (Period::Month(year, month), Locale::English | Locale::Italian) => {
+                write!(f, "{} {year}", month_name(month, self.1))
+            }
+            (Period::Month(year, month), Locale::Japanese) => {
+                write!(f, "{year}年{}", month_name(month, Locale::Japanese))
+            }
+
+            (Period::Week(year, week), Locale::English) => write!(f, "Week {week}, {year}"),
+            (Period::Week(year, week), Locale::Italian) => write!(f, "settimana {week}, {year}"),
+            (Period::Week(year, week), Locale::Japanese) => write!(f, "{year}年第{week}週"),
+
+            (Period::Day(date), Locale::English) => write!(
+                f,
+                "{} {}, {}",
+                month_name(date.month(), Locale::English),
+                date.day(),
+                date.year()
+            ),
+            (Period::Day(date), Locale::Italian) => write!(
+                f,
+                "{} {} {}",
+                date.day(),
+                month_name(date.month(), Locale::Italian),
+                date.year()
+            ),
+            (Period::Day(date), Locale::Japanese) => write!(
+                f,
+                "{}年{}{}日",
+                date.year(),
+                month_name(date.month(), Locale::Japanese),
+                date.day()
+            ),
+        }
+    }
+}
+
+/// Returns the name of the month in the given locale, as used within a sentence.
+fn month_name(month: Month, locale: Locale) -> &'static str {
+    const ENGLISH: [&str; 12] = [
+        "January",
+        "February",
+        "March",
+        "April",
+        "May",
+        "June",
+        "July",
+        "August",
+        "September",
+        "October",
+        "November",
+        "December",
+    ];
+    const ITALIAN: [&str; 12] = [
+        "gennaio",
+        "febbraio",
+        "marzo",
+        "aprile",
+        "maggio",
+        "giugno",
+        "luglio",
+        "agosto",
+        "settembre",
+        "ottobre",
+        "novembre",
+        "dicembre",
+    ];
+    const JAPANESE: [&str; 12] = [
+        "1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月",
+    ];
+    let names = match locale {
+        Locale::English => ENGLISH,
+        Locale::Italian => ITALIAN,
+        Locale::Japanese => JAPANESE,
+    };
+    names[month as usize - 1]
+}
@@ -0,0 +1,106 @@
+//! Lookup Module
+//!
+//! This module contains a set of methods to find, in sorted series of datetimes, the datetimes closest to
+//! given instants, and to align two series with as-of joins.
+use time::{Duration, OffsetDateTime};
+
+/// Represents the direction in which the closest datetime is searched.
+///
+/// - `Direction::Backward`: It indicates the last datetime at or before the target
+/// - `Direction::Forward`: It indicates the first datetime at or after the target
+/// - `Direction::Either`: It indicates the closest datetime in either direction, the earlier one on ties
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Backward,
+    Forward,
+    Either,
+}
+
+/// Finds the datetime closest to the target in a sorted series, using a binary search.
+///
+/// Datetimes are compared as instants. When the series contains the target or the closest datetime
+/// more than once, the search backward returns the last occurrence and the search forward the first
+/// one.
+///
+/// ## Arguments
+/// - `sorted: &[OffsetDateTime]`: The series, sorted in ascending order.
+/// - `target: OffsetDateTime`: The instant to look up.
+/// - `direction: Direction`: The direction of the search.
+/// - `tolerance: Option<Duration>`: The maximum distance from the target, if any.
+///
+/// ## Returns
+/// - `Option<(usize, OffsetDateTime)>`: The index and the value of the closest datetime, or `None` if
+///   there is none in the direction within the tolerance.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::{nearest, Direction};
+/// use time::{macros::datetime, Duration};
+///
+/// let observations = [datetime!(2024-05-01 0:00 UTC), datetime!(2024-06-01 0:00 UTC)];
+/// let query = datetime!(2024-05-20 0:00 UTC);
+/// assert_eq!(nearest(&observations, query, Direction::Backward, None), Some((0, observations[0])));
+/// assert_eq!(nearest(&observations, query, Direction::Either, None), Some((1, observations[1])));
+/// assert_eq!(nearest(&observations, query, Direction::Backward, Some(Duration::days(7))), None);
+/// ```
+pub fn nearest(
+    sorted: &[OffsetDateTime],
+    target: OffsetDateTime,
+    direction: Direction,
+    tolerance: Option<Duration>,
+) -> Option<(usize, OffsetDateTime)> {
+    let backward = || {
+        let index = sorted.partition_point(|datetime| *datetime <= target);
+        index.checked_sub(1).map(|index| (index, sorted[index]))
+    };
+    let forward = || {
+        let index = sorted.partition_point(|datetime| *datetime < target);
+        sorted.get(index).map(|datetime| (index, *datetime))
+    };
+    let distance = |(_, datetime): &(usize, OffsetDateTime)| (*datetime - target).abs();
+    let found = match direction {
+        Direction::Backward => backward(),
+        Direction::Forward => forward(),
+        Direction::Either => match (backward(), forward()) {
+            (Some(before), Some(after)) if distance(&after) < distance(&before) => Some(after),
+            (Some(before), _) => Some(before),
+            (None, after) => after,
+        },
+    };
+    found.filter(|found| tolerance.is_none_or(|tolerance| distance(found) <= tolerance))
+}
+
+/// Aligns two series of datetimes, mapping each datetime of the left series to the closest datetime of
+/// the right series (e.g. each market data timestamp to the latest macro release).
+///
+/// Each left datetime is matched as `nearest` does, so the right series must be sorted while the left
+/// series may be in any order.
+///
+/// ## Arguments
+/// - `left: &[OffsetDateTime]`: The datetimes to match.
+/// - `right: &[OffsetDateTime]`: The datetimes to match against, sorted in ascending order.
+/// - `tolerance: Option<Duration>`: The maximum distance between matched datetimes, if any.
+/// - `direction: Direction`: The direction in which right datetimes are searched.
+///
+/// ## Returns
+/// - `Vec<Option<usize>>`: For each left datetime, the index of the matched right datetime, or `None`.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::{asof_join, Direction};
+/// use time::macros::datetime;
+///
+/// let trades = [datetime!(2024-05-31 9:00 UTC), datetime!(2024-06-07 9:00 UTC)];
+/// let releases = [datetime!(2024-05-03 12:30 UTC), datetime!(2024-06-07 12:30 UTC)];
+/// assert_eq!(asof_join(&trades, &releases, None, Direction::Backward), [Some(0), Some(0)]);
+/// ```
+pub fn asof_join(
+    left: &[OffsetDateTime],
+    right: &[OffsetDateTime],
+    tolerance: Option<Duration>,
+    direction: Direction,
+) -> Vec<Option<usize>> {
+    left.iter()
+        .map(|datetime| nearest(right, *datetime, direction, tolerance).map(|(index, _)| index))
+        .collect()
+}
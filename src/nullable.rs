@@ -0,0 +1,104 @@
+//! Nullable Fields Module
+//!
+//! This module contains a set of methods to parse nullable date fields, treating the placeholders
+//! commonly found in CSV/JSON feeds (empty strings, `"null"`, `"NaT"`, `"-"`) as missing values.
+use time::OffsetDateTime;
+
+use crate::{parse_response_string_to_datetime, DateTimeError, OffsetType};
+
+/// The placeholders always treated as missing values, compared case-insensitively.
+pub const NULL_SENTINELS: &[&str] = &["", "null", "NaT", "-"];
+
+/// Checks whether the value is a missing value placeholder.
+///
+/// The function trims the value and compares it case-insensitively against `NULL_SENTINELS` and the
+/// additional sentinels passed as argument.
+///
+/// ## Arguments
+/// - `value: &str`: The raw field value.
+/// - `extra_sentinels: &[&str]`: Additional placeholders to treat as missing values (e.g. `"N/A"`).
+///
+/// ## Returns
+/// - `bool`: `true` if the value is a missing value placeholder.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::is_null_sentinel;
+///
+/// assert!(is_null_sentinel(" NULL ", &[]));
+/// assert!(is_null_sentinel("N/A", &["n/a"]));
+/// assert!(!is_null_sentinel("2024-05-31", &[]));
+/// ```
+pub fn is_null_sentinel(value: &str, extra_sentinels: &[&str]) -> bool {
+    let value = value.trim();
+    NULL_SENTINELS
+        .iter()
+        .chain(extra_sentinels)
+        .any(|sentinel| sentinel.trim().eq_ignore_ascii_case(value))
+}
+
+/// Parses a nullable time period string into an optional `OffsetDateTime`.
+///
+/// The function returns `Ok(None)` for the placeholders listed in `NULL_SENTINELS`, and otherwise parses
+/// the trimmed value with `parse_response_string_to_datetime`.
+///
+/// ## Arguments
+/// - `value: &str`: The raw field value.
+/// - `offset_type: OffsetType`: The offset used for full dates.
+///
+/// ## Returns
+/// - `Ok(Some(OffsetDateTime))`: The parsed datetime.
+/// - `Ok(None)`: If the value is a missing value placeholder.
+/// - `Err(DateTimeError)`: If the value is neither a placeholder nor a supported time period.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::{parse_optional, OffsetType};
+///
+/// assert!(parse_optional("NaT", OffsetType::Utc).unwrap().is_none());
+/// assert!(parse_optional("2024-Q2", OffsetType::Utc).unwrap().is_some());
+/// ```
+pub fn parse_optional(
+    value: &str,
+    offset_type: OffsetType,
+) -> Result<Option<OffsetDateTime>, DateTimeError> {
+    parse_optional_with(value, &[], |value| {
+        parse_response_string_to_datetime(value, offset_type)
+    })
+}
+
+/// Parses a nullable field with the given parser.
+///
+/// The function returns `Ok(None)` for the placeholders listed in `NULL_SENTINELS` and in
+/// `extra_sentinels`, and otherwise calls the parser with the trimmed value.
+///
+/// ## Arguments
+/// - `value: &str`: The raw field value.
+/// - `extra_sentinels: &[&str]`: Additional placeholders to treat as missing values.
+/// - `parse`: The parser applied to non-placeholder values.
+///
+/// ## Returns
+/// - `Ok(Some(T))`: The parsed value.
+/// - `Ok(None)`: If the value is a missing value placeholder.
+/// - `Err(E)`: If the parser fails.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::{parse_optional_with, parse_to_datetime, DateType, OffsetType};
+///
+/// let parsed = parse_optional_with("N/A", &["N/A"], |value| {
+///     parse_to_datetime(value, DateType::Start, OffsetType::Utc)
+/// })
+/// .unwrap();
+/// assert!(parsed.is_none());
+/// ```
+pub fn parse_optional_with<T, E>(
+    value: &str,
+    extra_sentinels: &[&str],
+    parse: impl FnOnce(&str) -> Result<T, E>,
+) -> Result<Option<T>, E> {
+    if is_null_sentinel(value, extra_sentinels) {
+        return Ok(None);
+    }
+    parse(value.trim()).map(Some)
+}
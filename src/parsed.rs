@@ -0,0 +1,112 @@
+//! Parsed DateTime Module
+//!
+//! This module contains the `ParsedDateTime` type, which keeps the original input and the detected format
+//! of a parsed datetime, so ingestion pipelines can trace every value back to the exact text producing
+//! it.
+use std::{borrow::Cow, fmt};
+
+use time::OffsetDateTime;
+
+use crate::{detect_response_string, parse_datetime, DateTimeError, OffsetHandling, OffsetType};
+
+/// Represents the format detected when parsing a datetime string.
+///
+/// - `DetectedFormat::Date`: It indicates a full date (`YYYY-MM-DD`)
+/// - `DetectedFormat::YearMonth`: It indicates a year and month (`YYYY-MM`)
+/// - `DetectedFormat::Quarter`: It indicates a quarter (e.g. `2024-Q2`, `Q2 2024`)
+/// - `DetectedFormat::HalfYear`: It indicates a half-year (e.g. `2024-H1`, `2024-S1`)
+/// - `DetectedFormat::DateTime`: It indicates a datetime with an optional offset
+///   (`YYYY-MM-DD HH:MM:SS+02:00`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DetectedFormat {
+    Date,
+    YearMonth,
+    Quarter,
+    HalfYear,
+    DateTime,
+}
+
+/// Represents a parsed datetime, along with the format detected and the original input.
+///
+/// The original input is borrowed when possible; `into_owned` detaches it from the input (e.g. to keep
+/// the value after the buffer it was read from is reused). `Display` writes the original input.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::{DetectedFormat, OffsetType, ParsedDateTime};
+/// use time::macros::datetime;
+///
+/// let parsed = ParsedDateTime::parse("2024-Q2", OffsetType::Utc).unwrap();
+/// assert_eq!(parsed.datetime(), datetime!(2024-04-01 0:00 UTC));
+/// assert_eq!(parsed.format(), DetectedFormat::Quarter);
+/// assert_eq!(format!("parsed from '{parsed}'"), "parsed from '2024-Q2'");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ParsedDateTime<'a> {
+    datetime: OffsetDateTime,
+    format: DetectedFormat,
+    original: Cow<'a, str>,
+}
+
+impl<'a> ParsedDateTime<'a> {
+    /// Parses a time period or a datetime string, detecting its format.
+    ///
+    /// Time periods are parsed as `parse_response_string_to_datetime` does. Other inputs are parsed as
+    /// datetimes by `parse_datetime`, keeping the offset of the input.
+    ///
+    /// ## Arguments
+    /// - `input: impl Into<Cow<'a, str>>`: The input, borrowed (`&str`) or owned (`String`).
+    /// - `offset_type: OffsetType`: The offset used for full dates.
+    ///
+    /// ## Returns
+    /// - `Ok(ParsedDateTime)`: The parsed datetime, with its format and the input.
+    /// - `Err(DateTimeError)`: If the input is neither a supported time period nor a datetime.
+    pub fn parse(
+        input: impl Into<Cow<'a, str>>,
+        offset_type: OffsetType,
+    ) -> Result<Self, DateTimeError> {
+        let original = input.into();
+        let (datetime, format) = match detect_response_string(&original, offset_type) {
+            Ok(detected) => detected,
+            Err(err) => match parse_datetime(&original, OffsetHandling::Preserve) {
+                Ok(datetime) => (datetime, DetectedFormat::DateTime),
+                Err(_) => return Err(err),
+            },
+        };
+        Ok(Self {
+            datetime,
+            format,
+            original,
+        })
+    }
+
+    /// Returns the parsed datetime.
+    pub fn datetime(&self) -> OffsetDateTime {
+        self.datetime
+    }
+
+    /// Returns the format detected in the input.
+    pub fn format(&self) -> DetectedFormat {
+        self.format
+    }
+
+    /// Returns the original input.
+    pub fn original(&self) -> &str {
+        &self.original
+    }
+
+    /// Converts the parsed datetime into one owning its original input.
+    pub fn into_owned(self) -> ParsedDateTime<'static> {
+        ParsedDateTime {
+            datetime: self.datetime,
+            format: self.format,
+            original: Cow::Owned(self.original.into_owned()),
+        }
+    }
+}
+
+impl fmt::Display for ParsedDateTime<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.original)
+    }
+}
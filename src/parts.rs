@@ -0,0 +1,71 @@
+//! Date Parts Module
+//!
+//! This module contains the `DateParts` type and a method to decompose a datetime into all its calendar
+//! and clock components at once.
+use time::{Month, OffsetDateTime, Weekday};
+
+use crate::period::day_of_quarter;
+
+/// Represents the calendar and clock components of a datetime, evaluated in its offset.
+///
+/// - `year`, `month`, `day`: The calendar date.
+/// - `quarter`: The quarter of the year, from 1 to 4.
+/// - `iso_year`, `week`: The ISO week-numbering year and week (from 1 to 53). The ISO year differs from
+///   `year` around January 1st.
+/// - `day_of_year`, `day_of_quarter`: The day of the year and of the quarter, starting from 1.
+/// - `weekday`: The day of the week.
+/// - `hour`, `minute`, `second`, `nanosecond`: The time of day.
+/// - `offset_seconds`: The offset from UTC, in seconds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DateParts {
+    pub year: i32,
+    pub quarter: u8,
+    pub month: Month,
+    pub iso_year: i32,
+    pub week: u8,
+    pub day: u8,
+    pub day_of_year: u16,
+    pub day_of_quarter: u16,
+    pub weekday: Weekday,
+    pub hour: u8,
+    pub minute: u8,
+    pub second: u8,
+    pub nanosecond: u32,
+    pub offset_seconds: i32,
+}
+
+/// Decomposes a datetime into its calendar and clock components.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::decompose;
+/// use time::{macros::datetime, Month, Weekday};
+///
+/// let parts = decompose(datetime!(2024-12-30 12:30 +1));
+/// assert_eq!(parts.quarter, 4);
+/// assert_eq!(parts.month, Month::December);
+/// assert_eq!((parts.iso_year, parts.week), (2025, 1));
+/// assert_eq!(parts.weekday, Weekday::Monday);
+/// assert_eq!(parts.day_of_year, 365);
+/// assert_eq!(parts.offset_seconds, 3600);
+/// ```
+pub fn decompose(datetime: OffsetDateTime) -> DateParts {
+    let date = datetime.date();
+    let (iso_year, week, weekday) = date.to_iso_week_date();
+    DateParts {
+        year: date.year(),
+        quarter: (date.month() as u8 - 1) / 3 + 1,
+        month: date.month(),
+        iso_year,
+        week,
+        day: date.day(),
+        day_of_year: date.ordinal(),
+        day_of_quarter: day_of_quarter(date),
+        weekday,
+        hour: datetime.hour(),
+        minute: datetime.minute(),
+        second: datetime.second(),
+        nanosecond: datetime.nanosecond(),
+        offset_seconds: datetime.offset().whole_seconds(),
+    }
+}
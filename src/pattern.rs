@@ -0,0 +1,115 @@
+//! Date Pattern Module
+//!
+//! This module contains the `Pattern` type, which expresses calendar rules combining month, day of month
+//! and weekday (e.g. Friday the 13th, February 29th, first Monday of the month), and a method to find the
+//! dates matching them.
+use time::{Date, Duration, Month, Weekday};
+
+use crate::DateTimeRange;
+
+/// Represents a calendar rule matched by dates.
+///
+/// Every constraint is optional, and a date matches the pattern if it satisfies all the constraints set.
+/// An empty pattern matches every date.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::Pattern;
+/// use time::{macros::date, Month, Weekday};
+///
+/// let friday_13th = Pattern::new().day(13).weekday(Weekday::Friday);
+/// assert!(friday_13th.matches(date!(2024 - 09 - 13)));
+///
+/// let leap_day = Pattern::new().month(Month::February).day(29);
+/// assert!(leap_day.matches(date!(2024 - 02 - 29)));
+///
+/// let first_monday = Pattern::new().nth_weekday(1, Weekday::Monday);
+/// assert!(first_monday.matches(date!(2024 - 07 - 01)));
+///
+/// let last_friday = Pattern::new().nth_weekday(-1, Weekday::Friday);
+/// assert!(last_friday.matches(date!(2024 - 05 - 31)));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Pattern {
+    month: Option<Month>,
+    day: Option<u8>,
+    weekday: Option<Weekday>,
+    nth: Option<i8>,
+}
+
+impl Pattern {
+    /// Creates an empty pattern, matching every date.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Restricts the pattern to the given month.
+    pub fn month(mut self, month: Month) -> Self {
+        self.month = Some(month);
+        self
+    }
+
+    /// Restricts the pattern to the given day of month.
+    pub fn day(mut self, day: u8) -> Self {
+        self.day = Some(day);
+        self
+    }
+
+    /// Restricts the pattern to the given weekday.
+    pub fn weekday(mut self, weekday: Weekday) -> Self {
+        self.weekday = Some(weekday);
+        self
+    }
+
+    /// Restricts the pattern to the nth occurrence of the weekday in the month.
+    ///
+    /// Positive values count from the start of the month (`1` for the first occurrence), negative values
+    /// count from the end of the month (`-1` for the last occurrence).
+    pub fn nth_weekday(mut self, nth: i8, weekday: Weekday) -> Self {
+        self.weekday = Some(weekday);
+        self.nth = Some(nth);
+        self
+    }
+
+    /// Checks whether the date satisfies every constraint of the pattern.
+    pub fn matches(&self, date: Date) -> bool {
+        let (year, month, day) = date.to_calendar_date();
+        let occurrence = || {
+            if self.nth? > 0 {
+                Some((day as i8 - 1) / 7 + 1)
+            } else {
+                Some(-((month.length(year) as i8 - day as i8) / 7 + 1))
+            }
+        };
+        self.month.is_none_or(|expected| expected == month)
+            && self.day.is_none_or(|expected| expected == day)
+            && self
+                .weekday
+                .is_none_or(|expected| expected == date.weekday())
+            && self
+                .nth
+                .is_none_or(|expected| occurrence() == Some(expected))
+    }
+}
+
+/// Finds the calendar dates covered by the range matching the pattern.
+///
+/// Calendar dates are evaluated in the offset of the start of the range, and both the first and last
+/// dates are evaluated even if only partially covered.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::{find_dates, DateTimeRange, Pattern};
+/// use time::{macros::{date, datetime}, Weekday};
+///
+/// let range = DateTimeRange::new(datetime!(2024-01-01 0:00 UTC), datetime!(2024-12-31 0:00 UTC)).unwrap();
+/// let dates = find_dates(&range, &Pattern::new().day(13).weekday(Weekday::Friday));
+/// assert_eq!(dates, vec![date!(2024 - 09 - 13), date!(2024 - 12 - 13)]);
+/// ```
+pub fn find_dates(range: &DateTimeRange, pattern: &Pattern) -> Vec<Date> {
+    let (first, last) = range.dates();
+    (0..=(last - first).whole_days())
+        .filter_map(|days| first.checked_add(Duration::days(days)))
+        .filter(|date| pattern.matches(*date))
+        .collect()
+}
@@ -0,0 +1,1207 @@
+//! Period Module
+//!
+//! This module contains a set of methods to handle calendar periods (days, weeks, months, quarters,
+//! half-years, years) and their frequencies.
+use std::{cmp::Ordering, fmt, str::FromStr};
+
+use time::{
+    error::ComponentRange,
+    util::{is_leap_year, weeks_in_year},
+    Date, Duration, Month, OffsetDateTime, Time, Weekday,
+};
+
+use crate::{
+    date_to_epoch_days, epoch_days_to_date,
+    fiscal::{first_of_month, months_since_year0},
+    DateTimeError, DateTimeRange, Feb29Policy,
+};
+
+/// Represents the frequency of a calendar period.
+///
+/// - `Frequency::Daily`: It indicates a calendar day
+/// - `Frequency::Weekly`: It indicates an ISO week (Monday to Sunday)
+/// - `Frequency::Monthly`: It indicates a calendar month
+/// - `Frequency::Quarterly`: It indicates a calendar quarter (January, April, July, October)
+/// - `Frequency::Semiannual`: It indicates a half-year (January to June, July to December)
+/// - `Frequency::Annual`: It indicates a calendar year
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Frequency {
+    Daily,
+    Weekly,
+    Monthly,
+    Quarterly,
+    Semiannual,
+    Annual,
+}
+
+/// Computes the exact length of the period containing the reference date.
+///
+/// The function takes a frequency and a reference date, and returns the actual length of the period
+/// of that frequency containing the reference date. Months are 28 to 31 days long, quarters 90 to 92
+/// days long and years 365 or 366 days long, depending on the reference date.
+///
+/// ## Arguments
+/// - `frequency: Frequency`: The frequency of the period.
+/// - `reference_date: Date`: A date falling into the period.
+///
+/// ## Returns
+/// - `Duration`: The length of the period.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::{period_length, Frequency};
+/// use time::{macros::date, Duration};
+///
+/// assert_eq!(period_length(Frequency::Monthly, date!(2024 - 02 - 10)), Duration::days(29));
+/// assert_eq!(period_length(Frequency::Annual, date!(2023 - 06 - 30)), Duration::days(365));
+/// ```
+pub fn period_length(frequency: Frequency, reference_date: Date) -> Duration {
+    let year = reference_date.year();
+    let days = match frequency {
+        Frequency::Daily => 1,
+        Frequency::Weekly => 7,
+        Frequency::Monthly => reference_date.month().length(year) as i64,
+        Frequency::Quarterly => {
+            let first = quarter_first_month(reference_date.month());
+            (0..3)
+                .map(|offset| first.nth_next(offset).length(year) as i64)
+                .sum()
+        }
+        Frequency::Semiannual => {
+            let first = half_first_month(reference_date.month());
+            (0..6)
+                .map(|offset| first.nth_next(offset).length(year) as i64)
+                .sum()
+        }
+        Frequency::Annual => {
+            if is_leap_year(year) {
+                366
+            } else {
+                365
+            }
+        }
+    };
+    Duration::days(days)
+}
+
+/// Returns the day of the quarter (starting from 1) of the given date.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::day_of_quarter;
+/// use time::macros::date;
+///
+/// assert_eq!(day_of_quarter(date!(2024 - 04 - 01)), 1);
+/// assert_eq!(day_of_quarter(date!(2024 - 05 - 31)), 61);
+/// ```
+pub fn day_of_quarter(date: Date) -> u16 {
+    let first_month = quarter_first_month(date.month());
+    let days_before: u16 = (0..date.month() as u8 - first_month as u8)
+        .map(|offset| first_month.nth_next(offset).length(date.year()) as u16)
+        .sum();
+    days_before + date.day() as u16
+}
+
+/// Returns the week of the quarter (starting from 1) of the given date.
+///
+/// Weeks are counted in blocks of 7 days from the first day of the quarter, so the first week of the
+/// quarter spans its first 7 days regardless of the weekday it starts on.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::week_of_quarter;
+/// use time::macros::date;
+///
+/// assert_eq!(week_of_quarter(date!(2024 - 04 - 07)), 1);
+/// assert_eq!(week_of_quarter(date!(2024 - 06 - 30)), 13);
+/// ```
+pub fn week_of_quarter(date: Date) -> u8 {
+    ((day_of_quarter(date) - 1) / 7 + 1) as u8
+}
+
+/// Computes the fraction of the quarter completed at the end of the given date.
+///
+/// ## Returns
+/// - `f64`: A value in `(0.0, 1.0]`, equal to `1.0` on the last day of the quarter.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::quarter_progress;
+/// use time::macros::date;
+///
+/// assert_eq!(quarter_progress(date!(2024 - 06 - 30)), 1.0);
+/// assert!((quarter_progress(date!(2024 - 05 - 15)) - 45.0 / 91.0).abs() < 1e-12);
+/// ```
+pub fn quarter_progress(date: Date) -> f64 {
+    day_of_quarter(date) as f64 / period_length(Frequency::Quarterly, date).whole_days() as f64
+}
+
+/// Represents a fixed-length unit of time, aligned on the clock.
+///
+/// - `Unit::Second`: It indicates a second
+/// - `Unit::Minute`: It indicates a minute
+/// - `Unit::Hour`: It indicates an hour
+/// - `Unit::Day`: It indicates a day, from midnight to midnight
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Unit {
+    Second,
+    Minute,
+    Hour,
+    Day,
+}
+
+/// Computes the duration until the end of the unit of time containing the datetime.
+///
+/// The end of the unit is the start of the next one (e.g. the next full hour), evaluated in the offset of
+/// the datetime, so the returned duration is always positive.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::{until_end_of, Unit};
+/// use time::{macros::datetime, Duration};
+///
+/// assert_eq!(until_end_of(datetime!(2024-05-31 12:45 UTC), Unit::Hour), Duration::minutes(15));
+/// assert_eq!(until_end_of(datetime!(2024-05-31 0:00 +2), Unit::Day), Duration::DAY);
+/// ```
+pub fn until_end_of(datetime: OffsetDateTime, unit: Unit) -> Duration {
+    let unit_nanos: i64 = match unit {
+        Unit::Second => 1_000_000_000,
+        Unit::Minute => 60_000_000_000,
+        Unit::Hour => 3_600_000_000_000,
+        Unit::Day => 86_400_000_000_000,
+    };
+    let (hour, minute, second, nanosecond) = datetime.to_hms_nano();
+    let nanos_of_day = ((hour as i64 * 60 + minute as i64) * 60 + second as i64) * 1_000_000_000
+        + nanosecond as i64;
+    Duration::nanoseconds(unit_nanos - nanos_of_day % unit_nanos)
+}
+
+/// Computes the duration until the start of the period following the one containing the datetime.
+///
+/// The period boundary is midnight, in the offset of the datetime, of the first day of the next period,
+/// so the returned duration is always positive.
+///
+/// ## Arguments
+/// - `datetime: OffsetDateTime`: The reference datetime.
+/// - `frequency: Frequency`: The frequency of the periods.
+///
+/// ## Returns
+/// - `Ok(Duration)`: The duration until the next period boundary.
+/// - `Err(DateTimeError)`: If the next period starts beyond the supported date range.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::{until_next, Frequency};
+/// use time::{macros::datetime, Duration};
+///
+/// let duration = until_next(datetime!(2024-06-30 18:00 +2), Frequency::Quarterly).unwrap();
+/// assert_eq!(duration, Duration::hours(6));
+/// ```
+pub fn until_next(
+    datetime: OffsetDateTime,
+    frequency: Frequency,
+) -> Result<Duration, DateTimeError> {
+    let next = period_bounds(frequency, datetime.date())
+        .and_then(|(_, last)| last.next_day())
+        .ok_or_else(|| DateTimeError::OutOfRange(datetime.to_string()))?;
+    Ok(next.midnight().assume_offset(datetime.offset()) - datetime)
+}
+
+/// Represents the progress through the current period, as returned by `period_pacing`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Pacing {
+    pub elapsed_fraction: f64,
+    pub remaining: Duration,
+    pub current_period: Period,
+}
+
+/// Computes the progress through the period of the given frequency containing the datetime, for budget
+/// pacing and quota dashboards.
+///
+/// The period runs from midnight of its first day to midnight of the first day of the next period, in
+/// the offset of the datetime.
+///
+/// ## Arguments
+/// - `now: OffsetDateTime`: The reference datetime.
+/// - `frequency: Frequency`: The frequency of the periods.
+///
+/// ## Returns
+/// - `Ok(Pacing)`: The fraction of the period elapsed (from 0 included to 1 excluded), the duration until
+///   the next period and the current period.
+/// - `Err(DateTimeError)`: If the period extends beyond the supported date range.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::{period_pacing, Frequency, Period};
+/// use time::{macros::datetime, Duration, Month};
+///
+/// let pacing = period_pacing(datetime!(2024-04-16 0:00 UTC), Frequency::Monthly).unwrap();
+/// assert_eq!(pacing.elapsed_fraction, 0.5);
+/// assert_eq!(pacing.remaining, Duration::days(15));
+/// assert_eq!(pacing.current_period, Period::Month(2024, Month::April));
+/// ```
+pub fn period_pacing(now: OffsetDateTime, frequency: Frequency) -> Result<Pacing, DateTimeError> {
+    let (first, _) = period_bounds(frequency, now.date())
+        .ok_or_else(|| DateTimeError::OutOfRange(now.to_string()))?;
+    let start = first.midnight().assume_offset(now.offset());
+    let remaining = until_next(now, frequency)?;
+    Ok(Pacing {
+        elapsed_fraction: (now - start).as_seconds_f64()
+            / (now + remaining - start).as_seconds_f64(),
+        remaining,
+        current_period: Period::containing(frequency, now.date()),
+    })
+}
+
+/// Counts the whole periods elapsed since an anchor and returns the range of the current period.
+///
+/// Periods are anchored on the anchor datetime rather than on the calendar: with a monthly frequency and
+/// an anchor on January 15th at 10:00, periods start on the 15th of each month at 10:00. When the day of
+/// the anchor does not exist in a month, the period starts on the last day of that month (e.g. February
+/// 29th for an anchor on January 31st), without drifting for the following periods. The only exception is
+/// an anchor on February 29, whose periods falling on February of non-leap years start where the
+/// `Feb29Policy` decides. Periods are evaluated in the offset of the anchor.
+///
+/// ## Arguments
+/// - `anchor: OffsetDateTime`: The start of the first period (e.g. the signup datetime).
+/// - `now: OffsetDateTime`: The reference datetime.
+/// - `frequency: Frequency`: The length of the periods.
+/// - `feb29: Feb29Policy`: The handling of an anchor on February 29 in non-leap years.
+///
+/// ## Returns
+/// - `Ok((u64, DateTimeRange))`: The number of whole periods elapsed (0 during the first period) and the
+///   range of the current period, both ends included.
+/// - `Err(DateTimeError)`: If `now` is before `anchor`, the current period ends beyond the supported
+///   date range, or the anchor is on February 29 and the period starts or ends in a non-leap year with
+///   `Feb29Policy::Error`.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::{periods_since, Feb29Policy, Frequency};
+/// use time::macros::datetime;
+///
+/// let signup = datetime!(2024-01-31 10:00 UTC);
+/// let now = datetime!(2024-03-15 0:00 UTC);
+/// let (elapsed, current) = periods_since(signup, now, Frequency::Monthly, Feb29Policy::default()).unwrap();
+/// assert_eq!(elapsed, 1);
+/// assert_eq!(current.start(), datetime!(2024-02-29 10:00 UTC));
+/// assert_eq!(current.end(), datetime!(2024-03-31 09:59:59.999999999 UTC));
+///
+/// let signup = datetime!(2024-02-29 10:00 UTC);
+/// let now = datetime!(2025-03-15 0:00 UTC);
+/// let (_, current) = periods_since(signup, now, Frequency::Annual, Feb29Policy::MoveToMar1).unwrap();
+/// assert_eq!(current.start(), datetime!(2025-03-01 10:00 UTC));
+/// ```
+pub fn periods_since(
+    anchor: OffsetDateTime,
+    now: OffsetDateTime,
+    frequency: Frequency,
+    feb29: Feb29Policy,
+) -> Result<(u64, DateTimeRange), DateTimeError> {
+    if now < anchor {
+        return Err(DateTimeError::InvalidRange(
+            anchor.to_string(),
+            now.to_string(),
+        ));
+    }
+    let now = now.to_offset(anchor.offset());
+    let out_of_range = || DateTimeError::OutOfRange(now.to_string());
+    let (days, months) = match frequency {
+        Frequency::Daily => (1, 0),
+        Frequency::Weekly => (7, 0),
+        Frequency::Monthly => (0, 1),
+        Frequency::Quarterly => (0, 3),
+        Frequency::Semiannual => (0, 6),
+        Frequency::Annual => (0, 12),
+    };
+    let nth_start = |n: i64| -> Result<OffsetDateTime, DateTimeError> {
+        if months == 0 {
+            return anchor
+                .checked_add(Duration::days(n * days))
+                .ok_or_else(out_of_range);
+        }
+        let total = anchor.year() as i64 * 12 + anchor.month() as i64 - 1 + n * months;
+        let year = i32::try_from(total.div_euclid(12)).map_err(|_| out_of_range())?;
+        let month = Month::try_from(total.rem_euclid(12) as u8 + 1).map_err(|_| out_of_range())?;
+        let leap_day = (anchor.month(), anchor.day()) == (Month::February, 29);
+        let date = match (month, anchor.day()) {
+            (Month::February, 29) if leap_day => feb29.date(year, month, 29)?,
+            (_, day) => Date::from_calendar_date(year, month, day.min(month.length(year)))
+                .map_err(|_| out_of_range())?,
+        };
+        Ok(date.with_time(anchor.time()).assume_offset(anchor.offset()))
+    };
+    let mut elapsed = if months == 0 {
+        (now - anchor).whole_days() / days
+    } else {
+        ((now.year() - anchor.year()) as i64 * 12 + now.month() as i64 - anchor.month() as i64)
+            / months
+    };
+    // The estimate ignores the day and time of the anchor, so it may be one period too many.
+    if nth_start(elapsed)? > now {
+        elapsed -= 1;
+    }
+    let start = nth_start(elapsed)?;
+    let end = nth_start(elapsed + 1)? - Duration::NANOSECOND;
+    Ok((elapsed as u64, DateTimeRange::new(start, end)?))
+}
+
+/// Represents a calendar quarter of a year.
+///
+/// The canonical notation is `YYYY-QN` (e.g. `2024-Q2`), used by `Display`. `FromStr` also accepts the
+/// alternative notations found in earnings reports and research notes, case-insensitively:
+/// - `2024Q2`, `2024 Q2`, `2024/Q2`
+/// - `Q2 2024`, `Q2-2024`, `Q2/2024`, `Q22024`
+/// - `2Q24`, `2Q2024`, `Q2'24`, `Q2 24`
+///
+/// Two-digit years are interpreted as years of the 21st century (`24` is `2024`).
+///
+/// ## Example
+/// ```rust
+/// use date_utils::Quarter;
+/// use time::macros::date;
+///
+/// let quarter: Quarter = "2Q24".parse().unwrap();
+/// assert_eq!(quarter, Quarter::new(2024, 2).unwrap());
+/// assert_eq!(quarter.to_string(), "2024-Q2");
+/// assert_eq!(quarter.first_day(), date!(2024 - 04 - 01));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Quarter {
+    year: i32,
+    quarter: u8,
+}
+
+impl Quarter {
+    /// Creates the given quarter (from 1 to 4) of the given year.
+    ///
+    /// ## Returns
+    /// - `Ok(Quarter)`: The quarter.
+    /// - `Err(DateTimeError)`: If the quarter is not between 1 and 4, or the year is out of the supported
+    ///   range.
+    pub fn new(year: i32, quarter: u8) -> Result<Self, DateTimeError> {
+        if !(1..=4).contains(&quarter) {
+            return Err(DateTimeError::InvalidTimeComponent(quarter.to_string()));
+        }
+        Date::from_calendar_date(year, Month::January, 1)
+            .map_err(|err| DateTimeError::OutOfRange(err.to_string()))?;
+        Ok(Self { year, quarter })
+    }
+
+    /// Returns the quarter containing the given date.
+    pub fn containing(date: Date) -> Self {
+        Self {
+            year: date.year(),
+            quarter: (date.month() as u8 - 1) / 3 + 1,
+        }
+    }
+
+    /// Returns the year of the quarter.
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// Returns the number of the quarter, from 1 to 4.
+    pub fn quarter(&self) -> u8 {
+        self.quarter
+    }
+
+    /// Returns the first day of the quarter.
+    pub fn first_day(&self) -> Date {
+        let month = Month::January.nth_next(3 * (self.quarter - 1));
+        Date::from_calendar_date(self.year, month, 1).expect("quarter year is validated")
+    }
+
+    /// Returns the last day of the quarter.
+    pub fn last_day(&self) -> Date {
+        let month = Month::March.nth_next(3 * (self.quarter - 1));
+        Date::from_calendar_date(self.year, month, month.length(self.year))
+            .expect("quarter year is validated")
+    }
+
+    /// Shifts the quarter by the given number of quarters (e.g. `2024-Q2` shifted by -4 is `2023-Q2`).
+    ///
+    /// ## Returns
+    /// - `Ok(Quarter)`: The shifted quarter.
+    /// - `Err(DateTimeError)`: If the shifted quarter is out of the supported range.
+    pub fn shift(&self, quarters: i32) -> Result<Self, DateTimeError> {
+        let (year, quarter) = shift_ordinal(self.year, self.quarter, 4, quarters)?;
+        Quarter::new(year, quarter)
+    }
+
+    /// Expands the quarter into the range from its first instant to its last instant, in UTC.
+    pub fn range(&self) -> DateTimeRange {
+        days_range(self.first_day(), self.last_day())
+    }
+}
+
+impl fmt::Display for Quarter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-Q{}", self.year, self.quarter)
+    }
+}
+
+impl From<Quarter> for Period {
+    fn from(quarter: Quarter) -> Self {
+        Period::Quarter(quarter.year, quarter.quarter)
+    }
+}
+
+impl FromStr for Quarter {
+    type Err = DateTimeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (year, quarter) = parse_year_and_number(
+            s,
+            &['Q'],
+            "a quarter such as 2024-Q2, Q2 2024, 2024Q2 or 2Q24",
+        )?;
+        Quarter::new(year, quarter)
+    }
+}
+
+/// Represents a half of a year.
+///
+/// The canonical notation is `YYYY-HN` (e.g. `2024-H1`), used by `Display`. `FromStr` also accepts the
+/// SDMX semester notation `YYYY-SN` (e.g. `2024-S1`) and the same alternative notations as `Quarter`
+/// (e.g. `H1 2024`, `2024H1`, `1H24`), case-insensitively.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::HalfYear;
+/// use time::macros::date;
+///
+/// let half: HalfYear = "2024-S2".parse().unwrap();
+/// assert_eq!(half.to_string(), "2024-H2");
+/// assert_eq!(half.first_day(), date!(2024 - 07 - 01));
+/// assert_eq!(half.last_day(), date!(2024 - 12 - 31));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct HalfYear {
+    year: i32,
+    half: u8,
+}
+
+impl HalfYear {
+    /// Creates the given half (1 or 2) of the given year.
+    ///
+    /// ## Returns
+    /// - `Ok(HalfYear)`: The half-year.
+    /// - `Err(DateTimeError)`: If the half is not 1 or 2, or the year is out of the supported range.
+    pub fn new(year: i32, half: u8) -> Result<Self, DateTimeError> {
+        if !(1..=2).contains(&half) {
+            return Err(DateTimeError::InvalidTimeComponent(half.to_string()));
+        }
+        Date::from_calendar_date(year, Month::January, 1)
+            .map_err(|err| DateTimeError::OutOfRange(err.to_string()))?;
+        Ok(Self { year, half })
+    }
+
+    /// Returns the half-year containing the given date.
+    pub fn containing(date: Date) -> Self {
+        Self {
+            year: date.year(),
+            half: (date.month() as u8 - 1) / 6 + 1,
+        }
+    }
+
+    /// Returns the year of the half-year.
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// Returns the number of the half, 1 or 2.
+    pub fn half(&self) -> u8 {
+        self.half
+    }
+
+    /// Returns the first day of the half-year.
+    pub fn first_day(&self) -> Date {
+        let month = Month::January.nth_next(6 * (self.half - 1));
+        Date::from_calendar_date(self.year, month, 1).expect("half-year year is validated")
+    }
+
+    /// Returns the last day of the half-year.
+    pub fn last_day(&self) -> Date {
+        let (month, day) = if self.half == 1 {
+            (Month::June, 30)
+        } else {
+            (Month::December, 31)
+        };
+        Date::from_calendar_date(self.year, month, day).expect("half-year year is validated")
+    }
+
+    /// Shifts the half-year by the given number of half-years.
+    ///
+    /// ## Returns
+    /// - `Ok(HalfYear)`: The shifted half-year.
+    /// - `Err(DateTimeError)`: If the shifted half-year is out of the supported range.
+    pub fn shift(&self, halves: i32) -> Result<Self, DateTimeError> {
+        let (year, half) = shift_ordinal(self.year, self.half, 2, halves)?;
+        HalfYear::new(year, half)
+    }
+
+    /// Expands the half-year into the range from its first instant to its last instant, in UTC.
+    pub fn range(&self) -> DateTimeRange {
+        days_range(self.first_day(), self.last_day())
+    }
+}
+
+impl fmt::Display for HalfYear {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-H{}", self.year, self.half)
+    }
+}
+
+impl From<HalfYear> for Period {
+    fn from(half: HalfYear) -> Self {
+        Period::HalfYear(half.year, half.half)
+    }
+}
+
+impl FromStr for HalfYear {
+    type Err = DateTimeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (year, half) = parse_year_and_number(
+            s,
+            &['H', 'S'],
+            "a half-year such as 2024-H1, 2024-S1, H1 2024 or 1H24",
+        )?;
+        HalfYear::new(year, half)
+    }
+}
+
+/// Represents a calendar period of any frequency, such as the values of the `TIME_PERIOD` column of
+/// SDMX datasets.
+///
+/// The canonical notations, used by `Display`, are `2024`, `2024-H1`, `2024-Q2`, `2024-05`, `2024-W05`
+/// and `2024-05-31`. `FromStr` also accepts, case-insensitively, the SDMX reporting period notations
+/// (`2024-A1`, `2024-S1`, `2024-M05`), the compact notations without separator used in file names and
+/// cube dimensions (`2024H1`, `2024Q2`, `202405`, `2024W05`, `20240531`, rendered by `CompactPeriod`)
+/// and the alternative notations of `Quarter` and `HalfYear` (e.g. `Q2 2024`, `1H24`). Weeks are ISO
+/// weeks.
+///
+/// Periods are ordered by year, then from the coarsest to the finest frequency, then chronologically.
+///
+/// - `Period::Year`: It indicates a calendar year
+/// - `Period::HalfYear`: It indicates a half (1 or 2) of a year
+/// - `Period::Quarter`: It indicates a quarter (from 1 to 4) of a year
+/// - `Period::Month`: It indicates a calendar month
+/// - `Period::Week`: It indicates an ISO week (from 1 to 52 or 53) of an ISO year
+/// - `Period::Day`: It indicates a calendar day
+///
+/// ## Example
+/// ```rust
+/// use date_utils::{Frequency, Period};
+/// use time::{macros::date, Month};
+///
+/// let period: Period = "2024-M05".parse().unwrap();
+/// assert_eq!(period, Period::Month(2024, Month::May));
+/// assert_eq!(period.to_string(), "2024-05");
+/// assert_eq!(period.dates().unwrap(), (date!(2024 - 05 - 01), date!(2024 - 05 - 31)));
+/// assert_eq!(Period::containing(Frequency::Quarterly, date!(2024 - 05 - 31)).to_string(), "2024-Q2");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Period {
+    Year(i32),
+    HalfYear(i32, u8),
+    Quarter(i32, u8),
+    Month(i32, Month),
+    Week(i32, u8),
+    Day(Date),
+}
+
+impl Period {
+    /// Returns the period of the given frequency containing the given date.
+    pub fn containing(frequency: Frequency, date: Date) -> Self {
+        match frequency {
+            Frequency::Daily => Period::Day(date),
+            Frequency::Weekly => {
+                let (year, week, _) = date.to_iso_week_date();
+                Period::Week(year, week)
+            }
+            Frequency::Monthly => Period::Month(date.year(), date.month()),
+            Frequency::Quarterly => Period::Quarter(date.year(), (date.month() as u8 - 1) / 3 + 1),
+            Frequency::Semiannual => {
+                Period::HalfYear(date.year(), (date.month() as u8 - 1) / 6 + 1)
+            }
+            Frequency::Annual => Period::Year(date.year()),
+        }
+    }
+
+    /// Returns the frequency of the period.
+    pub fn frequency(&self) -> Frequency {
+        match self {
+            Period::Year(_) => Frequency::Annual,
+            Period::HalfYear(..) => Frequency::Semiannual,
+            Period::Quarter(..) => Frequency::Quarterly,
+            Period::Month(..) => Frequency::Monthly,
+            Period::Week(..) => Frequency::Weekly,
+            Period::Day(_) => Frequency::Daily,
+        }
+    }
+
+    /// Returns the year of the period (the ISO year for weeks).
+    pub fn year(&self) -> i32 {
+        match self {
+            Period::Year(year)
+            | Period::HalfYear(year, _)
+            | Period::Quarter(year, _)
+            | Period::Month(year, _)
+            | Period::Week(year, _) => *year,
+            Period::Day(date) => date.year(),
+        }
+    }
+
+    /// Returns the first and last dates of the period.
+    ///
+    /// ## Returns
+    /// - `Ok((Date, Date))`: The first and last dates of the period.
+    /// - `Err(DateTimeError)`: If the half, quarter or week does not exist in the year, or the period is
+    ///   out of the supported date range.
+    pub fn dates(&self) -> Result<(Date, Date), DateTimeError> {
+        match *self {
+            Period::Year(year) => {
+                let first = first_of_month(months_since_year0(year, 0)?)?;
+                let last =
+                    Date::from_calendar_date(year, Month::December, 31).expect("year is validated");
+                Ok((first, last))
+            }
+            Period::HalfYear(year, half) => {
+                let half = HalfYear::new(year, half)?;
+                Ok((half.first_day(), half.last_day()))
+            }
+            Period::Quarter(year, quarter) => {
+                let quarter = Quarter::new(year, quarter)?;
+                Ok((quarter.first_day(), quarter.last_day()))
+            }
+            Period::Month(year, month) => {
+                let first = first_of_month(months_since_year0(year, month as i32 - 1)?)?;
+                let last = first
+                    .replace_day(month.length(year))
+                    .expect("month length is a valid day");
+                Ok((first, last))
+            }
+            Period::Week(year, week) => {
+                if week == 0 || week > weeks_in_year(year) {
+                    return Err(DateTimeError::InvalidTimeComponent(week.to_string()));
+                }
+                let out_of_range = || DateTimeError::OutOfRange(format!("{year}-W{week:02}"));
+                let first = Date::from_iso_week_date(year, week, Weekday::Monday)
+                    .map_err(|_| out_of_range())?;
+                let last = first
+                    .checked_add(Duration::days(6))
+                    .ok_or_else(out_of_range)?;
+                Ok((first, last))
+            }
+            Period::Day(date) => Ok((date, date)),
+        }
+    }
+
+    /// Expands the period into the range from its first instant to its last instant, in UTC.
+    ///
+    /// ## Returns
+    /// - `Ok(DateTimeRange)`: The range covered by the period.
+    /// - `Err(DateTimeError)`: If the period is invalid or out of the supported date range.
+    pub fn range(&self) -> Result<DateTimeRange, DateTimeError> {
+        let (first, last) = self.dates()?;
+        Ok(days_range(first, last))
+    }
+
+    /// Encodes the period as a stable integer, suitable for database keys and hashing.
+    ///
+    /// The identifier is `ordinal * 8 + code`, where the code identifies the frequency and the ordinal
+    /// counts the periods of that frequency:
+    ///
+    /// | Frequency  | Code | Ordinal                                             |
+    /// | ---------- | ---- | --------------------------------------------------- |
+    /// | Annual     | 1    | `year`                                              |
+    /// | Semiannual | 2    | `year * 2 + half - 1`                               |
+    /// | Quarterly  | 3    | `year * 4 + quarter - 1`                            |
+    /// | Monthly    | 4    | `year * 12 + month - 1`                             |
+    /// | Weekly     | 5    | Weeks since the week starting on Monday, 1970-01-05 |
+    /// | Daily      | 6    | Days since 1970-01-01                               |
+    ///
+    /// Identifiers of a given frequency therefore sort chronologically, and consecutive periods have
+    /// identifiers 8 apart. The encoding is part of the public API and will not change.
+    ///
+    /// ## Returns
+    /// - `Ok(i64)`: The identifier of the period.
+    /// - `Err(DateTimeError)`: If the period is invalid or out of the supported date range.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use date_utils::Period;
+    ///
+    /// let id = Period::Quarter(2024, 2).stable_id().unwrap();
+    /// assert_eq!(id, (2024 * 4 + 1) * 8 + 3);
+    /// assert_eq!(Period::from_stable_id(id).unwrap(), Period::Quarter(2024, 2));
+    /// ```
+    pub fn stable_id(&self) -> Result<i64, DateTimeError> {
+        let (first, _) = self.dates()?;
+        let (ordinal, code) = match *self {
+            Period::Year(year) => (year as i64, 1),
+            Period::HalfYear(year, half) => (year as i64 * 2 + half as i64 - 1, 2),
+            Period::Quarter(year, quarter) => (year as i64 * 4 + quarter as i64 - 1, 3),
+            Period::Month(year, month) => (year as i64 * 12 + month as i64 - 1, 4),
+            Period::Week(..) => ((date_to_epoch_days(first) - 4).div_euclid(7), 5),
+            Period::Day(date) => (date_to_epoch_days(date), 6),
+        };
+        Ok(ordinal * 8 + code)
+    }
+
+    /// Decodes a period from its identifier, as returned by `stable_id`.
+    ///
+    /// ## Returns
+    /// - `Ok(Period)`: The period.
+    /// - `Err(DateTimeError)`: If the frequency code is unknown, or the period is out of the supported
+    ///   date range.
+    pub fn from_stable_id(id: i64) -> Result<Self, DateTimeError> {
+        let ordinal = id.div_euclid(8);
+        let out_of_range = || DateTimeError::OutOfRange(format!("period identifier {id}"));
+        let year = |periods_per_year: i64| {
+            i32::try_from(ordinal.div_euclid(periods_per_year)).map_err(|_| out_of_range())
+        };
+        let number = |periods_per_year: i64| ordinal.rem_euclid(periods_per_year) as u8 + 1;
+        let period = match id.rem_euclid(8) {
+            1 => Period::Year(year(1)?),
+            2 => Period::HalfYear(year(2)?, number(2)),
+            3 => Period::Quarter(year(4)?, number(4)),
+            4 => Period::Month(
+                year(12)?,
+                Month::try_from(number(12)).expect("month number is between 1 and 12"),
+            ),
+            5 => {
+                let days = ordinal.checked_mul(7).and_then(|days| days.checked_add(4));
+                let monday = epoch_days_to_date(days.ok_or_else(out_of_range)?)?;
+                let (year, week, _) = monday.to_iso_week_date();
+                Period::Week(year, week)
+            }
+            6 => Period::Day(epoch_days_to_date(ordinal)?),
+            code => {
+                return Err(DateTimeError::InvalidComponent(
+                    "frequency",
+                    format!("{code} is not a frequency code"),
+                ));
+            }
+        };
+        period.dates()?;
+        Ok(period)
+    }
+
+    /// Shifts the period by the given number of periods of its frequency, with year rollover (e.g.
+    /// `2024-01` shifted by -1 is `2023-12`).
+    ///
+    /// ## Returns
+    /// - `Ok(Period)`: The shifted period.
+    /// - `Err(DateTimeError)`: If the period is invalid, or the shifted period is out of the supported
+    ///   date range.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use date_utils::Period;
+    ///
+    /// assert_eq!(Period::Quarter(2024, 2).shift(-4).unwrap(), Period::Quarter(2023, 2));
+    /// assert_eq!(Period::Week(2020, 53).shift(1).unwrap(), Period::Week(2021, 1));
+    /// ```
+    pub fn shift(&self, periods: i32) -> Result<Self, DateTimeError> {
+        // Stable identifiers of consecutive periods are 8 apart.
+        let id = self.stable_id()?;
+        let shifted = id
+            .checked_add(periods as i64 * 8)
+            .ok_or_else(|| DateTimeError::OutOfRange(format!("{self} shifted by {periods}")))?;
+        Period::from_stable_id(shifted)
+    }
+
+    /// Returns the key ordering periods by year, frequency (coarsest first) and position in the year.
+    fn sort_key(&self) -> (i32, u8, u16) {
+        match *self {
+            Period::Year(year) => (year, 0, 0),
+            Period::HalfYear(year, half) => (year, 1, half as u16),
+            Period::Quarter(year, quarter) => (year, 2, quarter as u16),
+            Period::Month(year, month) => (year, 3, month as u16),
+            Period::Week(year, week) => (year, 4, week as u16),
+            Period::Day(date) => (date.year(), 5, date.ordinal()),
+        }
+    }
+}
+
+impl PartialOrd for Period {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Period {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.sort_key().cmp(&other.sort_key())
+    }
+}
+
+impl fmt::Display for Period {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Period::Year(year) => write!(f, "{year:04}"),
+            Period::HalfYear(year, half) => write!(f, "{year:04}-H{half}"),
+            Period::Quarter(year, quarter) => write!(f, "{year:04}-Q{quarter}"),
+            Period::Month(year, month) => write!(f, "{year:04}-{:02}", *month as u8),
+            Period::Week(year, week) => write!(f, "{year:04}-W{week:02}"),
+            Period::Day(date) => write!(f, "{date}"),
+        }
+    }
+}
+
+impl FromStr for Period {
+    type Err = DateTimeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = || {
+            DateTimeError::InvalidDateFormat(
+                s.to_string(),
+                "expected a period such as 2024, 2024-H1, 2024-Q2, 2024-05, 2024-W05 or 2024-05-31"
+                    .to_string(),
+            )
+        };
+        let component =
+            |err: ComponentRange| DateTimeError::InvalidComponent(err.name(), err.to_string());
+        let upper = s.trim().to_ascii_uppercase();
+        // Compact notations (e.g. `202405`, `20240531`, `2024W05`) have no separator after the year.
+        let (year, rest, compact) = match upper.split_once('-') {
+            Some((year, rest)) => (year, rest, false),
+            None => {
+                let (year, rest) = upper.split_at_checked(4).unwrap_or((&upper, ""));
+                (year, rest, true)
+            }
+        };
+        if year.len() == 4 && year.bytes().all(|byte| byte.is_ascii_digit()) {
+            let year: i32 = year.parse().map_err(|_| error())?;
+            let number = |digits: &str| match digits.as_bytes() {
+                [b'0'..=b'9', b'0'..=b'9'] => digits.parse::<u8>().map_err(|_| error()),
+                _ => Err(error()),
+            };
+            match rest.as_bytes() {
+                [] | [b'A', b'1'] => return Ok(Period::Year(year)),
+                [b'0'..=b'9', b'0'..=b'9'] | [b'M', _, _] => {
+                    let month = Month::try_from(number(rest.trim_start_matches('M'))?)
+                        .map_err(component)?;
+                    return Ok(Period::Month(year, month));
+                }
+                [b'W', _, _] => {
+                    let period = Period::Week(year, number(&rest[1..])?);
+                    period.dates()?;
+                    return Ok(period);
+                }
+                [b'0'..=b'9', b'0'..=b'9', b'-', b'0'..=b'9', b'0'..=b'9']
+                | [b'0'..=b'9', b'0'..=b'9', b'0'..=b'9', b'0'..=b'9']
+                    if compact == (rest.len() == 4) =>
+                {
+                    let month = Month::try_from(number(&rest[..2])?).map_err(component)?;
+                    let day = number(&rest[rest.len() - 2..])?;
+                    let date = Date::from_calendar_date(year, month, day).map_err(component)?;
+                    return Ok(Period::Day(date));
+                }
+                _ => {}
+            }
+        }
+        match s.parse::<HalfYear>() {
+            Ok(half) => return Ok(Period::HalfYear(half.year(), half.half())),
+            Err(DateTimeError::InvalidDateFormat(..)) => {}
+            Err(err) => return Err(err),
+        }
+        match s.parse::<Quarter>() {
+            Ok(quarter) => return Ok(Period::Quarter(quarter.year(), quarter.quarter())),
+            Err(DateTimeError::InvalidDateFormat(..)) => {}
+            Err(err) => return Err(err),
+        }
+        Err(error())
+    }
+}
+
+/// Displays a period in the compact notation without separator: `2024`, `2024H1`, `2024Q2`, `202405`,
+/// `2024W05` or `20240531`.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::{CompactPeriod, Period};
+/// use time::Month;
+///
+/// assert_eq!(format!("export_{}.csv", CompactPeriod(Period::Month(2024, Month::May))), "export_202405.csv");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CompactPeriod(pub Period);
+
+impl fmt::Display for CompactPeriod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Period::Year(year) => write!(f, "{year:04}"),
+            Period::HalfYear(year, half) => write!(f, "{year:04}H{half}"),
+            Period::Quarter(year, quarter) => write!(f, "{year:04}Q{quarter}"),
+            Period::Month(year, month) => write!(f, "{year:04}{:02}", month as u8),
+            Period::Week(year, week) => write!(f, "{year:04}W{week:02}"),
+            Period::Day(date) => write!(
+                f,
+                "{:04}{:02}{:02}",
+                date.year(),
+                date.month() as u8,
+                date.day()
+            ),
+        }
+    }
+}
+
+/// Represents the notation of a period string.
+///
+/// - `PeriodNotation::Canonical`: It indicates the canonical notation of `Period` (e.g. `2024-Q2`)
+/// - `PeriodNotation::Compact`: It indicates the compact notation of `CompactPeriod` (e.g. `2024Q2`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PeriodNotation {
+    Canonical,
+    Compact,
+}
+
+/// Rewrites a period string, in any notation accepted by `Period`, in the given notation.
+///
+/// ## Arguments
+/// - `input: &str`: The period string.
+/// - `notation: PeriodNotation`: The notation of the result.
+///
+/// ## Returns
+/// - `Ok(String)`: The period in the given notation.
+/// - `Err(DateTimeError)`: If the input is not a valid period.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::{canonicalize_period, PeriodNotation};
+///
+/// assert_eq!(canonicalize_period("2Q24", PeriodNotation::Canonical).unwrap(), "2024-Q2");
+/// assert_eq!(canonicalize_period("20240531", PeriodNotation::Canonical).unwrap(), "2024-05-31");
+/// assert_eq!(canonicalize_period("2024-M05", PeriodNotation::Compact).unwrap(), "202405");
+/// ```
+pub fn canonicalize_period(input: &str, notation: PeriodNotation) -> Result<String, DateTimeError> {
+    let period: Period = input.parse()?;
+    let mut formatted = String::new();
+    // Writing into a `String` cannot fail.
+    let _ = write_period(&mut formatted, period, notation);
+    Ok(formatted)
+}
+
+/// Writes a period into the given writer, in the given notation.
+pub(crate) fn write_period(
+    out: &mut impl fmt::Write,
+    period: Period,
+    notation: PeriodNotation,
+) -> fmt::Result {
+    match notation {
+        PeriodNotation::Canonical => write!(out, "{period}"),
+        PeriodNotation::Compact => write!(out, "{}", CompactPeriod(period)),
+    }
+}
+
+/// Shifts each period back by the given number of periods of its frequency (e.g. the same quarter of the
+/// previous year with `n = 4`), for period-over-period comparisons.
+///
+/// ## Returns
+/// - `Ok(Vec<Period>)`: The shifted periods, in the same order.
+/// - `Err(DateTimeError)`: If a period is invalid, or a shifted period is out of the supported date
+///   range.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::{lag_periods, Period};
+///
+/// let quarters = [Period::Quarter(2024, 1), Period::Quarter(2024, 2)];
+/// assert_eq!(
+///     lag_periods(&quarters, 4).unwrap(),
+///     [Period::Quarter(2023, 1), Period::Quarter(2023, 2)]
+/// );
+/// ```
+pub fn lag_periods(periods: &[Period], n: i32) -> Result<Vec<Period>, DateTimeError> {
+    let n = n
+        .checked_neg()
+        .ok_or_else(|| DateTimeError::OutOfRange(format!("lag of {n} periods")))?;
+    lead_periods(periods, n)
+}
+
+/// Shifts each period forward by the given number of periods of its frequency.
+///
+/// ## Returns
+/// - `Ok(Vec<Period>)`: The shifted periods, in the same order.
+/// - `Err(DateTimeError)`: If a period is invalid, or a shifted period is out of the supported date
+///   range.
+pub fn lead_periods(periods: &[Period], n: i32) -> Result<Vec<Period>, DateTimeError> {
+    periods.iter().map(|period| period.shift(n)).collect()
+}
+
+/// Iterates over the consecutive periods of a frequency overlapping a range of dates.
+///
+/// Each item is the first and last date of a period. The first and last periods are returned whole, even
+/// if they extend beyond the range.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::{periods, Frequency};
+/// use time::macros::date;
+///
+/// let halves: Vec<_> = periods(Frequency::Semiannual, date!(2024 - 05 - 10), date!(2025 - 01 - 10)).collect();
+/// assert_eq!(
+///     halves,
+///     vec![
+///         (date!(2024 - 01 - 01), date!(2024 - 06 - 30)),
+///         (date!(2024 - 07 - 01), date!(2024 - 12 - 31)),
+///         (date!(2025 - 01 - 01), date!(2025 - 06 - 30)),
+///     ]
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct Periods {
+    frequency: Frequency,
+    next: Option<Date>,
+    last: Date,
+}
+
+/// Returns an iterator over the periods of the given frequency overlapping the dates from `first` to
+/// `last`, both included.
+///
+/// ## Arguments
+/// - `frequency: Frequency`: The frequency of the periods.
+/// - `first: Date`: The first date of the range.
+/// - `last: Date`: The last date of the range.
+///
+/// ## Returns
+/// - `Periods`: The iterator over the `(first day, last day)` of each period, empty if `first` is after
+///   `last`.
+pub fn periods(frequency: Frequency, first: Date, last: Date) -> Periods {
+    Periods {
+        frequency,
+        next: Some(first),
+        last,
+    }
+}
+
+impl Iterator for Periods {
+    type Item = (Date, Date);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let date = self.next.filter(|date| *date <= self.last)?;
+        let bounds = period_bounds(self.frequency, date);
+        self.next = bounds.and_then(|(_, last)| last.next_day());
+        bounds
+    }
+}
+
+/// Returns the range from the first instant of `first` to the last instant of `last`, in UTC.
+pub(crate) fn days_range(first: Date, last: Date) -> DateTimeRange {
+    DateTimeRange::new(
+        first.midnight().assume_utc(),
+        last.with_time(Time::MAX).assume_utc(),
+    )
+    .expect("first day precedes last day")
+}
+
+/// Shifts the numbered period of a year (e.g. a quarter) by the given number of periods.
+fn shift_ordinal(
+    year: i32,
+    number: u8,
+    per_year: i64,
+    shift: i32,
+) -> Result<(i32, u8), DateTimeError> {
+    let ordinal = year as i64 * per_year + number as i64 - 1 + shift as i64;
+    let year = i32::try_from(ordinal.div_euclid(per_year))
+        .map_err(|err| DateTimeError::OutOfRange(err.to_string()))?;
+    Ok((year, ordinal.rem_euclid(per_year) as u8 + 1))
+}
+
+/// Parses a year and a period number designated by one of the given letters, in the notations accepted
+/// by `Quarter` (e.g. `2024-Q2`, `Q2 2024`, `2024Q2`, `2Q24`).
+fn parse_year_and_number(
+    s: &str,
+    designators: &[char],
+    expected: &str,
+) -> Result<(i32, u8), DateTimeError> {
+    let error = || DateTimeError::InvalidDateFormat(s.to_string(), format!("expected {expected}"));
+    const SEPARATORS: [char; 4] = [' ', '-', '/', '\''];
+    let upper = s.trim().to_ascii_uppercase();
+    let (number, year) = if let Some(rest) = upper.strip_prefix(designators) {
+        // Q2 2024, Q2-2024, Q2'24
+        let (number, year) = rest.split_at_checked(1).ok_or_else(error)?;
+        (number, year.trim_start_matches(SEPARATORS))
+    } else {
+        let (left, right) = upper.split_once(designators).ok_or_else(error)?;
+        let left = left.trim_end_matches(SEPARATORS);
+        if left.len() == 1 {
+            // 2Q24, 2Q2024
+            (left, right)
+        } else {
+            // 2024-Q2, 2024Q2
+            (right, left)
+        }
+    };
+    let number = match number.as_bytes() {
+        [digit @ b'0'..=b'9'] => digit - b'0',
+        _ => return Err(error()),
+    };
+    if !year.bytes().all(|byte| byte.is_ascii_digit()) {
+        return Err(error());
+    }
+    let year = match year.len() {
+        2 => 2000 + year.parse::<i32>().map_err(|_| error())?,
+        4 => year.parse::<i32>().map_err(|_| error())?,
+        _ => return Err(error()),
+    };
+    Ok((year, number))
+}
+
+/// Returns the first and last dates of the period containing the given date, if they are both within
+/// the supported date range.
+pub(crate) fn period_bounds(frequency: Frequency, date: Date) -> Option<(Date, Date)> {
+    let first = match frequency {
+        Frequency::Daily => date,
+        Frequency::Weekly => date.checked_sub(Duration::days(
+            date.weekday().number_days_from_monday() as i64,
+        ))?,
+        Frequency::Monthly => date.replace_day(1).ok()?,
+        Frequency::Quarterly => {
+            Date::from_calendar_date(date.year(), quarter_first_month(date.month()), 1).ok()?
+        }
+        Frequency::Semiannual => {
+            Date::from_calendar_date(date.year(), half_first_month(date.month()), 1).ok()?
+        }
+        Frequency::Annual => Date::from_calendar_date(date.year(), Month::January, 1).ok()?,
+    };
+    let last = first.checked_add(period_length(frequency, date) - Duration::DAY)?;
+    Some((first, last))
+}
+
+/// Returns the first month of the quarter containing the given month.
+pub(crate) fn quarter_first_month(month: Month) -> Month {
+    match month as u8 {
+        1..=3 => Month::January,
+        4..=6 => Month::April,
+        7..=9 => Month::July,
+        _ => Month::October,
+    }
+}
+
+/// Returns the first month of the half-year containing the given month.
+pub(crate) fn half_first_month(month: Month) -> Month {
+    if (month as u8) <= 6 {
+        Month::January
+    } else {
+        Month::July
+    }
+}
+
+/// Returns the nth occurrence (starting from 1) of the weekday in the given month, if it exists.
+pub(crate) fn nth_weekday_of_month(
+    year: i32,
+    month: Month,
+    weekday: Weekday,
+    n: u8,
+) -> Option<Date> {
+    let first = Date::from_calendar_date(year, month, 1).ok()?;
+    let offset =
+        (7 + weekday.number_days_from_monday() - first.weekday().number_days_from_monday()) % 7;
+    let day = 1 + offset + 7 * n.checked_sub(1)?;
+    Date::from_calendar_date(year, month, day).ok()
+}
@@ -0,0 +1,155 @@
+//! Publication Module
+//!
+//! This module contains a set of methods to map observation periods to their expected publication
+//! dates, and publication dates back to the latest published period, for vintage-aware analysis of
+//! statistical releases.
+use time::{Date, Duration};
+
+use crate::{
+    add_business_days,
+    fiscal::{first_of_month, months_since_year0},
+    is_business_day,
+    period::period_bounds,
+    DateTimeError, DateTimeRange, Frequency, HolidayCalendar, Weekend,
+};
+
+/// Represents the delay between the end of an observation period and the publication of its data.
+///
+/// - `PublicationLag::Days`: It indicates a number of calendar days
+/// - `PublicationLag::Months`: It indicates a number of calendar months; a period ending on the last day
+///   of a month is published on the last day of the target month
+/// - `PublicationLag::BusinessDays`: It indicates a number of business days
+///
+/// Publications computed with calendar days or months falling on a non-business day are moved to the
+/// next business day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PublicationLag {
+    Days(u32),
+    Months(u32),
+    BusinessDays(u32),
+}
+
+/// Computes the expected publication date of an observation period.
+///
+/// The lag is counted from the last calendar date of the period, in the offset of its start.
+///
+/// ## Arguments
+/// - `period: &DateTimeRange`: The observation period (e.g. `Quarter::range()`).
+/// - `lag: PublicationLag`: The publication lag.
+/// - `weekend: &Weekend`: The weekdays which are not business days.
+/// - `calendar: &HolidayCalendar`: The holidays which are not business days.
+///
+/// ## Returns
+/// - `Ok(Date)`: The expected publication date.
+/// - `Err(DateTimeError)`: If the publication date falls outside the supported date range.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::{apply_publication_lag, HolidayCalendar, PublicationLag, Quarter, Weekend};
+/// use time::macros::date;
+///
+/// let quarter: Quarter = "2024-Q1".parse().unwrap();
+/// let published = apply_publication_lag(
+///     &quarter.range(),
+///     PublicationLag::Months(2),
+///     &Weekend::default(),
+///     &HolidayCalendar::new(),
+/// );
+/// // May 31, 2024 is a Friday.
+/// assert_eq!(published.unwrap(), date!(2024 - 05 - 31));
+/// ```
+pub fn apply_publication_lag(
+    period: &DateTimeRange,
+    lag: PublicationLag,
+    weekend: &Weekend,
+    calendar: &HolidayCalendar,
+) -> Result<Date, DateTimeError> {
+    publication_date(period.dates().1, lag, weekend, calendar)
+}
+
+/// Finds the latest observation period of the given frequency published on or before a date.
+///
+/// This is the inverse of `apply_publication_lag`: it answers which period was the latest available
+/// in the vintage of the given date.
+///
+/// ## Arguments
+/// - `as_of: Date`: The vintage date.
+/// - `frequency: Frequency`: The frequency of the observation periods.
+/// - `lag: PublicationLag`: The publication lag.
+/// - `weekend: &Weekend`: The weekdays which are not business days.
+/// - `calendar: &HolidayCalendar`: The holidays which are not business days.
+///
+/// ## Returns
+/// - `Ok((Date, Date))`: The first and last days of the latest published period.
+/// - `Err(DateTimeError)`: If no period within the supported date range is published by that date.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::{latest_published_period, Frequency, HolidayCalendar, PublicationLag, Weekend};
+/// use time::macros::date;
+///
+/// let period = latest_published_period(
+///     date!(2024 - 05 - 30),
+///     Frequency::Quarterly,
+///     PublicationLag::Months(2),
+///     &Weekend::default(),
+///     &HolidayCalendar::new(),
+/// );
+/// // 2024-Q1 is only published on May 31.
+/// assert_eq!(period.unwrap(), (date!(2023 - 10 - 01), date!(2023 - 12 - 31)));
+/// ```
+pub fn latest_published_period(
+    as_of: Date,
+    frequency: Frequency,
+    lag: PublicationLag,
+    weekend: &Weekend,
+    calendar: &HolidayCalendar,
+) -> Result<(Date, Date), DateTimeError> {
+    let not_published = || DateTimeError::OutOfRange(format!("no period published by {as_of}"));
+    let mut bounds = period_bounds(frequency, as_of).ok_or_else(not_published)?;
+    loop {
+        // Publications beyond the supported range are never on or before the vintage date.
+        if publication_date(bounds.1, lag, weekend, calendar).is_ok_and(|date| date <= as_of) {
+            return Ok(bounds);
+        }
+        let previous = bounds.0.previous_day().ok_or_else(not_published)?;
+        bounds = period_bounds(frequency, previous).ok_or_else(not_published)?;
+    }
+}
+
+/// Computes the publication date of a period ending on the given date.
+fn publication_date(
+    end: Date,
+    lag: PublicationLag,
+    weekend: &Weekend,
+    calendar: &HolidayCalendar,
+) -> Result<Date, DateTimeError> {
+    let out_of_range = || DateTimeError::OutOfRange(format!("{end} + {lag:?}"));
+    let date = match lag {
+        PublicationLag::BusinessDays(days) => {
+            return add_business_days(end, days as i64, weekend, calendar);
+        }
+        PublicationLag::Days(days) => end
+            .checked_add(Duration::days(days as i64))
+            .ok_or_else(out_of_range)?,
+        PublicationLag::Months(months) => {
+            let months = i32::try_from(months).map_err(|_| out_of_range())?;
+            let total = months_since_year0(end.year(), end.month() as i32 - 1)?
+                .checked_add(months)
+                .ok_or_else(out_of_range)?;
+            let first = first_of_month(total).map_err(|_| out_of_range())?;
+            let length = first.month().length(first.year());
+            let day = if end.day() == end.month().length(end.year()) {
+                length
+            } else {
+                end.day().min(length)
+            };
+            first.replace_day(day).map_err(|_| out_of_range())?
+        }
+    };
+    if is_business_day(date, weekend, calendar) {
+        Ok(date)
+    } else {
+        add_business_days(date, 1, weekend, calendar)
+    }
+}
@@ -0,0 +1,469 @@
+//! Range Module
+//!
+//! This module contains the `DateTimeRange` type and a set of methods to query the calendar days it
+//! covers and to prorate amounts over it.
+use thiserror::Error;
+use time::{Date, Duration, OffsetDateTime, Weekday};
+
+use crate::DateTimeError;
+
+/// Represents the kind of the end bound of a range.
+///
+/// - `BoundKind::Inclusive`: It indicates that the end is the last instant of the range, as with
+///   `DateType::End` (e.g. `2025-01-31 23:59:59.999999999` for January)
+/// - `BoundKind::Exclusive`: It indicates that the end is the first instant after the range, as with
+///   half-open `[start, end)` APIs (e.g. `2025-02-01 00:00:00` for January)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BoundKind {
+    Inclusive,
+    Exclusive,
+}
+
+/// Represents a range between two datetimes, both included.
+///
+/// Both bounds are included, consistently with `DateType::End` representing the last nanosecond of a
+/// day: the range built from `2025-01-01` (start) and `2025-01-31` (end) covers the whole month. Use
+/// `DateTimeRange::from_bounds` and `DateTimeRange::end_bound` to exchange ranges with half-open APIs.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::DateTimeRange;
+/// use time::macros::datetime;
+///
+/// let range = DateTimeRange::new(
+///     datetime!(2025-01-01 0:00 UTC),
+///     datetime!(2025-01-31 23:59:59.999999999 UTC),
+/// )
+/// .unwrap();
+/// assert!(range.contains(datetime!(2025-01-31 12:00 UTC)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DateTimeRange {
+    start: OffsetDateTime,
+    end: OffsetDateTime,
+}
+
+impl DateTimeRange {
+    /// Creates a range between two datetimes, both included.
+    ///
+    /// ## Returns
+    /// - `Ok(DateTimeRange)`: The range between the two datetimes.
+    /// - `Err(DateTimeError)`: If `start` is after `end`.
+    pub fn new(start: OffsetDateTime, end: OffsetDateTime) -> Result<Self, DateTimeError> {
+        if start > end {
+            return Err(DateTimeError::InvalidRange(
+                start.to_string(),
+                end.to_string(),
+            ));
+        }
+        Ok(Self { start, end })
+    }
+
+    /// Creates a range from its start and an end bound of the given kind.
+    ///
+    /// ## Returns
+    /// - `Ok(DateTimeRange)`: The range between the two datetimes.
+    /// - `Err(DateTimeError)`: If `start` is after `end`, or if `start` is not before an exclusive
+    ///   `end` (the range would be empty).
+    ///
+    /// ## Example
+    /// ```rust
+    /// use date_utils::{BoundKind, DateTimeRange};
+    /// use time::macros::datetime;
+    ///
+    /// let january = DateTimeRange::from_bounds(
+    ///     datetime!(2025-01-01 0:00 UTC),
+    ///     datetime!(2025-02-01 0:00 UTC),
+    ///     BoundKind::Exclusive,
+    /// )
+    /// .unwrap();
+    /// assert_eq!(january.end(), datetime!(2025-01-31 23:59:59.999999999 UTC));
+    /// ```
+    pub fn from_bounds(
+        start: OffsetDateTime,
+        end: OffsetDateTime,
+        kind: BoundKind,
+    ) -> Result<Self, DateTimeError> {
+        match kind {
+            BoundKind::Inclusive => Self::new(start, end),
+            BoundKind::Exclusive if start >= end => Err(DateTimeError::InvalidRange(
+                start.to_string(),
+                end.to_string(),
+            )),
+            BoundKind::Exclusive => Self::new(start, inclusive_end(end)?),
+        }
+    }
+
+    /// Returns the start of the range.
+    pub fn start(&self) -> OffsetDateTime {
+        self.start
+    }
+
+    /// Returns the end of the range.
+    pub fn end(&self) -> OffsetDateTime {
+        self.end
+    }
+
+    /// Returns the end of the range as a bound of the given kind.
+    ///
+    /// ## Returns
+    /// - `Ok(OffsetDateTime)`: The end of the range, or the first instant after it if exclusive.
+    /// - `Err(DateTimeError)`: If the exclusive end falls outside the supported date range.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use date_utils::{parse_to_datetime, BoundKind, DateTimeRange, DateType, OffsetType};
+    /// use time::macros::datetime;
+    ///
+    /// let range = DateTimeRange::new(
+    ///     parse_to_datetime("2025-01-01", DateType::Start, OffsetType::Utc).unwrap(),
+    ///     parse_to_datetime("2025-01-31", DateType::End, OffsetType::Utc).unwrap(),
+    /// )
+    /// .unwrap();
+    /// let end = range.end_bound(BoundKind::Exclusive).unwrap();
+    /// assert_eq!(end, datetime!(2025-02-01 0:00 UTC));
+    /// ```
+    pub fn end_bound(&self, kind: BoundKind) -> Result<OffsetDateTime, DateTimeError> {
+        match kind {
+            BoundKind::Inclusive => Ok(self.end),
+            BoundKind::Exclusive => exclusive_end(self.end),
+        }
+    }
+
+    /// Returns the duration between the start and the end of the range.
+    pub fn duration(&self) -> Duration {
+        self.end - self.start
+    }
+
+    /// Checks whether the datetime falls within the range.
+    pub fn contains(&self, datetime: OffsetDateTime) -> bool {
+        self.start <= datetime && datetime <= self.end
+    }
+
+    /// Checks whether the two ranges share at least one instant.
+    pub fn overlaps(&self, other: &DateTimeRange) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+
+    /// Returns the range of instants shared by the two ranges, if any.
+    pub fn intersection(&self, other: &DateTimeRange) -> Option<DateTimeRange> {
+        self.overlaps(other).then(|| DateTimeRange {
+            start: self.start.max(other.start),
+            end: self.end.min(other.end),
+        })
+    }
+
+    /// Returns the first and last calendar dates covered by the range, in the offset of its start.
+    pub(crate) fn dates(&self) -> (Date, Date) {
+        (
+            self.start.date(),
+            self.end.to_offset(self.start.offset()).date(),
+        )
+    }
+}
+
+/// Converts an inclusive end (e.g. a `DateType::End` datetime) into the exclusive end of a half-open
+/// range, the first instant after it.
+///
+/// ## Returns
+/// - `Ok(OffsetDateTime)`: The instant one nanosecond after the inclusive end.
+/// - `Err(DateTimeError)`: If the exclusive end falls outside the supported date range.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::exclusive_end;
+/// use time::macros::datetime;
+///
+/// let end = exclusive_end(datetime!(2025-01-31 23:59:59.999999999 UTC)).unwrap();
+/// assert_eq!(end, datetime!(2025-02-01 0:00 UTC));
+/// ```
+pub fn exclusive_end(inclusive_end: OffsetDateTime) -> Result<OffsetDateTime, DateTimeError> {
+    inclusive_end
+        .checked_add(Duration::nanoseconds(1))
+        .ok_or_else(|| DateTimeError::OutOfRange(format!("no instant follows '{inclusive_end}'")))
+}
+
+/// Converts the exclusive end of a half-open range into an inclusive end, the last instant before it,
+/// following the `DateType::End` convention.
+///
+/// ## Returns
+/// - `Ok(OffsetDateTime)`: The instant one nanosecond before the exclusive end.
+/// - `Err(DateTimeError)`: If the inclusive end falls outside the supported date range.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::inclusive_end;
+/// use time::macros::datetime;
+///
+/// let end = inclusive_end(datetime!(2025-02-01 0:00 UTC)).unwrap();
+/// assert_eq!(end, datetime!(2025-01-31 23:59:59.999999999 UTC));
+/// ```
+pub fn inclusive_end(exclusive_end: OffsetDateTime) -> Result<OffsetDateTime, DateTimeError> {
+    exclusive_end
+        .checked_sub(Duration::nanoseconds(1))
+        .ok_or_else(|| DateTimeError::OutOfRange(format!("no instant precedes '{exclusive_end}'")))
+}
+
+/// Counts the occurrences of a weekday among the calendar dates covered by the range.
+///
+/// Calendar dates are evaluated in the offset of the start of the range, and both the first and last
+/// dates are counted even if only partially covered.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::{count_weekdays, DateTimeRange};
+/// use time::{macros::datetime, Weekday};
+///
+/// // How many Mondays in Q3 2024?
+/// let q3 = DateTimeRange::new(
+///     datetime!(2024-07-01 0:00 UTC),
+///     datetime!(2024-09-30 23:59:59.999999999 UTC),
+/// )
+/// .unwrap();
+/// assert_eq!(count_weekdays(&q3, Weekday::Monday), 14);
+/// ```
+pub fn count_weekdays(range: &DateTimeRange, weekday: Weekday) -> u64 {
+    weekday_distribution(range)[weekday.number_days_from_monday() as usize]
+}
+
+/// Counts the occurrences of each weekday among the calendar dates covered by the range.
+///
+/// ## Returns
+/// - `[u64; 7]`: The count of each weekday, from Monday (index 0) to Sunday (index 6).
+///
+/// ## Example
+/// ```rust
+/// use date_utils::{weekday_distribution, DateTimeRange};
+/// use time::macros::datetime;
+///
+/// let range = DateTimeRange::new(datetime!(2024-07-01 0:00 UTC), datetime!(2024-07-10 0:00 UTC)).unwrap();
+/// assert_eq!(weekday_distribution(&range), [2, 2, 2, 1, 1, 1, 1]);
+/// ```
+pub fn weekday_distribution(range: &DateTimeRange) -> [u64; 7] {
+    let (first, last) = range.dates();
+    let days = (last - first).whole_days() as u64 + 1;
+    let first_weekday = first.weekday().number_days_from_monday() as u64;
+    let mut distribution = [days / 7; 7];
+    for offset in 0..days % 7 {
+        distribution[((first_weekday + offset) % 7) as usize] += 1;
+    }
+    distribution
+}
+
+/// Computes the fraction of the range elapsed at the given datetime.
+///
+/// ## Returns
+/// - `f64`: `0.0` before the start of the range, `1.0` after its end, and the time-weighted fraction in
+///   between.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::{fraction_elapsed, DateTimeRange};
+/// use time::macros::datetime;
+///
+/// let range = DateTimeRange::new(datetime!(2024-01-01 0:00 UTC), datetime!(2024-01-05 0:00 UTC)).unwrap();
+/// assert_eq!(fraction_elapsed(&range, datetime!(2024-01-02 0:00 UTC)), 0.25);
+/// ```
+pub fn fraction_elapsed(range: &DateTimeRange, at: OffsetDateTime) -> f64 {
+    if at >= range.end {
+        return 1.0;
+    }
+    if at <= range.start {
+        return 0.0;
+    }
+    (at - range.start).as_seconds_f64() / range.duration().as_seconds_f64()
+}
+
+/// Prorates an amount over a sub-range of the given range.
+///
+/// The function returns the share of the amount corresponding to the time-weighted fraction of the range
+/// covered by the sub-range. The portion of the sub-range falling outside the range is ignored.
+///
+/// ## Arguments
+/// - `amount: f64`: The amount accrued over the whole range (e.g. a monthly fee).
+/// - `range: &DateTimeRange`: The range over which the amount accrues (e.g. the billing month).
+/// - `sub_range: &DateTimeRange`: The range to charge (e.g. the days the subscription was active).
+///
+/// ## Returns
+/// - `f64`: The prorated amount.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::{prorate, DateTimeRange};
+/// use time::macros::datetime;
+///
+/// let april = DateTimeRange::new(datetime!(2024-04-01 0:00 UTC), datetime!(2024-05-01 0:00 UTC)).unwrap();
+/// let active = DateTimeRange::new(datetime!(2024-04-16 0:00 UTC), datetime!(2024-05-10 0:00 UTC)).unwrap();
+/// assert_eq!(prorate(30.0, &april, &active), 15.0);
+/// ```
+pub fn prorate(amount: f64, range: &DateTimeRange, sub_range: &DateTimeRange) -> f64 {
+    let Some(covered) = range.intersection(sub_range) else {
+        return 0.0;
+    };
+    if range.duration().is_zero() {
+        return amount;
+    }
+    amount * covered.duration().as_seconds_f64() / range.duration().as_seconds_f64()
+}
+
+/// Represents the bound of a range kept when it is truncated.
+///
+/// - `Anchor::Start`: It indicates that the start is kept and the end is clipped
+/// - `Anchor::End`: It indicates that the end is kept and the start is clipped (e.g. to keep the most
+///   recent data)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Anchor {
+    Start,
+    End,
+}
+
+/// Builds the range between two datetimes, truncating it to a maximum span.
+///
+/// Instead of rejecting windows longer than allowed, the function clips them from the side opposite to
+/// the anchor and reports the discarded part, so callers can serve the clipped window and warn about
+/// the rest.
+///
+/// ## Arguments
+/// - `start: OffsetDateTime`: The start of the range.
+/// - `end: OffsetDateTime`: The end of the range.
+/// - `max_span: Duration`: The maximum duration of the range.
+/// - `anchor: Anchor`: The bound kept when the range is truncated.
+///
+/// ## Returns
+/// - `Ok((DateTimeRange, Option<DateTimeRange>))`: The range truncated to the maximum span, and the
+///   discarded range if it has been truncated.
+/// - `Err(DateTimeError)`: If `start` is after `end` or the maximum span is negative.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::{truncate_range_to, Anchor};
+/// use time::{macros::datetime, Duration};
+///
+/// let (range, discarded) = truncate_range_to(
+///     datetime!(2024-01-01 0:00 UTC),
+///     datetime!(2024-12-31 0:00 UTC),
+///     Duration::days(90),
+///     Anchor::End,
+/// )
+/// .unwrap();
+/// assert_eq!(range.start(), datetime!(2024-10-02 0:00 UTC));
+/// assert_eq!(discarded.unwrap().start(), datetime!(2024-01-01 0:00 UTC));
+/// ```
+pub fn truncate_range_to(
+    start: OffsetDateTime,
+    end: OffsetDateTime,
+    max_span: Duration,
+    anchor: Anchor,
+) -> Result<(DateTimeRange, Option<DateTimeRange>), DateTimeError> {
+    let range = DateTimeRange::new(start, end)?;
+    if max_span.is_negative() {
+        return Err(DateTimeError::InvalidComponent(
+            "max_span",
+            format!("{max_span} is negative"),
+        ));
+    }
+    if range.duration() <= max_span {
+        return Ok((range, None));
+    }
+    let nanosecond = Duration::nanoseconds(1);
+    match anchor {
+        Anchor::Start => {
+            let last = start + max_span;
+            Ok((
+                DateTimeRange::new(start, last)?,
+                Some(DateTimeRange::new(last + nanosecond, end)?),
+            ))
+        }
+        Anchor::End => {
+            let first = end - max_span;
+            Ok((
+                DateTimeRange::new(first, end)?,
+                Some(DateTimeRange::new(start, first - nanosecond)?),
+            ))
+        }
+    }
+}
+
+/// Represents the error of stitching ranges which do not form a single contiguous range.
+///
+/// - `StitchError::Empty`: It indicates that there is no range to stitch
+/// - `StitchError::NotContiguous`: It indicates the holes between the ranges and the parts covered by
+///   more than one range, beyond the tolerance
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum StitchError {
+    #[error("Cannot stitch an empty list of ranges.")]
+    Empty,
+    #[error("Ranges are not contiguous: {} gap(s) and {} overlap(s).", gaps.len(), overlaps.len())]
+    NotContiguous {
+        gaps: Vec<DateTimeRange>,
+        overlaps: Vec<DateTimeRange>,
+    },
+}
+
+/// Stitches ranges (e.g. the chunks of a backfill) into the single range they cover, checking that they
+/// are contiguous and do not overlap.
+///
+/// Ranges may be given in any order. Since ranges include both bounds, a range is contiguous with the
+/// previous one when it starts one nanosecond after the previous one ends; holes and overlaps up to the
+/// tolerance are accepted (e.g. chunks ending at `23:59:59` followed by chunks starting at `00:00:00`).
+///
+/// ## Arguments
+/// - `ranges: Vec<DateTimeRange>`: The ranges to stitch.
+/// - `tolerance: Duration`: The longest hole or overlap accepted between consecutive ranges.
+///
+/// ## Returns
+/// - `Ok(DateTimeRange)`: The range from the earliest start to the latest end.
+/// - `Err(StitchError)`: If there is no range, or the holes and overlaps beyond the tolerance.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::{stitch_ranges, DateTimeRange, StitchError};
+/// use time::{macros::datetime, Duration};
+///
+/// let chunk = |start, end| DateTimeRange::new(start, end).unwrap();
+/// let chunks = vec![
+///     chunk(datetime!(2024-01-01 0:00 UTC), datetime!(2024-01-31 23:59:59 UTC)),
+///     chunk(datetime!(2024-03-01 0:00 UTC), datetime!(2024-03-31 23:59:59 UTC)),
+///     chunk(datetime!(2024-02-01 0:00 UTC), datetime!(2024-02-29 23:59:59 UTC)),
+/// ];
+/// let covered = stitch_ranges(chunks.clone(), Duration::SECOND).unwrap();
+/// assert_eq!(covered.end(), datetime!(2024-03-31 23:59:59 UTC));
+///
+/// let error = stitch_ranges(vec![chunks[0], chunks[1]], Duration::SECOND).unwrap_err();
+/// let StitchError::NotContiguous { gaps, .. } = error else { panic!("{error}") };
+/// assert_eq!(gaps[0].start(), datetime!(2024-01-31 23:59:59.000000001 UTC));
+/// ```
+pub fn stitch_ranges(
+    mut ranges: Vec<DateTimeRange>,
+    tolerance: Duration,
+) -> Result<DateTimeRange, StitchError> {
+    ranges.sort_unstable_by_key(|range| (range.start, range.end));
+    let Some(first) = ranges.first() else {
+        return Err(StitchError::Empty);
+    };
+    let nanosecond = Duration::nanoseconds(1);
+    let mut covered = *first;
+    let mut gaps = Vec::new();
+    let mut overlaps = Vec::new();
+    for range in &ranges[1..] {
+        if range.start > covered.end {
+            if range.start - covered.end - nanosecond > tolerance {
+                gaps.push(DateTimeRange {
+                    start: covered.end + nanosecond,
+                    end: range.start - nanosecond,
+                });
+            }
+        } else if covered.end - range.start + nanosecond > tolerance {
+            overlaps.push(DateTimeRange {
+                start: range.start,
+                end: covered.end.min(range.end),
+            });
+        }
+        covered.end = covered.end.max(range.end);
+    }
+    if gaps.is_empty() && overlaps.is_empty() {
+        Ok(covered)
+    } else {
+        Err(StitchError::NotContiguous { gaps, overlaps })
+    }
+}
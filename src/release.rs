@@ -0,0 +1,154 @@
+//! Release Schedule Module
+//!
+//! This module contains the `ReleaseSchedule` type, which describes monthly publication calendars (e.g.
+//! "second Friday of the month at 08:30") and computes the upcoming releases.
+use time::{Date, Month, OffsetDateTime, Time, UtcOffset, Weekday};
+
+use crate::{period::nth_weekday_of_month, Clock, DateTimeError};
+
+/// Represents the day of the month of a release.
+///
+/// - `ReleaseDay::NthWeekday`: It indicates the nth (from 1 to 5) occurrence of a weekday in the month;
+///   months without a fifth occurrence have no release
+/// - `ReleaseDay::LastWeekday`: It indicates the last occurrence of a weekday in the month
+/// - `ReleaseDay::DayOfMonth`: It indicates a fixed day (from 1 to 31); months without that day have no
+///   release
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ReleaseDay {
+    NthWeekday(u8, Weekday),
+    LastWeekday(Weekday),
+    DayOfMonth(u8),
+}
+
+/// Represents a monthly release calendar of a data publication.
+///
+/// Release times are wall-clock times in a fixed offset: for publications following daylight saving
+/// time (e.g. 08:30 Eastern Time), use one schedule per offset period or convert the time to UTC.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::{ReleaseDay, ReleaseSchedule};
+/// use time::{macros::{datetime, offset, time}, Weekday};
+///
+/// let schedule = ReleaseSchedule::new(
+///     ReleaseDay::NthWeekday(2, Weekday::Friday),
+///     time!(8:30),
+///     offset!(-4),
+/// )
+/// .unwrap();
+/// let next = schedule.next_release(datetime!(2024-05-31 12:00 UTC)).unwrap();
+/// assert_eq!(next, datetime!(2024-06-14 8:30 -4));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ReleaseSchedule {
+    day: ReleaseDay,
+    time: Time,
+    offset: UtcOffset,
+}
+
+impl ReleaseSchedule {
+    /// Creates a release schedule.
+    ///
+    /// ## Arguments
+    /// - `day: ReleaseDay`: The day of the month of the releases.
+    /// - `time: Time`: The wall-clock time of the releases.
+    /// - `offset: UtcOffset`: The offset of the wall-clock time.
+    ///
+    /// ## Returns
+    /// - `Ok(ReleaseSchedule)`: The schedule.
+    /// - `Err(DateTimeError)`: If the occurrence is not between 1 and 5, or the day is not between 1 and
+    ///   31.
+    pub fn new(day: ReleaseDay, time: Time, offset: UtcOffset) -> Result<Self, DateTimeError> {
+        match day {
+            ReleaseDay::NthWeekday(n, _) if !(1..=5).contains(&n) => {
+                return Err(DateTimeError::InvalidComponent(
+                    "occurrence",
+                    format!("{n} is not between 1 and 5"),
+                ));
+            }
+            ReleaseDay::DayOfMonth(day) if !(1..=31).contains(&day) => {
+                return Err(DateTimeError::InvalidComponent(
+                    "day",
+                    format!("{day} is not between 1 and 31"),
+                ));
+            }
+            _ => {}
+        }
+        Ok(Self { day, time, offset })
+    }
+
+    /// Returns the release of the given month, if the month has one.
+    pub fn release_in(&self, year: i32, month: Month) -> Option<OffsetDateTime> {
+        let date = match self.day {
+            ReleaseDay::NthWeekday(n, weekday) => nth_weekday_of_month(year, month, weekday, n)?,
+            ReleaseDay::LastWeekday(weekday) => {
+                let last = Date::from_calendar_date(year, month, month.length(year)).ok()?;
+                let days_back = (7 + last.weekday().number_days_from_monday()
+                    - weekday.number_days_from_monday())
+                    % 7;
+                last.replace_day(last.day() - days_back).ok()?
+            }
+            ReleaseDay::DayOfMonth(day) => Date::from_calendar_date(year, month, day).ok()?,
+        };
+        Some(date.with_time(self.time).assume_offset(self.offset))
+    }
+
+    /// Returns the first release strictly after the given instant.
+    ///
+    /// ## Returns
+    /// - `Option<OffsetDateTime>`: The release, in the offset of the schedule, or `None` if it is out of
+    ///   the supported date range.
+    pub fn next_release(&self, after: OffsetDateTime) -> Option<OffsetDateTime> {
+        let date = after.checked_to_offset(self.offset)?.date();
+        let (mut year, mut month) = (date.year(), date.month());
+        // A release exists at least every third month (e.g. fifth weekdays).
+        for _ in 0..=12 {
+            if let Some(release) = self.release_in(year, month).filter(|r| *r > after) {
+                return Some(release);
+            }
+            year += (month == Month::December) as i32;
+            month = month.next();
+        }
+        None
+    }
+
+    /// Returns the first release strictly after the current time of the clock.
+    pub fn next_release_at(&self, clock: &impl Clock) -> Option<OffsetDateTime> {
+        self.next_release(clock.now())
+    }
+
+    /// Returns an iterator over the releases strictly after the given instant.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use date_utils::{ReleaseDay, ReleaseSchedule};
+    /// use time::{macros::{datetime, offset, time}, Weekday};
+    ///
+    /// let schedule =
+    ///     ReleaseSchedule::new(ReleaseDay::LastWeekday(Weekday::Thursday), time!(10:00), offset!(UTC)).unwrap();
+    /// let releases: Vec<_> = schedule.releases(datetime!(2024-05-30 10:00 UTC)).take(2).collect();
+    /// assert_eq!(releases, [datetime!(2024-06-27 10:00 UTC), datetime!(2024-07-25 10:00 UTC)]);
+    /// ```
+    pub fn releases(&self, after: OffsetDateTime) -> Releases {
+        Releases {
+            schedule: *self,
+            after: Some(after),
+        }
+    }
+}
+
+/// Iterator over the releases of a `ReleaseSchedule`, returned by `ReleaseSchedule::releases`.
+#[derive(Debug, Clone)]
+pub struct Releases {
+    schedule: ReleaseSchedule,
+    after: Option<OffsetDateTime>,
+}
+
+impl Iterator for Releases {
+    type Item = OffsetDateTime;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.after = self.schedule.next_release(self.after?);
+        self.after
+    }
+}
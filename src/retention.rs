@@ -0,0 +1,129 @@
+//! Retention Module
+//!
+//! This module contains the `RetentionPolicy` type, which computes when stored data expires and whether
+//! it is expired at the current time of a clock, for data-retention sweeps.
+use time::{Duration, OffsetDateTime};
+
+#[cfg(feature = "business")]
+use crate::{add_business_days, HolidayCalendar, Weekend};
+use crate::{Clock, DateTimeError};
+
+/// Represents a data-retention policy.
+///
+/// Data expires once it is older than the maximum age, unless it was created before the grandfathering
+/// instant, in which case it is retained indefinitely. With business days, the whole days of the
+/// maximum age are counted as business days.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::{FixedClock, RetentionPolicy};
+/// use time::{macros::datetime, Duration};
+///
+/// let policy = RetentionPolicy::new(Duration::days(30))
+///     .unwrap()
+///     .grandfather_before(datetime!(2024-01-01 0:00 UTC));
+/// let clock = FixedClock::new(datetime!(2024-06-01 0:00 UTC));
+///
+/// assert_eq!(
+///     policy.expires_at(datetime!(2024-05-01 12:00 UTC)).unwrap(),
+///     Some(datetime!(2024-05-31 12:00 UTC))
+/// );
+/// assert!(policy.is_expired(datetime!(2024-05-01 12:00 UTC), &clock).unwrap());
+/// assert!(!policy.is_expired(datetime!(2023-12-31 12:00 UTC), &clock).unwrap());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetentionPolicy {
+    max_age: Duration,
+    grandfather_before: Option<OffsetDateTime>,
+    #[cfg(feature = "business")]
+    business_days: Option<(Weekend, HolidayCalendar)>,
+}
+
+impl RetentionPolicy {
+    /// Creates a policy expiring data older than the given maximum age.
+    ///
+    /// ## Returns
+    /// - `Ok(RetentionPolicy)`: The policy.
+    /// - `Err(DateTimeError)`: If the maximum age is negative.
+    pub fn new(max_age: Duration) -> Result<Self, DateTimeError> {
+        if max_age.is_negative() {
+            return Err(DateTimeError::InvalidComponent(
+                "max_age",
+                format!("{max_age} is negative"),
+            ));
+        }
+        Ok(Self {
+            max_age,
+            grandfather_before: None,
+            #[cfg(feature = "business")]
+            business_days: None,
+        })
+    }
+
+    /// Retains indefinitely the data created before the given instant.
+    pub fn grandfather_before(mut self, instant: OffsetDateTime) -> Self {
+        self.grandfather_before = Some(instant);
+        self
+    }
+
+    /// Counts the whole days of the maximum age as business days, the rest of the maximum age being
+    /// added as is.
+    #[cfg(feature = "business")]
+    pub fn business_days(mut self, weekend: Weekend, calendar: HolidayCalendar) -> Self {
+        self.business_days = Some((weekend, calendar));
+        self
+    }
+
+    /// Returns the maximum age of the data.
+    pub fn max_age(&self) -> Duration {
+        self.max_age
+    }
+
+    /// Computes when data created at the given instant expires.
+    ///
+    /// ## Returns
+    /// - `Ok(Some(OffsetDateTime))`: The expiry instant, in the offset of the creation instant.
+    /// - `Ok(None)`: If the data is grandfathered and never expires.
+    /// - `Err(DateTimeError)`: If the expiry falls outside the supported date range.
+    pub fn expires_at(
+        &self,
+        created_at: OffsetDateTime,
+    ) -> Result<Option<OffsetDateTime>, DateTimeError> {
+        if self
+            .grandfather_before
+            .is_some_and(|instant| created_at < instant)
+        {
+            return Ok(None);
+        }
+        let out_of_range = || DateTimeError::OutOfRange(format!("{created_at} + {}", self.max_age));
+        #[cfg(feature = "business")]
+        if let Some((weekend, calendar)) = &self.business_days {
+            let days = self.max_age.whole_days();
+            let date = add_business_days(created_at.date(), days, weekend, calendar)?;
+            return created_at
+                .replace_date(date)
+                .checked_add(self.max_age - Duration::days(days))
+                .map(Some)
+                .ok_or_else(out_of_range);
+        }
+        created_at
+            .checked_add(self.max_age)
+            .map(Some)
+            .ok_or_else(out_of_range)
+    }
+
+    /// Checks whether data created at the given instant is expired at the current time of the clock.
+    ///
+    /// ## Returns
+    /// - `Ok(bool)`: Whether the expiry instant is not after the current time.
+    /// - `Err(DateTimeError)`: If the expiry falls outside the supported date range.
+    pub fn is_expired(
+        &self,
+        created_at: OffsetDateTime,
+        clock: &impl Clock,
+    ) -> Result<bool, DateTimeError> {
+        Ok(self
+            .expires_at(created_at)?
+            .is_some_and(|expiry| expiry <= clock.now()))
+    }
+}
@@ -0,0 +1,205 @@
+//! Sampling Module
+//!
+//! This module contains a seedable random number generator and a set of methods to sample dates within
+//! a range, either uniformly or following a target distribution by weekday or month, to generate
+//! synthetic datasets.
+use time::Date;
+
+use crate::{DateTimeError, DateTimeRange};
+
+/// Represents a seedable, non-cryptographic random number generator (SplitMix64).
+///
+/// The same seed always yields the same sequence, so generated datasets are reproducible.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::Rng;
+///
+/// let mut rng = Rng::new(42);
+/// assert_eq!(rng.next_u64(), Rng::new(42).next_u64());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    /// Creates a generator from a seed.
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    /// Returns the next random number of the sequence.
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a random number lower than the positive bound, with no modulo bias.
+    pub(crate) fn below(&mut self, bound: u64) -> u64 {
+        let threshold = bound.wrapping_neg() % bound;
+        loop {
+            let product = self.next_u64() as u128 * bound as u128;
+            if product as u64 >= threshold {
+                return (product >> 64) as u64;
+            }
+        }
+    }
+
+    /// Returns a random item of a non-empty slice.
+    fn pick<T: Copy>(&mut self, items: &[T]) -> T {
+        items[self.below(items.len() as u64) as usize]
+    }
+}
+
+/// Represents the target distribution of sampled dates, as relative weights.
+///
+/// - `Strata::Weekday`: It indicates weights by weekday, from Monday (index 0) to Sunday (index 6)
+/// - `Strata::Month`: It indicates weights by month, from January (index 0) to December (index 11)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Strata {
+    Weekday([f64; 7]),
+    Month([f64; 12]),
+}
+
+/// Samples dates uniformly, with replacement, among the calendar dates covered by the range.
+///
+/// Calendar dates are evaluated in the offset of the start of the range.
+///
+/// ## Arguments
+/// - `rng: &mut Rng`: The random number generator.
+/// - `range: &DateTimeRange`: The range to sample from.
+/// - `count: usize`: The number of dates to sample.
+///
+/// ## Returns
+/// - `Vec<Date>`: The sampled dates, in chronological order.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::{sample_dates, DateTimeRange, Rng};
+/// use time::macros::datetime;
+///
+/// let range = DateTimeRange::new(datetime!(2024-05-01 0:00 UTC), datetime!(2024-05-31 0:00 UTC)).unwrap();
+/// let dates = sample_dates(&mut Rng::new(7), &range, 10);
+/// assert_eq!(dates.len(), 10);
+/// assert!(dates.iter().all(|date| date.month() == time::Month::May));
+/// ```
+pub fn sample_dates(rng: &mut Rng, range: &DateTimeRange, count: usize) -> Vec<Date> {
+    let dates = range_dates(range);
+    let mut sample: Vec<Date> = (0..count).map(|_| rng.pick(&dates)).collect();
+    sample.sort_unstable();
+    sample
+}
+
+/// Samples dates, with replacement, among the calendar dates covered by the range so that their
+/// weekdays or months follow the target distribution.
+///
+/// Each weekday or month receives its share of `count` (rounded with the largest remainder method,
+/// after dropping the weekdays or months the range does not cover), and its dates are then sampled
+/// uniformly. Calendar dates are evaluated in the offset of the start of the range.
+///
+/// ## Arguments
+/// - `rng: &mut Rng`: The random number generator.
+/// - `range: &DateTimeRange`: The range to sample from.
+/// - `strata: &Strata`: The target distribution.
+/// - `count: usize`: The number of dates to sample.
+///
+/// ## Returns
+/// - `Ok(Vec<Date>)`: The sampled dates, in chronological order.
+/// - `Err(DateTimeError)`: If a weight is negative or not finite, or no date of the range has a
+///   positive weight.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::{sample_dates_stratified, DateTimeRange, Rng, Strata};
+/// use time::{macros::datetime, Weekday};
+///
+/// let range = DateTimeRange::new(datetime!(2024-01-01 0:00 UTC), datetime!(2024-12-31 0:00 UTC)).unwrap();
+/// // Four times more transactions on weekdays than on weekends.
+/// let strata = Strata::Weekday([4.0, 4.0, 4.0, 4.0, 4.0, 1.0, 1.0]);
+/// let dates = sample_dates_stratified(&mut Rng::new(7), &range, &strata, 22).unwrap();
+/// let sundays = dates.iter().filter(|date| date.weekday() == Weekday::Sunday).count();
+/// assert_eq!(sundays, 1);
+/// ```
+pub fn sample_dates_stratified(
+    rng: &mut Rng,
+    range: &DateTimeRange,
+    strata: &Strata,
+    count: usize,
+) -> Result<Vec<Date>, DateTimeError> {
+    let (weights, stratum): (&[f64], fn(Date) -> usize) = match strata {
+        Strata::Weekday(weights) => (weights, |date| {
+            date.weekday().number_days_from_monday() as usize
+        }),
+        Strata::Month(weights) => (weights, |date| date.month() as usize - 1),
+    };
+    if let Some(weight) = weights.iter().find(|w| !w.is_finite() || **w < 0.0) {
+        return Err(DateTimeError::InvalidComponent(
+            "weight",
+            format!("{weight} is not a finite non-negative number"),
+        ));
+    }
+
+    let mut groups = vec![Vec::new(); weights.len()];
+    for date in range_dates(range) {
+        groups[stratum(date)].push(date);
+    }
+    let total: f64 = groups
+        .iter()
+        .zip(weights)
+        .filter(|(dates, _)| !dates.is_empty())
+        .map(|(_, weight)| weight)
+        .sum();
+    if total <= 0.0 {
+        return Err(DateTimeError::InvalidComponent(
+            "weight",
+            "no date of the range has a positive weight".to_string(),
+        ));
+    }
+
+    // Largest remainder allocation of the sample among the non-empty strata.
+    let quotas: Vec<f64> = groups
+        .iter()
+        .zip(weights)
+        .map(|(dates, weight)| {
+            if dates.is_empty() {
+                0.0
+            } else {
+                count as f64 * weight / total
+            }
+        })
+        .collect();
+    let mut counts: Vec<usize> = quotas.iter().map(|quota| quota.floor() as usize).collect();
+    let mut by_remainder: Vec<usize> = (0..quotas.len()).collect();
+    by_remainder.sort_by(|a, b| {
+        (quotas[*b] - quotas[*b].floor()).total_cmp(&(quotas[*a] - quotas[*a].floor()))
+    });
+    let allocated: usize = counts.iter().sum();
+    for index in by_remainder
+        .into_iter()
+        .filter(|index| !groups[*index].is_empty() && weights[*index] > 0.0)
+        .cycle()
+        .take(count.saturating_sub(allocated))
+    {
+        counts[index] += 1;
+    }
+
+    let mut sample: Vec<Date> = groups
+        .iter()
+        .zip(counts)
+        .flat_map(|(dates, count)| (0..count).map(|_| rng.pick(dates)).collect::<Vec<_>>())
+        .collect();
+    sample.sort_unstable();
+    Ok(sample)
+}
+
+/// Returns the calendar dates covered by the range, in the offset of its start.
+fn range_dates(range: &DateTimeRange) -> Vec<Date> {
+    let (first, last) = range.dates();
+    std::iter::successors(Some(first), |date| date.next_day())
+        .take_while(|date| *date <= last)
+        .collect()
+}
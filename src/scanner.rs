@@ -0,0 +1,216 @@
+//! Timestamp Scanner Module
+//!
+//! This module contains the `TimestampScanner` type, which extracts timestamps from log files line by
+//! line without loading them into memory, and the fixed-format parser it relies on.
+use std::io::BufRead;
+
+use time::{Date, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
+
+use crate::DateTimeError;
+
+/// Represents the format of the timestamps found in log lines.
+///
+/// - `LogFormat::Rfc3339`: Timestamp at the start of the line, e.g. `2024-05-31T12:00:00.123+02:00`
+/// - `LogFormat::Iso8601Space`: Timestamp at the start of the line, e.g. `2024-05-31 12:00:00.123`,
+///   assumed UTC unless followed by an offset
+/// - `LogFormat::CommonLog`: Apache/Nginx common log timestamp, e.g. `[31/May/2024:12:00:00 +0200]`,
+///   found anywhere in the line
+/// - `LogFormat::UnixSeconds`: Unix timestamp at the start of the line, e.g. `1717156800.123`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LogFormat {
+    Rfc3339,
+    Iso8601Space,
+    CommonLog,
+    UnixSeconds,
+}
+
+/// Incrementally extracts timestamps from a reader, one line at a time.
+///
+/// The scanner yields the line number (starting from 1) and the timestamp of each line starting with (or,
+/// for `LogFormat::CommonLog`, containing) a timestamp in the given format. Lines without a timestamp,
+/// such as continuation lines of stack traces, are skipped. Only one line is held in memory at a time.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::{LogFormat, TimestampScanner};
+/// use time::macros::datetime;
+///
+/// let log = "2024-05-31 12:00:00 starting\n  at main.rs:10\n2024-05-31 12:00:01.5 done\n";
+/// let timestamps: Vec<_> = TimestampScanner::new(log.as_bytes(), LogFormat::Iso8601Space)
+///     .collect::<Result<_, _>>()
+///     .unwrap();
+/// assert_eq!(
+///     timestamps,
+///     vec![(1, datetime!(2024-05-31 12:00 UTC)), (3, datetime!(2024-05-31 12:00:01.5 UTC))]
+/// );
+/// ```
+#[derive(Debug)]
+pub struct TimestampScanner<R> {
+    reader: R,
+    format: LogFormat,
+    line: Vec<u8>,
+    line_number: usize,
+}
+
+impl<R: BufRead> TimestampScanner<R> {
+    /// Creates a scanner reading lines from the given reader.
+    pub fn new(reader: R, format: LogFormat) -> Self {
+        Self {
+            reader,
+            format,
+            line: Vec::new(),
+            line_number: 0,
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for TimestampScanner<R> {
+    type Item = Result<(usize, OffsetDateTime), DateTimeError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            self.line.clear();
+            match self.reader.read_until(b'\n', &mut self.line) {
+                Ok(0) => return None,
+                Ok(_) => self.line_number += 1,
+                Err(err) => return Some(Err(err.into())),
+            }
+            if let Some(datetime) = parse_fixed(&self.line, self.format) {
+                return Some(Ok((self.line_number, datetime)));
+            }
+        }
+    }
+}
+
+/// Parses the timestamp of a log line in the given format, without allocating.
+pub(crate) fn parse_fixed(line: &[u8], format: LogFormat) -> Option<OffsetDateTime> {
+    match format {
+        LogFormat::Rfc3339 => parse_iso(line, b'T', true),
+        LogFormat::Iso8601Space => parse_iso(line, b' ', false),
+        LogFormat::CommonLog => {
+            let start = line.iter().position(|byte| *byte == b'[')? + 1;
+            parse_common_log(line.get(start..)?)
+        }
+        LogFormat::UnixSeconds => parse_unix_seconds(line),
+    }
+}
+
+/// Parses `YYYY-MM-DD?HH:MM:SS[.fffffffff][Z|±HH:MM]`, where `?` is the given separator.
+fn parse_iso(bytes: &[u8], separator: u8, offset_required: bool) -> Option<OffsetDateTime> {
+    if bytes.len() < 19
+        || bytes[4] != b'-'
+        || bytes[7] != b'-'
+        || bytes[10] != separator
+        || bytes[13] != b':'
+        || bytes[16] != b':'
+    {
+        return None;
+    }
+    let date = date(
+        digits(bytes, 0, 4)? as i32,
+        digits(bytes, 5, 2)? as u8,
+        digits(bytes, 8, 2)? as u8,
+    )?;
+    let (nanosecond, rest) = fraction(&bytes[19..]);
+    let time = Time::from_hms_nano(
+        digits(bytes, 11, 2)? as u8,
+        digits(bytes, 14, 2)? as u8,
+        digits(bytes, 17, 2)? as u8,
+        nanosecond,
+    )
+    .ok()?;
+    let offset = match rest.first() {
+        Some(b'Z' | b'z') => UtcOffset::UTC,
+        Some(sign @ (b'+' | b'-')) if rest.len() >= 6 && rest[3] == b':' => {
+            offset(*sign, digits(rest, 1, 2)? as i8, digits(rest, 4, 2)? as i8)?
+        }
+        _ if offset_required => return None,
+        _ => UtcOffset::UTC,
+    };
+    Some(PrimitiveDateTime::new(date, time).assume_offset(offset))
+}
+
+/// Parses `DD/Mon/YYYY:HH:MM:SS ±HHMM`.
+fn parse_common_log(bytes: &[u8]) -> Option<OffsetDateTime> {
+    if bytes.len() < 26 || bytes[2] != b'/' || bytes[6] != b'/' || bytes[11] != b':' {
+        return None;
+    }
+    const MONTHS: [&[u8]; 12] = [
+        b"Jan", b"Feb", b"Mar", b"Apr", b"May", b"Jun", b"Jul", b"Aug", b"Sep", b"Oct", b"Nov",
+        b"Dec",
+    ];
+    let month = MONTHS.iter().position(|month| *month == &bytes[3..6])? as u8 + 1;
+    let date = date(
+        digits(bytes, 7, 4)? as i32,
+        month,
+        digits(bytes, 0, 2)? as u8,
+    )?;
+    let time = Time::from_hms(
+        digits(bytes, 12, 2)? as u8,
+        digits(bytes, 15, 2)? as u8,
+        digits(bytes, 18, 2)? as u8,
+    )
+    .ok()?;
+    let offset = offset(
+        bytes[21],
+        digits(bytes, 22, 2)? as i8,
+        digits(bytes, 24, 2)? as i8,
+    )?;
+    Some(PrimitiveDateTime::new(date, time).assume_offset(offset))
+}
+
+/// Parses `SSSSSSSSSS[.fffffffff]`.
+fn parse_unix_seconds(bytes: &[u8]) -> Option<OffsetDateTime> {
+    let len = bytes
+        .iter()
+        .take_while(|byte| byte.is_ascii_digit())
+        .count();
+    if len == 0 || len > 12 {
+        return None;
+    }
+    let seconds = digits(bytes, 0, len)? as i64;
+    let (nanosecond, _) = fraction(&bytes[len..]);
+    OffsetDateTime::from_unix_timestamp_nanos(seconds as i128 * 1_000_000_000 + nanosecond as i128)
+        .ok()
+}
+
+/// Reads `len` ASCII digits starting at `start`.
+fn digits(bytes: &[u8], start: usize, len: usize) -> Option<u64> {
+    bytes
+        .get(start..start + len)?
+        .iter()
+        .try_fold(0, |value, byte| {
+            byte.is_ascii_digit()
+                .then(|| value * 10 + (byte - b'0') as u64)
+        })
+}
+
+/// Reads an optional `.fffffffff` fraction, returning the nanoseconds and the remaining bytes. Digits
+/// beyond the nanosecond are ignored.
+fn fraction(bytes: &[u8]) -> (u32, &[u8]) {
+    if bytes.first() != Some(&b'.') {
+        return (0, bytes);
+    }
+    let len = bytes[1..]
+        .iter()
+        .take_while(|byte| byte.is_ascii_digit())
+        .count();
+    let nanosecond = bytes[1..1 + len]
+        .iter()
+        .chain(std::iter::repeat(&b'0'))
+        .take(9)
+        .fold(0, |value, byte| value * 10 + (byte - b'0') as u32);
+    (nanosecond, &bytes[1 + len..])
+}
+
+fn date(year: i32, month: u8, day: u8) -> Option<Date> {
+    Date::from_calendar_date(year, Month::try_from(month).ok()?, day).ok()
+}
+
+fn offset(sign: u8, hours: i8, minutes: i8) -> Option<UtcOffset> {
+    match sign {
+        b'+' => UtcOffset::from_hms(hours, minutes, 0).ok(),
+        b'-' => UtcOffset::from_hms(-hours, -minutes, 0).ok(),
+        _ => None,
+    }
+}
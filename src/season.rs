@@ -0,0 +1,213 @@
+//! Season Module
+//!
+//! This module contains a set of methods to determine the season of a date, following either the
+//! meteorological or the astronomical definition, and to compute the solstices and equinoxes of a year.
+use time::{Date, Month, OffsetDateTime};
+
+use crate::DateTimeError;
+
+/// Julian day of the Unix epoch.
+const UNIX_EPOCH_JULIAN_DAY: f64 = 2_440_587.5;
+
+/// Periodic terms (amplitude, phase and speed in degrees) correcting the mean solstices and equinoxes,
+/// from Jean Meeus, Astronomical Algorithms, chapter 27.
+const PERIODIC_TERMS: [(f64, f64, f64); 24] = [
+    (485.0, 324.96, 1934.136),
+    (203.0, 337.23, 32964.467),
+    (199.0, 342.08, 20.186),
+    (182.0, 27.85, 445267.112),
+    (156.0, 73.14, 45036.886),
+    (136.0, 171.52, 22518.443),
+    (77.0, 222.54, 65928.934),
+    (74.0, 296.72, 3034.906),
+    (70.0, 243.58, 9037.513),
+    (58.0, 119.81, 33718.147),
+    (52.0, 297.17, 150.678),
+    (50.0, 21.02, 2281.226),
+    (45.0, 247.54, 29929.562),
+    (44.0, 325.15, 31555.956),
+    (29.0, 60.93, 4443.417),
+    (18.0, 155.12, 67555.328),
+    (17.0, 288.79, 4562.452),
+    (16.0, 198.04, 62894.029),
+    (14.0, 199.76, 31436.921),
+    (12.0, 95.39, 14577.848),
+    (12.0, 287.11, 31931.756),
+    (12.0, 320.81, 34777.259),
+    (9.0, 227.73, 1222.114),
+    (8.0, 15.45, 16859.074),
+];
+
+/// Represents a season.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Season {
+    Spring,
+    Summer,
+    Autumn,
+    Winter,
+}
+
+/// Represents the hemisphere of a location.
+///
+/// - `Hemisphere::Northern`: Summer starts in June
+/// - `Hemisphere::Southern`: Summer starts in December
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Hemisphere {
+    Northern,
+    Southern,
+}
+
+/// Represents the rule used to define seasons.
+///
+/// - `SeasonRule::Meteorological`: Seasons made of whole months (e.g. summer is June, July and August in
+///   the northern hemisphere)
+/// - `SeasonRule::Astronomical`: Seasons starting at solstices and equinoxes (e.g. summer starts at the
+///   June solstice in the northern hemisphere)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SeasonRule {
+    Meteorological,
+    Astronomical,
+}
+
+/// Represents a solstice or an equinox.
+///
+/// - `SolarEvent::MarchEquinox`: It indicates the March equinox
+/// - `SolarEvent::JuneSolstice`: It indicates the June solstice
+/// - `SolarEvent::SeptemberEquinox`: It indicates the September equinox
+/// - `SolarEvent::DecemberSolstice`: It indicates the December solstice
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SolarEvent {
+    MarchEquinox,
+    JuneSolstice,
+    SeptemberEquinox,
+    DecemberSolstice,
+}
+
+/// Computes the instant of a solstice or an equinox of the given year, in UTC.
+///
+/// The instant is computed with the algorithm of Jean Meeus (Astronomical Algorithms, chapter 27),
+/// accurate to a few minutes between years -1000 and 3000, which makes the date exact but for events
+/// falling within minutes of midnight. The difference between dynamical and universal time is ignored.
+///
+/// ## Arguments
+/// - `year: i32`: The year of the event.
+/// - `event: SolarEvent`: The solstice or equinox.
+///
+/// ## Returns
+/// - `Ok(OffsetDateTime)`: The instant of the event, in UTC.
+/// - `Err(DateTimeError)`: If the instant is out of the supported date range.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::{solar_event, SolarEvent};
+/// use time::macros::date;
+///
+/// let equinox = solar_event(2024, SolarEvent::MarchEquinox).unwrap();
+/// assert_eq!(equinox.date(), date!(2024 - 03 - 20));
+/// ```
+pub fn solar_event(year: i32, event: SolarEvent) -> Result<OffsetDateTime, DateTimeError> {
+    // Mean event, as a polynomial of the millennia from year 0 or from year 2000.
+    let (y, coefficients) = if year < 1000 {
+        let coefficients = match event {
+            SolarEvent::MarchEquinox => [1721139.29189, 365242.13740, 0.06134, 0.00111, -0.00071],
+            SolarEvent::JuneSolstice => [1721233.25401, 365241.72562, -0.05323, 0.00907, 0.00025],
+            SolarEvent::SeptemberEquinox => {
+                [1721325.70455, 365242.49558, -0.11677, -0.00297, 0.00074]
+            }
+            SolarEvent::DecemberSolstice => {
+                [1721414.39987, 365242.88257, -0.00769, -0.00933, -0.00006]
+            }
+        };
+        (year as f64 / 1000.0, coefficients)
+    } else {
+        let coefficients = match event {
+            SolarEvent::MarchEquinox => [2451623.80984, 365242.37404, 0.05169, -0.00411, -0.00057],
+            SolarEvent::JuneSolstice => [2451716.56767, 365241.62603, 0.00325, 0.00888, -0.00030],
+            SolarEvent::SeptemberEquinox => {
+                [2451810.21715, 365242.01767, -0.11575, 0.00337, 0.00078]
+            }
+            SolarEvent::DecemberSolstice => {
+                [2451900.05952, 365242.74049, -0.06223, -0.00823, 0.00032]
+            }
+        };
+        ((year - 2000) as f64 / 1000.0, coefficients)
+    };
+    let mean = coefficients.iter().rev().fold(0.0, |sum, c| sum * y + c);
+
+    let centuries = (mean - 2_451_545.0) / 36_525.0;
+    let w = (35_999.373 * centuries - 2.47).to_radians();
+    let lambda = 1.0 + 0.0334 * w.cos() + 0.0007 * (2.0 * w).cos();
+    let sum: f64 = PERIODIC_TERMS
+        .iter()
+        .map(|(a, b, c)| a * (b + c * centuries).to_radians().cos())
+        .sum();
+    let julian_day = mean + 0.00001 * sum / lambda;
+
+    let seconds = ((julian_day - UNIX_EPOCH_JULIAN_DAY) * 86_400.0).round() as i64;
+    OffsetDateTime::from_unix_timestamp(seconds)
+        .map_err(|err| DateTimeError::OutOfRange(err.to_string()))
+}
+
+/// Returns the season of the given date.
+///
+/// Astronomical seasons start on the UTC dates of the solstices and equinoxes of the year of the
+/// date, as computed by `solar_event`.
+///
+/// ## Arguments
+/// - `date: Date`: The date to classify.
+/// - `hemisphere: Hemisphere`: The hemisphere of the location.
+/// - `rule: SeasonRule`: The definition of seasons.
+///
+/// ## Returns
+/// - `Season`: The season of the date.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::{season_of, Hemisphere, Season, SeasonRule};
+/// use time::macros::date;
+///
+/// let date = date!(2024 - 03 - 10);
+/// assert_eq!(season_of(date, Hemisphere::Northern, SeasonRule::Meteorological), Season::Spring);
+/// assert_eq!(season_of(date, Hemisphere::Northern, SeasonRule::Astronomical), Season::Winter);
+/// assert_eq!(season_of(date, Hemisphere::Southern, SeasonRule::Meteorological), Season::Autumn);
+/// ```
+pub fn season_of(date: Date, hemisphere: Hemisphere, rule: SeasonRule) -> Season {
+    let northern = match rule {
+        SeasonRule::Meteorological => match date.month() {
+            Month::March | Month::April | Month::May => Season::Spring,
+            Month::June | Month::July | Month::August => Season::Summer,
+            Month::September | Month::October | Month::November => Season::Autumn,
+            Month::December | Month::January | Month::February => Season::Winter,
+        },
+        SeasonRule::Astronomical => {
+            let starts = |event, month, day| {
+                solar_event(date.year(), event)
+                    .map(|instant| instant.date())
+                    // Events out of the supported range fall back to their usual dates.
+                    .or_else(|_| Date::from_calendar_date(date.year(), month, day))
+                    .unwrap_or(Date::MAX)
+                    <= date
+            };
+            if starts(SolarEvent::DecemberSolstice, Month::December, 21) {
+                Season::Winter
+            } else if starts(SolarEvent::SeptemberEquinox, Month::September, 22) {
+                Season::Autumn
+            } else if starts(SolarEvent::JuneSolstice, Month::June, 21) {
+                Season::Summer
+            } else if starts(SolarEvent::MarchEquinox, Month::March, 20) {
+                Season::Spring
+            } else {
+                Season::Winter
+            }
+        }
+    };
+    match hemisphere {
+        Hemisphere::Northern => northern,
+        Hemisphere::Southern => match northern {
+            Season::Spring => Season::Autumn,
+            Season::Summer => Season::Winter,
+            Season::Autumn => Season::Spring,
+            Season::Winter => Season::Summer,
+        },
+    }
+}
@@ -0,0 +1,93 @@
+//! SLA Module
+//!
+//! This module contains the `Sla` type, which bundles business hours, weekend, holiday calendar and
+//! response target to compute deadlines and elapsed business time of tickets, requests, incidents.
+use time::{Duration, OffsetDateTime};
+
+use crate::{
+    add_business_time, business_time_between, BusinessHours, DateTimeError, HolidayCalendar,
+    Weekend,
+};
+
+/// Represents a service level agreement measured in business time.
+///
+/// The response target is consumed only within business hours of business days, so a 4 hours target
+/// for a request opened on Friday at 16:00 (with 09:00-17:00 hours) expires on Monday at 12:00.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::{BusinessHours, HolidayCalendar, Sla, Weekend};
+/// use time::{macros::{datetime, offset, time}, Duration};
+///
+/// let hours = BusinessHours::new(time!(9:00), time!(17:00), offset!(UTC)).unwrap();
+/// let sla = Sla::new(hours, Weekend::default(), HolidayCalendar::new(), Duration::hours(4));
+///
+/// let opened_at = datetime!(2025-05-09 16:00 UTC);
+/// assert_eq!(sla.deadline(opened_at).unwrap(), datetime!(2025-05-12 12:00 UTC));
+/// assert!(!sla.breached(opened_at, datetime!(2025-05-12 11:00 UTC)));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sla {
+    hours: BusinessHours,
+    weekend: Weekend,
+    calendar: HolidayCalendar,
+    target: Duration,
+}
+
+impl Sla {
+    /// Creates a service level agreement.
+    ///
+    /// ## Arguments
+    /// - `hours: BusinessHours`: The opening hours in which the target is consumed.
+    /// - `weekend: Weekend`: The weekdays which are not business days.
+    /// - `calendar: HolidayCalendar`: The holidays which are not business days.
+    /// - `target: Duration`: The response target, expressed in business time.
+    pub fn new(
+        hours: BusinessHours,
+        weekend: Weekend,
+        calendar: HolidayCalendar,
+        target: Duration,
+    ) -> Self {
+        Self {
+            hours,
+            weekend,
+            calendar,
+            target,
+        }
+    }
+
+    /// Returns the response target, expressed in business time.
+    pub fn target(&self) -> Duration {
+        self.target
+    }
+
+    /// Computes the deadline of a request opened at the given datetime.
+    ///
+    /// ## Returns
+    /// - `Ok(OffsetDateTime)`: The deadline, expressed in the offset of the business hours.
+    /// - `Err(DateTimeError)`: If the deadline falls outside the supported date range.
+    pub fn deadline(&self, opened_at: OffsetDateTime) -> Result<OffsetDateTime, DateTimeError> {
+        add_business_time(
+            opened_at,
+            self.target,
+            &self.hours,
+            &self.weekend,
+            &self.calendar,
+        )
+    }
+
+    /// Computes the business time elapsed since the request was opened.
+    pub fn elapsed(&self, opened_at: OffsetDateTime, now: OffsetDateTime) -> Duration {
+        business_time_between(opened_at, now, &self.hours, &self.weekend, &self.calendar)
+    }
+
+    /// Computes the business time left before the target is breached, zero if already breached.
+    pub fn remaining(&self, opened_at: OffsetDateTime, now: OffsetDateTime) -> Duration {
+        (self.target - self.elapsed(opened_at, now)).max(Duration::ZERO)
+    }
+
+    /// Checks whether the elapsed business time exceeds the response target.
+    pub fn breached(&self, opened_at: OffsetDateTime, now: OffsetDateTime) -> bool {
+        self.elapsed(opened_at, now) > self.target
+    }
+}
@@ -0,0 +1,155 @@
+//! Timestamp Slug Module
+//!
+//! This module contains a set of methods to embed timestamps in file names, as sortable and
+//! filesystem-safe slugs (e.g. `20240531T123000Z`), and to extract them back.
+use time::{Date, Month, OffsetDateTime, Time, UtcOffset};
+
+use crate::DateTimeError;
+
+/// Represents the resolution of a timestamp slug.
+///
+/// - `Resolution::Day`: It indicates a date, `20240531`
+/// - `Resolution::Minute`: It indicates a UTC datetime to the minute, `20240531T1230Z`
+/// - `Resolution::Second`: It indicates a UTC datetime to the second, `20240531T123000Z`
+/// - `Resolution::Millisecond`: It indicates a UTC datetime to the millisecond, `20240531T123000250Z`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Resolution {
+    Day,
+    Minute,
+    Second,
+    Millisecond,
+}
+
+/// Generates the slug of a datetime, truncated to the given resolution.
+///
+/// The datetime is converted to UTC first, so slugs of the same resolution sort chronologically and
+/// contain only ASCII digits, `T` and `Z`.
+///
+/// ## Arguments
+/// - `datetime: OffsetDateTime`: The datetime.
+/// - `resolution: Resolution`: The resolution of the slug.
+///
+/// ## Returns
+/// - `Ok(String)`: The slug.
+/// - `Err(DateTimeError)`: If the UTC year is not between 0 and 9999.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::{timestamp_slug, Resolution};
+/// use time::macros::datetime;
+///
+/// let slug = timestamp_slug(datetime!(2024-05-31 14:30:00.25 +2), Resolution::Second).unwrap();
+/// assert_eq!(format!("snapshot_{slug}.parquet"), "snapshot_20240531T123000Z.parquet");
+/// ```
+pub fn timestamp_slug(
+    datetime: OffsetDateTime,
+    resolution: Resolution,
+) -> Result<String, DateTimeError> {
+    let out_of_range =
+        |year| DateTimeError::InvalidComponent("year", format!("{year} is not between 0 and 9999"));
+    // The conversion only fails when the UTC date falls one year past the supported range.
+    let utc = datetime.checked_to_offset(UtcOffset::UTC).ok_or_else(|| {
+        out_of_range(datetime.year() - datetime.offset().whole_seconds().signum())
+    })?;
+    if !(0..=9999).contains(&utc.year()) {
+        return Err(out_of_range(utc.year()));
+    }
+    let date = format!("{:04}{:02}{:02}", utc.year(), utc.month() as u8, utc.day());
+    let (hour, minute, second) = (utc.hour(), utc.minute(), utc.second());
+    Ok(match resolution {
+        Resolution::Day => date,
+        Resolution::Minute => format!("{date}T{hour:02}{minute:02}Z"),
+        Resolution::Second => format!("{date}T{hour:02}{minute:02}{second:02}Z"),
+        Resolution::Millisecond => format!(
+            "{date}T{hour:02}{minute:02}{second:02}{:03}Z",
+            utc.millisecond()
+        ),
+    })
+}
+
+/// Extracts the first timestamp slug found in a file name.
+///
+/// Slugs of any resolution are recognized, as long as they are not part of a longer run of digits.
+/// Date slugs are read as midnight UTC.
+///
+/// ## Arguments
+/// - `name: &str`: The file name (or any string containing a slug).
+///
+/// ## Returns
+/// - `Ok((OffsetDateTime, Resolution))`: The UTC datetime of the slug and its resolution.
+/// - `Err(DateTimeError)`: If the name contains no valid slug.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::{parse_timestamp_slug, Resolution};
+/// use time::macros::datetime;
+///
+/// let (datetime, resolution) = parse_timestamp_slug("exports/v3_20240531T1230Z.csv.gz").unwrap();
+/// assert_eq!(datetime, datetime!(2024-05-31 12:30 UTC));
+/// assert_eq!(resolution, Resolution::Minute);
+/// ```
+pub fn parse_timestamp_slug(name: &str) -> Result<(OffsetDateTime, Resolution), DateTimeError> {
+    let bytes = name.as_bytes();
+    let digits_from = |start: usize| {
+        bytes.get(start..).map_or(0, |rest| {
+            rest.iter().take_while(|byte| byte.is_ascii_digit()).count()
+        })
+    };
+    (0..bytes.len())
+        .filter(|start| *start == 0 || !bytes[start - 1].is_ascii_digit())
+        .filter(|start| digits_from(*start) == 8)
+        .find_map(|start| {
+            let time_start = start + 9;
+            let time = match bytes.get(start + 8) {
+                Some(b'T' | b't') => {
+                    let length = digits_from(time_start);
+                    match bytes.get(time_start + length) {
+                        Some(b'Z' | b'z') => Some(&name[time_start..time_start + length]),
+                        _ => return None,
+                    }
+                }
+                _ => None,
+            };
+            parse_slug(&name[start..start + 8], time)
+        })
+        .ok_or_else(|| DateTimeError::InvalidDateTimeFormat(name.to_string()))
+}
+
+/// Parses the date digits and the optional time digits of a slug.
+fn parse_slug(date: &str, time: Option<&str>) -> Option<(OffsetDateTime, Resolution)> {
+    let number = |digits: &str, range: std::ops::Range<usize>| digits[range].parse::<u32>().ok();
+    let month = Month::try_from(number(date, 4..6)? as u8).ok()?;
+    let date =
+        Date::from_calendar_date(number(date, 0..4)? as i32, month, number(date, 6..8)? as u8)
+            .ok()?;
+    let (time, resolution) = match time {
+        None => (Time::MIDNIGHT, Resolution::Day),
+        Some(time) => {
+            let resolution = match time.len() {
+                4 => Resolution::Minute,
+                6 => Resolution::Second,
+                9 => Resolution::Millisecond,
+                _ => return None,
+            };
+            let second = if time.len() > 4 {
+                number(time, 4..6)?
+            } else {
+                0
+            };
+            let millisecond = if time.len() > 6 {
+                number(time, 6..9)?
+            } else {
+                0
+            };
+            let time = Time::from_hms_milli(
+                number(time, 0..2)? as u8,
+                number(time, 2..4)? as u8,
+                second as u8,
+                millisecond as u16,
+            )
+            .ok()?;
+            (time, resolution)
+        }
+    };
+    Some((date.with_time(time).assume_utc(), resolution))
+}
@@ -0,0 +1,376 @@
+//! Series Statistics Module
+//!
+//! This module contains a set of methods to profile series of datetimes (descriptive statistics,
+//! spacing between observations, distributions).
+use time::{Duration, OffsetDateTime, Time, UtcOffset};
+
+use crate::DateTimeError;
+
+/// Represents the descriptive statistics of a series of datetimes.
+///
+/// - `min`: The earliest datetime of the series.
+/// - `max`: The latest datetime of the series.
+/// - `span`: The duration between `min` and `max`.
+/// - `median_spacing`: The median duration between consecutive datetimes (once sorted).
+/// - `mean_spacing`: The mean duration between consecutive datetimes (once sorted).
+/// - `duplicates`: The number of datetimes representing an instant already present in the series.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SeriesStats {
+    pub min: OffsetDateTime,
+    pub max: OffsetDateTime,
+    pub span: Duration,
+    pub median_spacing: Duration,
+    pub mean_spacing: Duration,
+    pub duplicates: usize,
+}
+
+/// Computes the descriptive statistics of a series of datetimes.
+///
+/// The function takes a slice of datetimes, in any order, and computes its descriptive statistics.
+/// Datetimes are compared as instants, so two datetimes with different offsets representing the same
+/// instant are counted as duplicates. A series with a single datetime has zero span and spacing.
+///
+/// ## Arguments
+/// - `datetimes: &[OffsetDateTime]`: The series of datetimes to profile.
+///
+/// ## Returns
+/// - `Ok(SeriesStats)`: The descriptive statistics of the series.
+/// - `Err(DateTimeError)`: If the series is empty.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::series_stats;
+/// use time::{macros::datetime, Duration};
+///
+/// let stats = series_stats(&[
+///     datetime!(2025-01-03 0:00 UTC),
+///     datetime!(2025-01-01 0:00 UTC),
+///     datetime!(2025-01-02 0:00 UTC),
+/// ])
+/// .unwrap();
+/// assert_eq!(stats.span, Duration::days(2));
+/// assert_eq!(stats.median_spacing, Duration::days(1));
+/// ```
+pub fn series_stats(datetimes: &[OffsetDateTime]) -> Result<SeriesStats, DateTimeError> {
+    let mut sorted = datetimes.to_vec();
+    sorted.sort_unstable();
+    let (Some(&min), Some(&max)) = (sorted.first(), sorted.last()) else {
+        return Err(DateTimeError::EmptySeries);
+    };
+
+    let mut spacings: Vec<i128> = sorted
+        .windows(2)
+        .map(|pair| (pair[1] - pair[0]).whole_nanoseconds())
+        .collect();
+    spacings.sort_unstable();
+    let duplicates = spacings.iter().filter(|spacing| **spacing == 0).count();
+    let span = max - min;
+
+    let median_spacing = match spacings.len() {
+        0 => Duration::ZERO,
+        _ => duration_from_nanos(median_of_sorted(&spacings)),
+    };
+    let mean_spacing = match spacings.len() {
+        0 => Duration::ZERO,
+        len => duration_from_nanos(span.whole_nanoseconds() / len as i128),
+    };
+
+    Ok(SeriesStats {
+        min,
+        max,
+        span,
+        median_spacing,
+        mean_spacing,
+        duplicates,
+    })
+}
+
+/// Computes the mean instant of a series of datetimes.
+///
+/// Datetimes are averaged as offsets from the first one, in 128-bit nanoseconds, so the computation
+/// cannot overflow nor lose precision whatever the length and the span of the series.
+///
+/// ## Arguments
+/// - `datetimes: &[OffsetDateTime]`: The series of datetimes, in any order.
+///
+/// ## Returns
+/// - `Ok(OffsetDateTime)`: The mean instant, in the offset of the first datetime.
+/// - `Err(DateTimeError)`: If the series is empty.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::mean_datetime;
+/// use time::macros::datetime;
+///
+/// let mean = mean_datetime(&[datetime!(2025-01-01 0:00 UTC), datetime!(2025-01-02 0:00 UTC)]).unwrap();
+/// assert_eq!(mean, datetime!(2025-01-01 12:00 UTC));
+/// ```
+pub fn mean_datetime(datetimes: &[OffsetDateTime]) -> Result<OffsetDateTime, DateTimeError> {
+    let Some(&first) = datetimes.first() else {
+        return Err(DateTimeError::EmptySeries);
+    };
+    let sum: i128 = datetimes
+        .iter()
+        .map(|datetime| (*datetime - first).whole_nanoseconds())
+        .sum();
+    Ok(first + duration_from_nanos(sum / datetimes.len() as i128))
+}
+
+/// Computes the weighted mean instant of a series of datetimes.
+///
+/// The function is meant for duration-weighted dates (e.g. the Macaulay duration date of cash flows
+/// weighted by their present value) and centroids of events. Datetimes are averaged as offsets from the
+/// first one, so the computation cannot overflow; the result is precise to the microsecond over spans
+/// of centuries.
+///
+/// ## Arguments
+/// - `datetimes: &[(OffsetDateTime, f64)]`: The series of datetimes, in any order, with their weights.
+///
+/// ## Returns
+/// - `Ok(OffsetDateTime)`: The weighted mean instant, in the offset of the first datetime.
+/// - `Err(DateTimeError)`: If the series is empty, a weight is negative or not finite, or the weights
+///   sum to zero.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::weighted_mean_datetime;
+/// use time::macros::datetime;
+///
+/// let mean = weighted_mean_datetime(&[
+///     (datetime!(2025-01-01 0:00 UTC), 3.0),
+///     (datetime!(2025-01-05 0:00 UTC), 1.0),
+/// ])
+/// .unwrap();
+/// assert_eq!(mean, datetime!(2025-01-02 0:00 UTC));
+/// ```
+pub fn weighted_mean_datetime(
+    datetimes: &[(OffsetDateTime, f64)],
+) -> Result<OffsetDateTime, DateTimeError> {
+    let Some(&(first, _)) = datetimes.first() else {
+        return Err(DateTimeError::EmptySeries);
+    };
+    if let Some((_, weight)) = datetimes
+        .iter()
+        .find(|(_, weight)| !weight.is_finite() || *weight < 0.0)
+    {
+        return Err(DateTimeError::InvalidComponent(
+            "weight",
+            format!("{weight} is not a finite non-negative number"),
+        ));
+    }
+    let total: f64 = datetimes.iter().map(|(_, weight)| weight).sum();
+    if total <= 0.0 {
+        return Err(DateTimeError::InvalidComponent(
+            "weight",
+            "weights sum to zero".to_string(),
+        ));
+    }
+    let offset: f64 = datetimes
+        .iter()
+        .map(|(datetime, weight)| (*datetime - first).whole_nanoseconds() as f64 * weight / total)
+        .sum();
+    Ok(first + duration_from_nanos(offset.round() as i128))
+}
+
+/// Estimates the systematic offset of a series of datetimes with respect to another one (e.g. the
+/// skew of exchange feed timestamps against capture times).
+///
+/// The datetimes of the two series are paired by position, and the offset is the median of the
+/// differences `series_b[i] - series_a[i]`, once the differences further than three scaled median
+/// absolute deviations from the median are rejected as outliers (e.g. a message delayed by a
+/// reconnection).
+///
+/// ## Arguments
+/// - `series_a: &[OffsetDateTime]`: The reference series.
+/// - `series_b: &[OffsetDateTime]`: The series whose offset is estimated, paired by position with the
+///   reference series.
+///
+/// ## Returns
+/// - `Ok(Duration)`: The estimated offset, positive if `series_b` is late on `series_a`.
+/// - `Err(DateTimeError)`: If the series are empty or do not have the same length.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::estimate_offset;
+/// use time::{macros::datetime, Duration};
+///
+/// let exchange = [
+///     datetime!(2024-05-31 9:00:00 UTC),
+///     datetime!(2024-05-31 9:00:01 UTC),
+///     datetime!(2024-05-31 9:00:02 UTC),
+///     datetime!(2024-05-31 9:00:03 UTC),
+/// ];
+/// let captured = [
+///     datetime!(2024-05-31 9:00:00.012 UTC),
+///     datetime!(2024-05-31 9:00:01.011 UTC),
+///     datetime!(2024-05-31 9:00:04.500 UTC),
+///     datetime!(2024-05-31 9:00:03.013 UTC),
+/// ];
+/// assert_eq!(estimate_offset(&exchange, &captured).unwrap(), Duration::milliseconds(12));
+/// ```
+pub fn estimate_offset(
+    series_a: &[OffsetDateTime],
+    series_b: &[OffsetDateTime],
+) -> Result<Duration, DateTimeError> {
+    if series_a.is_empty() {
+        return Err(DateTimeError::EmptySeries);
+    }
+    if series_a.len() != series_b.len() {
+        return Err(DateTimeError::InvalidComponent(
+            "series_b",
+            format!(
+                "has {} datetimes, expected {} as series_a",
+                series_b.len(),
+                series_a.len()
+            ),
+        ));
+    }
+    let mut deltas: Vec<i128> = series_a
+        .iter()
+        .zip(series_b)
+        .map(|(a, b)| (*b - *a).whole_nanoseconds())
+        .collect();
+    deltas.sort_unstable();
+    let median = median_of_sorted(&deltas);
+
+    let mut deviations: Vec<i128> = deltas.iter().map(|delta| (delta - median).abs()).collect();
+    deviations.sort_unstable();
+    // 1.4826 scales the median absolute deviation to the standard deviation of normal data.
+    let threshold = 3.0 * 1.4826 * median_of_sorted(&deviations) as f64;
+    let inliers: Vec<i128> = deltas
+        .into_iter()
+        .filter(|delta| (delta - median).abs() as f64 <= threshold)
+        .collect();
+    Ok(duration_from_nanos(median_of_sorted(&inliers)))
+}
+
+/// Represents the dimension used to bucket datetimes in a histogram.
+///
+/// - `Dimension::Weekday`: 7 buckets, from Monday (index 0) to Sunday (index 6)
+/// - `Dimension::HourOfDay`: 24 buckets, from hour 0 to hour 23
+/// - `Dimension::MonthOfYear`: 12 buckets, from January (index 0) to December (index 11)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Dimension {
+    Weekday,
+    HourOfDay,
+    MonthOfYear,
+}
+
+/// Counts the datetimes falling into each bucket of the given dimension.
+///
+/// The function takes a slice of datetimes and a dimension, and counts how many datetimes fall into each
+/// bucket of the dimension. Datetimes are bucketed according to their own offset (e.g. `23:30 -02:00`
+/// falls into hour 23, not into hour 1).
+///
+/// ## Arguments
+/// - `datetimes: &[OffsetDateTime]`: The datetimes to bucket.
+/// - `dimension: Dimension`: The dimension used for bucketing.
+///
+/// ## Returns
+/// - `Vec<usize>`: The count of datetimes per bucket, indexed as described in `Dimension`.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::{histogram, Dimension};
+/// use time::macros::datetime;
+///
+/// let counts = histogram(
+///     &[datetime!(2025-05-12 9:00 UTC), datetime!(2025-05-13 9:00 UTC)],
+///     Dimension::Weekday,
+/// );
+/// assert_eq!(counts, vec![1, 1, 0, 0, 0, 0, 0]);
+/// ```
+pub fn histogram(datetimes: &[OffsetDateTime], dimension: Dimension) -> Vec<usize> {
+    let buckets = match dimension {
+        Dimension::Weekday => 7,
+        Dimension::HourOfDay => 24,
+        Dimension::MonthOfYear => 12,
+    };
+    let mut counts = vec![0; buckets];
+    for datetime in datetimes {
+        let index = match dimension {
+            Dimension::Weekday => datetime.weekday().number_days_from_monday() as usize,
+            Dimension::HourOfDay => datetime.hour() as usize,
+            Dimension::MonthOfYear => datetime.month() as usize - 1,
+        };
+        counts[index] += 1;
+    }
+    counts
+}
+
+/// Assigns each datetime to the time-of-day bucket (e.g. a daypart or a trading session) containing its
+/// local time in the given offset.
+///
+/// Buckets run from their start time (included) to their end time (excluded). A bucket whose end is
+/// before its start wraps around midnight (e.g. `22:00`-`06:00` for overnight), and a bucket whose start
+/// equals its end covers the whole day. When buckets overlap, a datetime is assigned to the first one.
+///
+/// ## Arguments
+/// - `datetimes: &[OffsetDateTime]`: The datetimes to assign.
+/// - `buckets: &[(Time, Time)]`: The start and end times of the buckets.
+/// - `offset: UtcOffset`: The offset in which local times are evaluated.
+///
+/// ## Returns
+/// - `Ok(Vec<Option<usize>>)`: For each datetime, the index of its bucket, or `None` if no bucket contains
+///   it.
+/// - `Err(DateTimeError::OutOfRange)`: If the local date of a datetime is outside the supported range of
+///   years.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::bucket_by_time_of_day;
+/// use time::macros::{datetime, offset, time};
+///
+/// let dayparts = ["overnight", "morning", "afternoon"];
+/// let buckets = [
+///     (time!(22:00), time!(6:00)),
+///     (time!(6:00), time!(12:00)),
+///     (time!(12:00), time!(18:00)),
+/// ];
+/// let events = [datetime!(2024-05-31 5:30 UTC), datetime!(2024-05-31 18:30 UTC)];
+/// let buckets = bucket_by_time_of_day(&events, &buckets, offset!(+2)).unwrap();
+/// assert_eq!(buckets, [Some(1), None]);
+/// assert_eq!(dayparts[buckets[0].unwrap()], "morning");
+/// ```
+pub fn bucket_by_time_of_day(
+    datetimes: &[OffsetDateTime],
+    buckets: &[(Time, Time)],
+    offset: UtcOffset,
+) -> Result<Vec<Option<usize>>, DateTimeError> {
+    datetimes
+        .iter()
+        .map(|datetime| {
+            let time = datetime
+                .checked_to_offset(offset)
+                .ok_or_else(|| DateTimeError::OutOfRange(format!("{datetime} in {offset}")))?
+                .time();
+            Ok(buckets
+                .iter()
+                .position(|(start, end)| match start.cmp(end) {
+                    std::cmp::Ordering::Less => (*start..*end).contains(&time),
+                    std::cmp::Ordering::Equal => true,
+                    std::cmp::Ordering::Greater => time >= *start || time < *end,
+                }))
+        })
+        .collect()
+}
+
+/// Returns the median of a non-empty sorted slice, the mean of the two middle values for even lengths.
+fn median_of_sorted(sorted: &[i128]) -> i128 {
+    let len = sorted.len();
+    if len % 2 == 1 {
+        sorted[len / 2]
+    } else {
+        (sorted[len / 2 - 1] + sorted[len / 2]) / 2
+    }
+}
+
+/// Builds a `Duration` from a number of nanoseconds expressed as `i128`.
+///
+/// The `time` crate only exposes an `i64` constructor, which cannot hold the sum of long spans.
+pub(crate) fn duration_from_nanos(nanos: i128) -> Duration {
+    Duration::new(
+        (nanos / 1_000_000_000) as i64,
+        (nanos % 1_000_000_000) as i32,
+    )
+}
@@ -0,0 +1,292 @@
+//! Textual Date Module
+//!
+//! This module contains a set of methods to parse dates and date ranges written by people (e.g.
+//! `May 31st, 2024`, `Jan 2024 to Mar 2024`).
+use time::{macros::format_description, Date, Month};
+
+use crate::{
+    period::{days_range, HalfYear, Quarter},
+    BoundKind, DateTimeError, DateTimeRange,
+};
+
+const MONTH_NAMES: [&str; 12] = [
+    "january",
+    "february",
+    "march",
+    "april",
+    "may",
+    "june",
+    "july",
+    "august",
+    "september",
+    "october",
+    "november",
+    "december",
+];
+
+const WEEKDAY_NAMES: [&str; 7] = [
+    "monday",
+    "tuesday",
+    "wednesday",
+    "thursday",
+    "friday",
+    "saturday",
+    "sunday",
+];
+
+/// Parses a date written in English prose.
+///
+/// The function accepts, case-insensitively, the month either before or after the day, full or
+/// abbreviated month names, days with or without an ordinal suffix, commas, an `of` between the day and
+/// the month, and a leading weekday, which is ignored. For example:
+/// - `May 31st, 2024`, `May 31, 2024`, `Friday, May 31st 2024`
+/// - `31st May 2024`, `31 May, 2024`, `the 31st of May 2024`
+///
+/// ## Returns
+/// - `Ok(Date)`: The parsed date.
+/// - `Err(DateTimeError)`: If the input is not a textual date, or the day does not exist in the month.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::parse_textual_date;
+/// use time::macros::date;
+///
+/// assert_eq!(parse_textual_date("May 31st, 2024").unwrap(), date!(2024 - 05 - 31));
+/// assert_eq!(parse_textual_date("the 1st of June 2024").unwrap(), date!(2024 - 06 - 01));
+/// ```
+pub fn parse_textual_date(input: &str) -> Result<Date, DateTimeError> {
+    let error = || {
+        DateTimeError::InvalidDateFormat(
+            input.to_string(),
+            "expected a date such as May 31st, 2024 or 31 May 2024".to_string(),
+        )
+    };
+    let lowercase = input.to_ascii_lowercase().replace(',', " ");
+    let mut words: Vec<&str> = lowercase
+        .split_whitespace()
+        .filter(|word| !matches!(*word, "the" | "of"))
+        .collect();
+    if words.first().is_some_and(|word| weekday_from_name(word)) {
+        words.remove(0);
+    }
+    let (month, day, year) = match words.as_slice() {
+        [first, second, year] => match (month_from_name(first), month_from_name(second)) {
+            (Some(month), None) => (month, *second, *year),
+            (None, Some(month)) => (month, *first, *year),
+            _ => return Err(error()),
+        },
+        _ => return Err(error()),
+    };
+    let day = ["st", "nd", "rd", "th"]
+        .iter()
+        .find_map(|suffix| day.strip_suffix(suffix))
+        .unwrap_or(day)
+        .parse::<u8>()
+        .map_err(|_| error())?;
+    if !year.bytes().all(|byte| byte.is_ascii_digit()) {
+        return Err(error());
+    }
+    let year = year.parse::<i32>().map_err(|_| error())?;
+    Date::from_calendar_date(year, month, day)
+        .map_err(|err| DateTimeError::InvalidComponent(err.name(), err.to_string()))
+}
+
+/// Parses a date range string into a `DateTimeRange`.
+///
+/// The string is made of two endpoints separated by `..`, `/`, ` to ` or ` - ` (with spaces). Each
+/// endpoint is a date (`2024-01-01`, `31/01/2024`), a month (`2024-01`, `Jan 2024`, `January 2024`), a
+/// quarter (`2024-Q1`), a half-year (`2024-H1`) or a year (`2024`). The range starts at the first instant
+/// of the start endpoint and ends at the last instant of the end endpoint, in UTC.
+///
+/// Slash-separated dates are read both as day/month/year and month/day/year, consistently across the
+/// two endpoints: the range is ambiguous unless only one reading yields a valid range (e.g.
+/// `13/01/2024 - 01/02/2024`).
+///
+/// ## Arguments
+/// - `input: &str`: The range string (e.g. `"2024-01-01..2024-03-31"`, `"Jan 2024 to Mar 2024"`).
+///
+/// ## Returns
+/// - `Ok(DateTimeRange)`: The parsed range.
+/// - `Err(DateTimeError)`: If the string cannot be split into two valid endpoints, if it can be read in
+///   more than one way yielding different ranges, or if the start is after the end.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::{parse_range, DateTimeError};
+/// use time::macros::datetime;
+///
+/// let range = parse_range("Jan 2024 to Mar 2024").unwrap();
+/// assert_eq!(range.start(), datetime!(2024-01-01 0:00 UTC));
+/// assert_eq!(range.end(), datetime!(2024-03-31 23:59:59.999999999 UTC));
+/// assert_eq!(parse_range("2024-01-01..2024-03-31").unwrap(), range);
+///
+/// assert!(matches!(
+///     parse_range("01/02/2024 - 03/04/2024").unwrap_err(),
+///     DateTimeError::AmbiguousRange(_, _)
+/// ));
+/// ```
+pub fn parse_range(input: &str) -> Result<DateTimeRange, DateTimeError> {
+    parse_range_with(input, BoundKind::Inclusive)
+}
+
+/// Parses a date range written by people, reading its end endpoint as a bound of the given kind.
+///
+/// The string is read as by `parse_range`. With `BoundKind::Exclusive`, the end endpoint is the first
+/// period excluded from the range, as in half-open `[start, end)` notations: `2024-01-01..2024-04-01`
+/// and `Jan 2024 to Apr 2024` both cover the first quarter.
+///
+/// ## Arguments
+/// - `input: &str`: The range string.
+/// - `end_bound: BoundKind`: The kind of the end endpoint.
+///
+/// ## Returns
+/// - `Ok(DateTimeRange)`: The parsed range, which includes both its bounds.
+/// - `Err(DateTimeError)`: If the string cannot be split into two valid endpoints, if it can be read in
+///   more than one way yielding different ranges, or if the range is empty.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::{parse_range, parse_range_with, BoundKind};
+///
+/// let q1 = parse_range("2024-01-01..2024-03-31").unwrap();
+/// assert_eq!(parse_range_with("2024-01-01..2024-04-01", BoundKind::Exclusive).unwrap(), q1);
+/// assert_eq!(parse_range_with("Jan 2024 to Apr 2024", BoundKind::Exclusive).unwrap(), q1);
+/// assert!(parse_range_with("2024-01-01..2024-01-01", BoundKind::Exclusive).is_err());
+/// ```
+pub fn parse_range_with(input: &str, end_bound: BoundKind) -> Result<DateTimeRange, DateTimeError> {
+    const SEPARATORS: [&str; 4] = ["..", "/", " to ", " - "];
+    let lowercase = input.trim().to_ascii_lowercase();
+    let mut candidates: Vec<(Date, Date)> = Vec::new();
+    for separator in SEPARATORS {
+        for (index, _) in lowercase.match_indices(separator) {
+            let starts = endpoints(&lowercase[..index]);
+            let ends = endpoints(&lowercase[index + separator.len()..]);
+            for (start_order, first, _) in &starts {
+                for (end_order, end_first, end_last) in &ends {
+                    let last = match end_bound {
+                        BoundKind::Inclusive => *end_last,
+                        BoundKind::Exclusive => match end_first.previous_day() {
+                            Some(last) => last,
+                            None => continue,
+                        },
+                    };
+                    // Both slash dates are read in the same order.
+                    let consistent =
+                        start_order.is_none() || end_order.is_none() || start_order == end_order;
+                    if consistent && !candidates.contains(&(*first, last)) {
+                        candidates.push((*first, last));
+                    }
+                }
+            }
+        }
+    }
+    let Some(&(first, last)) = candidates.first() else {
+        return Err(DateTimeError::InvalidDateFormat(
+            input.to_string(),
+            "expected two dates, months, quarters or years separated by '..', '/', 'to' or '-'"
+                .to_string(),
+        ));
+    };
+    let valid: Vec<_> = candidates
+        .iter()
+        .filter(|(first, last)| first <= last)
+        .collect();
+    match valid.as_slice() {
+        [] => Err(DateTimeError::InvalidRange(
+            first.to_string(),
+            last.to_string(),
+        )),
+        [(first, last)] => Ok(days_range(*first, *last)),
+        _ => Err(DateTimeError::AmbiguousRange(
+            input.to_string(),
+            valid
+                .iter()
+                .map(|(first, last)| format!("{first}..{last}"))
+                .collect::<Vec<_>>()
+                .join(" or "),
+        )),
+    }
+}
+
+/// Represents the order of the day and the month in a slash-separated date.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SlashOrder {
+    DayFirst,
+    MonthFirst,
+}
+
+/// Parses a range endpoint into the first and last dates of each period it may designate, along with
+/// the reading of slash-separated dates leading to it.
+fn endpoints(input: &str) -> Vec<(Option<SlashOrder>, Date, Date)> {
+    let input = input.trim();
+    if let Ok(date) = Date::parse(input, format_description!("[year]-[month]-[day]")) {
+        return vec![(None, date, date)];
+    }
+    if let Ok(quarter) = input.parse::<Quarter>() {
+        return vec![(None, quarter.first_day(), quarter.last_day())];
+    }
+    if let Ok(half) = input.parse::<HalfYear>() {
+        return vec![(None, half.first_day(), half.last_day())];
+    }
+    let mut dates = Vec::new();
+    for (order, format) in [
+        (
+            SlashOrder::DayFirst,
+            format_description!("[day padding:none]/[month padding:none]/[year]"),
+        ),
+        (
+            SlashOrder::MonthFirst,
+            format_description!("[month padding:none]/[day padding:none]/[year]"),
+        ),
+    ] {
+        if let Ok(date) = Date::parse(input, format) {
+            dates.push((Some(order), date, date));
+        }
+    }
+    if !dates.is_empty() {
+        return dates;
+    }
+    month_or_year(input)
+        .map(|(first, last)| (None, first, last))
+        .into_iter()
+        .collect()
+}
+
+/// Parses a year (`2024`) or a month (`2024-01`, `Jan 2024`, `January 2024`) endpoint.
+fn month_or_year(input: &str) -> Option<(Date, Date)> {
+    let is_year = |year: &str| year.len() == 4 && year.bytes().all(|byte| byte.is_ascii_digit());
+    if is_year(input) {
+        let year = input.parse().ok()?;
+        return Some((
+            Date::from_calendar_date(year, Month::January, 1).ok()?,
+            Date::from_calendar_date(year, Month::December, 31).ok()?,
+        ));
+    }
+    let (year, month) = match input.split_once(['-', ' '])? {
+        (year, month) if is_year(year) => (year, month.parse::<u8>().ok()?.try_into().ok()?),
+        (month, year) => (year.trim(), month_from_name(month)?),
+    };
+    if !is_year(year) {
+        return None;
+    }
+    let year = year.parse().ok()?;
+    Some((
+        Date::from_calendar_date(year, month, 1).ok()?,
+        Date::from_calendar_date(year, month, month.length(year)).ok()?,
+    ))
+}
+
+/// Parses a lowercase English month name, full or abbreviated to three letters.
+pub(crate) fn month_from_name(name: &str) -> Option<Month> {
+    let index = MONTH_NAMES
+        .iter()
+        .position(|full| name == *full || (name.len() == 3 && full.starts_with(name)))?;
+    Month::try_from(index as u8 + 1).ok()
+}
+
+/// Checks whether the word is a lowercase English weekday name, full or abbreviated to three letters.
+fn weekday_from_name(name: &str) -> bool {
+    WEEKDAY_NAMES
+        .iter()
+        .any(|full| name == *full || (name.len() == 3 && full.starts_with(name)))
+}
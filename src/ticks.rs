@@ -0,0 +1,167 @@
+//! Ticks Module
+//!
+//! This module contains a set of methods to generate synthetic, strictly increasing sequences of
+//! datetimes (time axes for load tests and simulations), evenly spaced, jittered or restricted to
+//! business days.
+use time::{Duration, OffsetDateTime};
+
+use crate::DateTimeError;
+#[cfg(feature = "business")]
+use crate::{is_business_day, HolidayCalendar, Weekend};
+#[cfg(feature = "rand")]
+use crate::{stats::duration_from_nanos, Rng};
+
+/// Generates evenly spaced datetimes.
+///
+/// ## Arguments
+/// - `start: OffsetDateTime`: The first datetime.
+/// - `step: Duration`: The positive duration between consecutive datetimes.
+/// - `count: usize`: The number of datetimes.
+///
+/// ## Returns
+/// - `Ok(Vec<OffsetDateTime>)`: The datetimes `start`, `start + step`, `start + 2 * step`, ...
+/// - `Err(DateTimeError)`: If the step is not positive, or the sequence is out of the supported date
+///   range.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::ticks;
+/// use time::{macros::datetime, Duration};
+///
+/// let axis = ticks(datetime!(2024-05-31 9:00 UTC), Duration::minutes(15), 3).unwrap();
+/// assert_eq!(axis[2], datetime!(2024-05-31 9:30 UTC));
+/// ```
+pub fn ticks(
+    start: OffsetDateTime,
+    step: Duration,
+    count: usize,
+) -> Result<Vec<OffsetDateTime>, DateTimeError> {
+    validate_step(step)?;
+    std::iter::successors(Some(Ok(start)), |tick| {
+        tick.as_ref().ok().map(|tick| next_tick(*tick, step))
+    })
+    .take(count)
+    .collect()
+}
+
+/// Generates evenly spaced datetimes, each delayed by a random jitter.
+///
+/// Each datetime is `start + i * step` delayed by a duration drawn uniformly from zero (included) to the
+/// jitter (excluded). Since the jitter is shorter than the step, the sequence stays strictly increasing.
+///
+/// ## Arguments
+/// - `rng: &mut Rng`: The random number generator.
+/// - `start: OffsetDateTime`: The first undelayed datetime.
+/// - `step: Duration`: The positive duration between consecutive undelayed datetimes.
+/// - `jitter: Duration`: The maximum delay, from zero to the step (excluded).
+/// - `count: usize`: The number of datetimes.
+///
+/// ## Returns
+/// - `Ok(Vec<OffsetDateTime>)`: The jittered datetimes.
+/// - `Err(DateTimeError)`: If the step is not positive, the jitter is negative or not shorter than the
+///   step, or the sequence is out of the supported date range.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::{jittered_ticks, Rng};
+/// use time::{macros::datetime, Duration};
+///
+/// let start = datetime!(2024-05-31 9:00 UTC);
+/// let axis = jittered_ticks(&mut Rng::new(1), start, Duration::MINUTE, Duration::SECOND * 30, 60).unwrap();
+/// assert!(axis.windows(2).all(|pair| pair[0] < pair[1]));
+/// assert!(axis[59] < start + Duration::seconds(59 * 60 + 30));
+/// ```
+#[cfg(feature = "rand")]
+pub fn jittered_ticks(
+    rng: &mut Rng,
+    start: OffsetDateTime,
+    step: Duration,
+    jitter: Duration,
+    count: usize,
+) -> Result<Vec<OffsetDateTime>, DateTimeError> {
+    validate_step(step)?;
+    if jitter.is_negative() || jitter >= step {
+        return Err(DateTimeError::InvalidComponent(
+            "jitter",
+            format!("{jitter} is not between zero and the step ({step})"),
+        ));
+    }
+    let bound = u64::try_from(jitter.whole_nanoseconds())
+        .map_err(|_| DateTimeError::InvalidComponent("jitter", format!("{jitter} is too long")))?;
+    ticks(start, step, count)?
+        .into_iter()
+        .map(|tick| {
+            let delay = match bound {
+                0 => Duration::ZERO,
+                bound => duration_from_nanos(rng.below(bound) as i128),
+            };
+            tick.checked_add(delay)
+                .ok_or_else(|| DateTimeError::OutOfRange(format!("{tick} + {delay}")))
+        })
+        .collect()
+}
+
+/// Generates evenly spaced datetimes, skipping those falling on days which are not business days.
+///
+/// Business days are evaluated on the date of each datetime in its own offset.
+///
+/// ## Arguments
+/// - `start: OffsetDateTime`: The first candidate datetime.
+/// - `step: Duration`: The positive duration between consecutive candidate datetimes.
+/// - `count: usize`: The number of datetimes.
+/// - `weekend: &Weekend`: The weekdays which are not business days.
+/// - `calendar: &HolidayCalendar`: The holidays which are not business days.
+///
+/// ## Returns
+/// - `Ok(Vec<OffsetDateTime>)`: The datetimes falling on business days.
+/// - `Err(DateTimeError)`: If the step is not positive, or the supported date range ends before `count`
+///   datetimes are found (e.g. a weekly step from a Saturday).
+///
+/// ## Example
+/// ```rust
+/// use date_utils::{business_day_ticks, HolidayCalendar, Weekend};
+/// use time::{macros::datetime, Duration};
+///
+/// let start = datetime!(2024-05-31 9:00 UTC); // a Friday
+/// let axis = business_day_ticks(start, Duration::DAY, 2, &Weekend::default(), &HolidayCalendar::new()).unwrap();
+/// assert_eq!(axis, [start, datetime!(2024-06-03 9:00 UTC)]);
+/// ```
+#[cfg(feature = "business")]
+pub fn business_day_ticks(
+    start: OffsetDateTime,
+    step: Duration,
+    count: usize,
+    weekend: &Weekend,
+    calendar: &HolidayCalendar,
+) -> Result<Vec<OffsetDateTime>, DateTimeError> {
+    validate_step(step)?;
+    let mut axis = Vec::with_capacity(count);
+    let mut tick = start;
+    while axis.len() < count {
+        if is_business_day(tick.date(), weekend, calendar) {
+            axis.push(tick);
+            if axis.len() == count {
+                break;
+            }
+        }
+        tick = next_tick(tick, step)?;
+    }
+    Ok(axis)
+}
+
+/// Validates that the step between ticks is positive.
+fn validate_step(step: Duration) -> Result<(), DateTimeError> {
+    if !step.is_positive() {
+        return Err(DateTimeError::InvalidComponent(
+            "step",
+            format!("{step} is not positive"),
+        ));
+    }
+    Ok(())
+}
+
+/// Returns the tick following the given one.
+fn next_tick(tick: OffsetDateTime, step: Duration) -> Result<OffsetDateTime, DateTimeError> {
+    tick.checked_add(step)
+        .ok_or_else(|| DateTimeError::OutOfRange(format!("{tick} + {step}")))
+}
@@ -0,0 +1,145 @@
+//! Time Zone Module
+//!
+//! This module contains a set of methods to move datetimes between offsets, either preserving the instant
+//! or preserving the wall-clock time, and to read the local calendar day of an instant.
+use time::{Date, OffsetDateTime, UtcOffset, Weekday};
+
+use crate::DateTimeError;
+#[cfg(feature = "business")]
+use crate::{is_business_day, HolidayCalendar, Weekend};
+
+/// Moves the datetime to a new offset, keeping the wall-clock time.
+///
+/// The function keeps date and time unchanged and replaces the offset, so the resulting datetime
+/// represents a different instant (e.g. a meeting at 09:00 in London moved to 09:00 in New York).
+///
+/// ## Arguments
+/// - `datetime: OffsetDateTime`: The datetime to move.
+/// - `offset: UtcOffset`: The new offset.
+///
+/// ## Returns
+/// - `OffsetDateTime`: The datetime with the same wall-clock time in the new offset.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::with_same_wall_clock;
+/// use time::macros::{datetime, offset};
+///
+/// let meeting = with_same_wall_clock(datetime!(2024-05-31 9:00 +1), offset!(-4));
+/// assert_eq!(meeting, datetime!(2024-05-31 9:00 -4));
+/// ```
+pub fn with_same_wall_clock(datetime: OffsetDateTime, offset: UtcOffset) -> OffsetDateTime {
+    datetime.replace_offset(offset)
+}
+
+/// Moves the datetime to a new offset, keeping the instant.
+///
+/// The function converts date and time to the new offset, so the resulting datetime represents the same
+/// instant (e.g. a call at 09:00 in London happens at 04:00 in New York).
+///
+/// ## Arguments
+/// - `datetime: OffsetDateTime`: The datetime to move.
+/// - `offset: UtcOffset`: The new offset.
+///
+/// ## Returns
+/// - `Ok(OffsetDateTime)`: The same instant expressed in the new offset.
+/// - `Err(DateTimeError::OutOfRange)`: If the local date is outside the supported range of years.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::with_same_instant;
+/// use time::macros::{datetime, offset};
+///
+/// let call = with_same_instant(datetime!(2024-05-31 9:00 +1), offset!(-4)).unwrap();
+/// assert_eq!(call, datetime!(2024-05-31 4:00 -4));
+/// ```
+pub fn with_same_instant(
+    datetime: OffsetDateTime,
+    offset: UtcOffset,
+) -> Result<OffsetDateTime, DateTimeError> {
+    datetime
+        .checked_to_offset(offset)
+        .ok_or_else(|| DateTimeError::OutOfRange(format!("{datetime} in {offset}")))
+}
+
+/// Returns the calendar date of the instant in the given offset.
+///
+/// The function answers "which day was it in Tokyo when this happened", whatever the offset the
+/// datetime is expressed in.
+///
+/// ## Arguments
+/// - `datetime: OffsetDateTime`: The instant.
+/// - `tz: UtcOffset`: The offset in which the calendar date is read.
+///
+/// ## Returns
+/// - `Ok(Date)`: The local calendar date of the instant.
+/// - `Err(DateTimeError::OutOfRange)`: If the local date is outside the supported range of years.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::local_date_in_tz;
+/// use time::macros::{date, datetime, offset};
+///
+/// let date = local_date_in_tz(datetime!(2024-05-31 20:00 UTC), offset!(+9)).unwrap();
+/// assert_eq!(date, date!(2024 - 06 - 01));
+/// ```
+pub fn local_date_in_tz(datetime: OffsetDateTime, tz: UtcOffset) -> Result<Date, DateTimeError> {
+    with_same_instant(datetime, tz).map(|local| local.date())
+}
+
+/// Returns the weekday of the instant in the given offset.
+///
+/// ## Arguments
+/// - `datetime: OffsetDateTime`: The instant.
+/// - `tz: UtcOffset`: The offset in which the weekday is read.
+///
+/// ## Returns
+/// - `Ok(Weekday)`: The local weekday of the instant.
+/// - `Err(DateTimeError::OutOfRange)`: If the local date is outside the supported range of years.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::weekday_in_tz;
+/// use time::{macros::{datetime, offset}, Weekday};
+///
+/// let weekday = weekday_in_tz(datetime!(2024-05-31 20:00 UTC), offset!(+9)).unwrap();
+/// assert_eq!(weekday, Weekday::Saturday);
+/// ```
+pub fn weekday_in_tz(datetime: OffsetDateTime, tz: UtcOffset) -> Result<Weekday, DateTimeError> {
+    local_date_in_tz(datetime, tz).map(|date| date.weekday())
+}
+
+/// Checks whether the instant falls on a business day in the given offset.
+///
+/// A Friday evening trade in New York is already on Saturday in Tokyo, so the check is made on the
+/// local calendar date of the instant rather than on the date of its own offset.
+///
+/// ## Arguments
+/// - `datetime: OffsetDateTime`: The instant.
+/// - `tz: UtcOffset`: The offset in which the calendar date is read.
+/// - `weekend: &Weekend`: The weekdays which are not business days.
+/// - `calendar: &HolidayCalendar`: The holidays which are not business days.
+///
+/// ## Returns
+/// - `Ok(bool)`: Whether the local calendar date of the instant is a business day.
+/// - `Err(DateTimeError::OutOfRange)`: If the local date is outside the supported range of years.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::{is_business_day_in_tz, HolidayCalendar, Weekend};
+/// use time::macros::{datetime, offset};
+///
+/// let trade = datetime!(2024-05-31 20:00 -4);
+/// let calendar = HolidayCalendar::new();
+/// assert!(is_business_day_in_tz(trade, offset!(-4), &Weekend::default(), &calendar).unwrap());
+/// assert!(!is_business_day_in_tz(trade, offset!(+9), &Weekend::default(), &calendar).unwrap());
+/// ```
+#[cfg(feature = "business")]
+pub fn is_business_day_in_tz(
+    datetime: OffsetDateTime,
+    tz: UtcOffset,
+    weekend: &Weekend,
+    calendar: &HolidayCalendar,
+) -> Result<bool, DateTimeError> {
+    local_date_in_tz(datetime, tz).map(|date| is_business_day(date, weekend, calendar))
+}
@@ -0,0 +1,73 @@
+//! Vintage Module
+//!
+//! This module contains the `VintagePoint` type, which pairs the reference period of an observation with
+//! the instant it was published, as found in real-time (vintage) datasets.
+use std::cmp::Ordering;
+
+use time::{
+    format_description::BorrowedFormatItem, macros::format_description, Date, OffsetDateTime,
+};
+
+use crate::{parse_datetime, DateTimeError, OffsetHandling, Period};
+
+const DATE_FORMAT: &[BorrowedFormatItem<'_>] = format_description!("[year]-[month]-[day]");
+
+/// Represents an observation of a reference period as published at a given instant (its vintage).
+///
+/// Vintage points are ordered by vintage, then by reference period, so sorting a dataset yields the
+/// successive releases in publication order.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::{Period, VintagePoint};
+/// use time::macros::datetime;
+///
+/// let first = VintagePoint::parse("2024-Q1", "2024-04-30").unwrap();
+/// let revision = VintagePoint::parse("2024-Q1", "2024-05-30T10:00:00+02:00").unwrap();
+/// assert_eq!(first.reference_period, Period::Quarter(2024, 1));
+/// assert_eq!(revision.vintage, datetime!(2024-05-30 8:00 UTC));
+/// assert!(first < revision);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct VintagePoint {
+    pub reference_period: Period,
+    pub vintage: OffsetDateTime,
+}
+
+impl VintagePoint {
+    /// Parses a vintage point from the values of a `TIME_PERIOD` column and a `PUBLICATION` column.
+    ///
+    /// ## Arguments
+    /// - `time_period: &str`: The reference period, in any notation accepted by `Period`.
+    /// - `publication: &str`: The publication instant, either a `YYYY-MM-DD` date (read as midnight UTC)
+    ///   or a datetime accepted by `parse_datetime`; its offset is preserved.
+    ///
+    /// ## Returns
+    /// - `Ok(VintagePoint)`: The vintage point.
+    /// - `Err(DateTimeError)`: If the period or the publication instant is invalid.
+    pub fn parse(time_period: &str, publication: &str) -> Result<Self, DateTimeError> {
+        let reference_period = time_period.parse()?;
+        let vintage = match Date::parse(publication.trim(), DATE_FORMAT) {
+            Ok(date) => date.midnight().assume_utc(),
+            Err(_) => parse_datetime(publication, OffsetHandling::Preserve)?,
+        };
+        Ok(Self {
+            reference_period,
+            vintage,
+        })
+    }
+}
+
+impl PartialOrd for VintagePoint {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for VintagePoint {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.vintage
+            .cmp(&other.vintage)
+            .then_with(|| self.reference_period.cmp(&other.reference_period))
+    }
+}
@@ -0,0 +1,160 @@
+//! Week Label Module
+//!
+//! This module contains a set of methods to generate and parse week labels such as `w/c 2024-05-27`
+//! (week commencing) and `w/e 2024-06-02` (week ending), as used by retail and operational reporting.
+use time::{
+    format_description::BorrowedFormatItem, macros::format_description, Date, Duration, Weekday,
+};
+
+use crate::DateTimeError;
+
+const DATE_FORMAT: &[BorrowedFormatItem<'_>] = format_description!("[year]-[month]-[day]");
+
+/// Represents the weekday on which weeks start.
+///
+/// - `WeekRule::MONDAY`: The default rule, weeks from Monday to Sunday (ISO weeks)
+/// - `WeekRule::SUNDAY`: Weeks from Sunday to Saturday (e.g. US retail)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct WeekRule {
+    first_day: Weekday,
+}
+
+impl WeekRule {
+    pub const MONDAY: WeekRule = WeekRule {
+        first_day: Weekday::Monday,
+    };
+    pub const SUNDAY: WeekRule = WeekRule {
+        first_day: Weekday::Sunday,
+    };
+
+    /// Creates a rule whose weeks start on the given weekday.
+    pub fn starting_on(first_day: Weekday) -> Self {
+        Self { first_day }
+    }
+
+    /// Returns the first weekday of the weeks.
+    pub fn first_day(&self) -> Weekday {
+        self.first_day
+    }
+
+    /// Returns the last weekday of the weeks.
+    pub fn last_day(&self) -> Weekday {
+        self.first_day.previous()
+    }
+
+    /// Returns the first and last dates of the week containing the given date.
+    ///
+    /// ## Returns
+    /// - `Ok((Date, Date))`: The first and last dates of the week.
+    /// - `Err(DateTimeError)`: If the week extends beyond the supported date range.
+    pub fn week_of(&self, date: Date) -> Result<(Date, Date), DateTimeError> {
+        let days_in = (7 + date.weekday().number_days_from_monday()
+            - self.first_day.number_days_from_monday())
+            % 7;
+        let out_of_range = || DateTimeError::OutOfRange(format!("week of {date}"));
+        let first = date
+            .checked_sub(Duration::days(days_in as i64))
+            .ok_or_else(out_of_range)?;
+        let last = first
+            .checked_add(Duration::days(6))
+            .ok_or_else(out_of_range)?;
+        Ok((first, last))
+    }
+}
+
+impl Default for WeekRule {
+    fn default() -> Self {
+        Self::MONDAY
+    }
+}
+
+/// Represents the date used to label a week.
+///
+/// - `LabelStyle::Commencing`: It indicates the first date of the week (`w/c 2024-05-27`)
+/// - `LabelStyle::Ending`: It indicates the last date of the week (`w/e 2024-06-02`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LabelStyle {
+    Commencing,
+    Ending,
+}
+
+/// Generates the label of the week containing the given date.
+///
+/// ## Arguments
+/// - `date: Date`: A date of the week.
+/// - `rule: WeekRule`: The weekday on which weeks start.
+/// - `style: LabelStyle`: Whether the week is labelled by its first or last date.
+///
+/// ## Returns
+/// - `Ok(String)`: The label, such as `w/c 2024-05-27` or `w/e 2024-06-02`.
+/// - `Err(DateTimeError)`: If the week extends beyond the supported date range.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::{week_label, LabelStyle, WeekRule};
+/// use time::macros::date;
+///
+/// let date = date!(2024 - 05 - 29);
+/// assert_eq!(week_label(date, WeekRule::MONDAY, LabelStyle::Commencing).unwrap(), "w/c 2024-05-27");
+/// assert_eq!(week_label(date, WeekRule::SUNDAY, LabelStyle::Ending).unwrap(), "w/e 2024-06-01");
+/// ```
+pub fn week_label(date: Date, rule: WeekRule, style: LabelStyle) -> Result<String, DateTimeError> {
+    let (first, last) = rule.week_of(date)?;
+    Ok(match style {
+        LabelStyle::Commencing => format!("w/c {first}"),
+        LabelStyle::Ending => format!("w/e {last}"),
+    })
+}
+
+/// Parses a week label into the first and last dates of the week.
+///
+/// The function accepts, case-insensitively, the `w/c` and `w/e` prefixes (also written `wc`, `we`,
+/// `w.c.`, `w.e.`, `week commencing` and `week ending`) followed by a `YYYY-MM-DD` date, which must fall
+/// on the first weekday (commencing) or the last weekday (ending) of the rule.
+///
+/// ## Arguments
+/// - `label: &str`: The week label.
+/// - `rule: WeekRule`: The weekday on which weeks start.
+///
+/// ## Returns
+/// - `Ok((Date, Date))`: The first and last dates of the week.
+/// - `Err(DateTimeError)`: If the label is not recognized, the date does not match the rule, or the week
+///   extends beyond the supported date range.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::{parse_week_label, WeekRule};
+/// use time::macros::date;
+///
+/// assert_eq!(
+///     parse_week_label("W/E 2024-06-02", WeekRule::MONDAY).unwrap(),
+///     (date!(2024 - 05 - 27), date!(2024 - 06 - 02))
+/// );
+/// ```
+pub fn parse_week_label(label: &str, rule: WeekRule) -> Result<(Date, Date), DateTimeError> {
+    let error = || {
+        DateTimeError::InvalidDateFormat(
+            label.to_string(),
+            "expected a week label such as w/c 2024-05-27 or w/e 2024-06-02".to_string(),
+        )
+    };
+    let lower = label.trim().to_ascii_lowercase();
+    let (prefix, date) = lower.rsplit_once(' ').ok_or_else(error)?;
+    let style = match prefix.trim_end() {
+        "w/c" | "wc" | "w.c." | "week commencing" => LabelStyle::Commencing,
+        "w/e" | "we" | "w.e." | "week ending" => LabelStyle::Ending,
+        _ => return Err(error()),
+    };
+    let date = Date::parse(date, DATE_FORMAT).map_err(|_| error())?;
+    let expected = match style {
+        LabelStyle::Commencing => rule.first_day(),
+        LabelStyle::Ending => rule.last_day(),
+    };
+    if date.weekday() != expected {
+        return Err(DateTimeError::InvalidComponent(
+            "weekday",
+            format!("{date} is a {}, expected a {expected}", date.weekday()),
+        ));
+    }
+    rule.week_of(date)
+}
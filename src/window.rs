@@ -0,0 +1,108 @@
+//! Window Module
+//!
+//! This module contains a set of methods to align instants on rate-limit and quota windows, either of a
+//! fixed duration or following the calendar.
+use time::{Duration, OffsetDateTime, Time, UtcOffset};
+
+use crate::{fiscal::first_of_month, stats::duration_from_nanos, Clock, DateTimeError};
+
+/// Represents how windows are delimited.
+///
+/// - `WindowSpec::Fixed`: It indicates consecutive windows of the given duration, aligned on the Unix
+///   epoch
+/// - `WindowSpec::CalendarDay`: It indicates calendar days in the given offset
+/// - `WindowSpec::CalendarMonth`: It indicates calendar months in the given offset
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WindowSpec {
+    Fixed(Duration),
+    CalendarDay(UtcOffset),
+    CalendarMonth(UtcOffset),
+}
+
+/// Represents the window containing an instant.
+///
+/// The window is half-open: it contains `start` but not `end`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Window {
+    pub start: OffsetDateTime,
+    pub end: OffsetDateTime,
+    pub remaining: Duration,
+}
+
+/// Computes the window containing the given instant.
+///
+/// Fixed windows are expressed in the offset of the instant, calendar windows in their own offset.
+///
+/// ## Arguments
+/// - `now: OffsetDateTime`: The instant.
+/// - `spec: WindowSpec`: How windows are delimited.
+///
+/// ## Returns
+/// - `Ok(Window)`: The bounds of the window and the time remaining until its end.
+/// - `Err(DateTimeError)`: If the fixed duration is not positive, or the window extends beyond the
+///   supported date range.
+///
+/// ## Example
+/// ```rust
+/// use date_utils::{current_window, WindowSpec};
+/// use time::{macros::{datetime, offset}, Duration};
+///
+/// let window = current_window(datetime!(2024-05-31 12:34 UTC), WindowSpec::Fixed(Duration::minutes(15))).unwrap();
+/// assert_eq!(window.start, datetime!(2024-05-31 12:30 UTC));
+/// assert_eq!(window.remaining, Duration::minutes(11));
+///
+/// let window = current_window(datetime!(2024-05-31 23:00 UTC), WindowSpec::CalendarDay(offset!(+9))).unwrap();
+/// assert_eq!(window.start, datetime!(2024-06-01 0:00 +9));
+/// ```
+pub fn current_window(now: OffsetDateTime, spec: WindowSpec) -> Result<Window, DateTimeError> {
+    let out_of_range = || DateTimeError::OutOfRange(format!("{spec:?} window of {now}"));
+    let (start, end) = match spec {
+        WindowSpec::Fixed(duration) => {
+            if !duration.is_positive() {
+                return Err(DateTimeError::InvalidComponent(
+                    "duration",
+                    format!("{duration} is not positive"),
+                ));
+            }
+            let epoch = OffsetDateTime::UNIX_EPOCH.to_offset(now.offset());
+            let length = duration.whole_nanoseconds();
+            let elapsed = (now - epoch).whole_nanoseconds();
+            let start = epoch
+                .checked_add(duration_from_nanos(elapsed - elapsed.rem_euclid(length)))
+                .ok_or_else(out_of_range)?;
+            (start, start.checked_add(duration).ok_or_else(out_of_range)?)
+        }
+        WindowSpec::CalendarDay(offset) => {
+            let start = now
+                .checked_to_offset(offset)
+                .ok_or_else(out_of_range)?
+                .replace_time(Time::MIDNIGHT);
+            (
+                start,
+                start.checked_add(Duration::DAY).ok_or_else(out_of_range)?,
+            )
+        }
+        WindowSpec::CalendarMonth(offset) => {
+            let local = now.checked_to_offset(offset).ok_or_else(out_of_range)?;
+            let months = local.year() * 12 + local.month() as i32 - 1;
+            let start = first_of_month(months)?.midnight().assume_offset(offset);
+            let end = first_of_month(months + 1)
+                .map_err(|_| out_of_range())?
+                .midnight()
+                .assume_offset(offset);
+            (start, end)
+        }
+    };
+    Ok(Window {
+        start,
+        end,
+        remaining: end - now,
+    })
+}
+
+/// Computes the window containing the current time of the clock.
+///
+/// See `current_window` for details.
+pub fn current_window_at(spec: WindowSpec, clock: &impl Clock) -> Result<Window, DateTimeError> {
+    current_window(clock.now(), spec)
+}
@@ -0,0 +1,113 @@
+use date_utils::{backoff_schedule, Backoff, DateTimeError};
+use time::{macros::datetime, Duration};
+
+#[test]
+fn test_backoff_schedule() {
+    let start = datetime!(2024-05-31 12:00 UTC);
+    let fixed = backoff_schedule(
+        start,
+        Backoff::Fixed(Duration::minutes(10)),
+        Duration::minutes(30),
+    )
+    .unwrap();
+    assert_eq!(
+        fixed,
+        [
+            datetime!(2024-05-31 12:10 UTC),
+            datetime!(2024-05-31 12:20 UTC),
+            datetime!(2024-05-31 12:30 UTC),
+        ]
+    );
+    let policy = Backoff::Exponential {
+        base: Duration::seconds(1),
+        cap: Duration::hours(1),
+    };
+    let retries = backoff_schedule(start, policy, Duration::days(1)).unwrap();
+    let delays: Vec<Duration> = std::iter::once(start)
+        .chain(retries.iter().copied())
+        .collect::<Vec<_>>()
+        .windows(2)
+        .map(|pair| pair[1] - pair[0])
+        .collect();
+    assert_eq!(delays[..3], [1, 2, 4].map(Duration::seconds));
+    assert_eq!(delays[11], Duration::seconds(2048));
+    assert!(delays[12..]
+        .iter()
+        .all(|delay| *delay == Duration::hours(1)));
+    assert!(*retries.last().unwrap() - start <= Duration::days(1));
+    assert!(backoff_schedule(start, policy, Duration::ZERO)
+        .unwrap()
+        .is_empty());
+    // Retries stop at the end of the supported date range.
+    assert_eq!(
+        backoff_schedule(
+            datetime!(9999-12-31 23:00 UTC),
+            Backoff::Fixed(Duration::minutes(25)),
+            Duration::days(1)
+        )
+        .unwrap()
+        .len(),
+        2
+    );
+}
+
+#[test]
+fn test_backoff_schedule_invalid() {
+    let start = datetime!(2024-05-31 12:00 UTC);
+    for (policy, component) in [
+        (Backoff::Fixed(Duration::ZERO), "delay"),
+        (
+            Backoff::Exponential {
+                base: Duration::seconds(-1),
+                cap: Duration::hours(1),
+            },
+            "base",
+        ),
+        (
+            Backoff::Exponential {
+                base: Duration::seconds(1),
+                cap: Duration::ZERO,
+            },
+            "cap",
+        ),
+    ] {
+        assert!(matches!(
+            backoff_schedule(start, policy, Duration::hours(1)).unwrap_err(),
+            DateTimeError::InvalidComponent(name, _) if name == component
+        ));
+    }
+}
+
+#[cfg(feature = "business")]
+#[test]
+fn test_business_backoff_schedule() {
+    use date_utils::{business_backoff_schedule, BusinessHours, HolidayCalendar, Weekend};
+    use time::macros::{offset, time};
+
+    let hours = BusinessHours::new(time!(9:00), time!(17:00), offset!(+2)).unwrap();
+    let calendar: HolidayCalendar = [time::macros::date!(2025 - 05 - 12)].into_iter().collect();
+    let retries = business_backoff_schedule(
+        datetime!(2025-05-09 14:00 UTC),
+        Backoff::Exponential {
+            base: Duration::minutes(30),
+            cap: Duration::hours(2),
+        },
+        Duration::days(4),
+        &hours,
+        &Weekend::default(),
+        &calendar,
+    )
+    .unwrap();
+    assert_eq!(
+        retries[..3],
+        [
+            datetime!(2025-05-09 16:30 +2),
+            // Monday is a holiday.
+            datetime!(2025-05-13 9:00 +2),
+            datetime!(2025-05-13 11:00 +2),
+        ]
+    );
+    assert!(retries
+        .iter()
+        .all(|retry| (time!(9:00)..=time!(17:00)).contains(&retry.time())));
+}
@@ -0,0 +1,160 @@
+use date_utils::{bitemporal_as_of, BitemporalRange, DateTimeRange};
+use time::{macros::datetime, OffsetDateTime};
+
+fn range(start: OffsetDateTime, end: OffsetDateTime) -> DateTimeRange {
+    DateTimeRange::new(start, end).unwrap()
+}
+
+#[test]
+fn test_bitemporal_range() {
+    let record = BitemporalRange::new(
+        range(
+            datetime!(2024-01-01 0:00 UTC),
+            datetime!(2024-06-30 0:00 UTC),
+        ),
+        range(
+            datetime!(2024-02-01 0:00 UTC),
+            datetime!(2024-04-01 0:00 UTC),
+        ),
+    );
+    assert!(record.contains(
+        datetime!(2024-01-01 0:00 UTC),
+        datetime!(2024-02-01 0:00 UTC)
+    ));
+    assert!(record.contains(
+        datetime!(2024-06-30 2:00 +2),
+        datetime!(2024-04-01 0:00 UTC)
+    ));
+    assert!(!record.contains(
+        datetime!(2024-07-01 0:00 UTC),
+        datetime!(2024-03-01 0:00 UTC)
+    ));
+    assert!(!record.contains(
+        datetime!(2024-03-01 0:00 UTC),
+        datetime!(2024-04-02 0:00 UTC)
+    ));
+
+    let correction = BitemporalRange::new(
+        range(
+            datetime!(2024-03-01 0:00 UTC),
+            datetime!(2024-12-31 0:00 UTC),
+        ),
+        range(
+            datetime!(2024-04-01 0:00 UTC),
+            datetime!(2024-12-31 0:00 UTC),
+        ),
+    );
+    assert!(record.overlaps(&correction));
+    assert_eq!(
+        record.intersection(&correction),
+        Some(BitemporalRange::new(
+            range(
+                datetime!(2024-03-01 0:00 UTC),
+                datetime!(2024-06-30 0:00 UTC)
+            ),
+            range(
+                datetime!(2024-04-01 0:00 UTC),
+                datetime!(2024-04-01 0:00 UTC)
+            ),
+        ))
+    );
+
+    let later = BitemporalRange {
+        recorded: range(
+            datetime!(2024-04-02 0:00 UTC),
+            datetime!(2024-12-31 0:00 UTC),
+        ),
+        ..correction
+    };
+    assert!(!record.overlaps(&later));
+    assert_eq!(record.intersection(&later), None);
+}
+
+#[test]
+fn test_valid_as_of() {
+    let valid = range(
+        datetime!(2024-01-01 0:00 UTC),
+        datetime!(2024-06-30 0:00 UTC),
+    );
+    let record = BitemporalRange::new(
+        valid,
+        range(
+            datetime!(2024-02-01 0:00 UTC),
+            datetime!(2024-04-01 0:00 UTC),
+        ),
+    );
+    assert_eq!(record.valid_as_of(datetime!(2024-01-31 0:00 UTC)), None);
+    assert_eq!(
+        record.valid_as_of(datetime!(2024-02-01 0:00 UTC)),
+        Some(valid)
+    );
+    assert_eq!(record.valid_as_of(datetime!(2024-04-01 0:00:01 UTC)), None);
+}
+
+#[test]
+fn test_bitemporal_as_of() {
+    let records = [
+        (
+            BitemporalRange::new(
+                range(
+                    datetime!(2024-01-01 0:00 UTC),
+                    datetime!(2024-12-31 0:00 UTC),
+                ),
+                range(
+                    datetime!(2024-01-01 0:00 UTC),
+                    datetime!(2024-12-31 0:00 UTC),
+                ),
+            ),
+            1,
+        ),
+        (
+            BitemporalRange::new(
+                range(
+                    datetime!(2024-06-01 0:00 UTC),
+                    datetime!(2024-12-31 0:00 UTC),
+                ),
+                range(
+                    datetime!(2024-07-01 0:00 UTC),
+                    datetime!(2024-12-31 0:00 UTC),
+                ),
+            ),
+            2,
+        ),
+    ];
+    let as_of = |valid_at, recorded_at| bitemporal_as_of(&records, valid_at, recorded_at);
+    assert_eq!(
+        as_of(
+            datetime!(2024-06-15 0:00 UTC),
+            datetime!(2024-06-20 0:00 UTC)
+        ),
+        Some(&1)
+    );
+    assert_eq!(
+        as_of(
+            datetime!(2024-06-15 0:00 UTC),
+            datetime!(2024-07-20 0:00 UTC)
+        ),
+        Some(&2)
+    );
+    assert_eq!(
+        as_of(
+            datetime!(2024-03-15 0:00 UTC),
+            datetime!(2024-07-20 0:00 UTC)
+        ),
+        Some(&1)
+    );
+    assert_eq!(
+        as_of(
+            datetime!(2025-01-15 0:00 UTC),
+            datetime!(2024-07-20 0:00 UTC)
+        ),
+        None
+    );
+    assert_eq!(
+        as_of(
+            datetime!(2024-06-15 0:00 UTC),
+            datetime!(2023-07-20 0:00 UTC)
+        ),
+        None
+    );
+}
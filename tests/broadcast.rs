@@ -0,0 +1,102 @@
+use date_utils::{BroadcastPeriod, DateTimeError};
+use time::{
+    macros::{date, datetime},
+    Month,
+};
+
+#[test]
+fn test_broadcast_lookup() {
+    // January 1, 2024 is a Monday; January 1, 2023 a Sunday.
+    assert_eq!(
+        BroadcastPeriod::week_of(date!(2024 - 01 - 07)),
+        BroadcastPeriod::Week(2024, 1)
+    );
+    assert_eq!(
+        BroadcastPeriod::week_of(date!(2022 - 12 - 26)),
+        BroadcastPeriod::Week(2023, 1)
+    );
+    assert_eq!(
+        BroadcastPeriod::week_of(date!(2022 - 12 - 25)),
+        BroadcastPeriod::Week(2022, 52)
+    );
+    assert_eq!(
+        BroadcastPeriod::week_of(date!(2023 - 12 - 31)),
+        BroadcastPeriod::Week(2023, 53)
+    );
+    assert_eq!(
+        BroadcastPeriod::month_of(date!(2024 - 05 - 27)),
+        BroadcastPeriod::Month(2024, Month::June)
+    );
+    assert_eq!(
+        BroadcastPeriod::month_of(date!(2024 - 05 - 26)),
+        BroadcastPeriod::Month(2024, Month::May)
+    );
+    assert_eq!(
+        BroadcastPeriod::quarter_of(date!(2024 - 03 - 31)),
+        BroadcastPeriod::Quarter(2024, 1)
+    );
+    assert_eq!(
+        BroadcastPeriod::year_of(date!(2024 - 12 - 30)),
+        BroadcastPeriod::Year(2025)
+    );
+}
+
+#[test]
+fn test_broadcast_weeks_in_year() {
+    assert_eq!(BroadcastPeriod::weeks_in_year(2023).unwrap(), 53);
+    assert_eq!(BroadcastPeriod::weeks_in_year(2024).unwrap(), 52);
+    for year in [9999, i32::MAX, i32::MIN] {
+        assert!(matches!(
+            BroadcastPeriod::weeks_in_year(year).unwrap_err(),
+            DateTimeError::OutOfRange(_)
+        ));
+    }
+}
+
+#[test]
+fn test_broadcast_dates_and_range() {
+    assert_eq!(
+        BroadcastPeriod::Year(2024).dates().unwrap(),
+        (date!(2024 - 01 - 01), date!(2024 - 12 - 29))
+    );
+    assert_eq!(
+        BroadcastPeriod::Quarter(2024, 2).dates().unwrap(),
+        (date!(2024 - 04 - 01), date!(2024 - 06 - 30))
+    );
+    assert_eq!(
+        BroadcastPeriod::Month(2024, Month::February)
+            .dates()
+            .unwrap(),
+        (date!(2024 - 01 - 29), date!(2024 - 02 - 25))
+    );
+    assert_eq!(
+        BroadcastPeriod::Week(2023, 53).dates().unwrap(),
+        (date!(2023 - 12 - 25), date!(2023 - 12 - 31))
+    );
+    let range = BroadcastPeriod::Month(2024, Month::June).range().unwrap();
+    assert_eq!(range.start(), datetime!(2024-05-27 0:00 UTC));
+    assert_eq!(range.end(), datetime!(2024-06-30 23:59:59.999999999 UTC));
+    for invalid in [
+        BroadcastPeriod::Quarter(2024, 5),
+        BroadcastPeriod::Week(2024, 53),
+        BroadcastPeriod::Week(2024, 0),
+    ] {
+        assert!(matches!(
+            invalid.dates().unwrap_err(),
+            DateTimeError::InvalidTimeComponent(_)
+        ));
+    }
+    for extreme in [
+        BroadcastPeriod::Year(i32::MAX),
+        BroadcastPeriod::Year(i32::MIN),
+        BroadcastPeriod::Quarter(i32::MAX, 4),
+        BroadcastPeriod::Month(i32::MAX, Month::December),
+        BroadcastPeriod::Month(i32::MIN, Month::January),
+        BroadcastPeriod::Week(i32::MAX, 1),
+    ] {
+        assert!(
+            matches!(extreme.dates().unwrap_err(), DateTimeError::OutOfRange(_)),
+            "{extreme:?}"
+        );
+    }
+}
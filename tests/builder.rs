@@ -0,0 +1,77 @@
+use date_utils::{from_iso_week_date, from_year_ordinal, DateTimeBuilder, DateTimeError};
+use time::{
+    macros::{date, datetime},
+    Weekday,
+};
+
+#[test]
+fn test_datetime_builder() {
+    let builder = DateTimeBuilder::new().year(2024).month(2).day(29);
+    assert_eq!(builder.build().unwrap(), datetime!(2024-02-29 0:00 UTC));
+    assert_eq!(
+        builder
+            .hour(23)
+            .minute(59)
+            .second(58)
+            .nanosecond(5)
+            .offset_seconds(-3600)
+            .build()
+            .unwrap(),
+        datetime!(2024-02-29 23:59:58.000000005 -1)
+    );
+
+    for (builder, component) in [
+        (DateTimeBuilder::new().month(1).day(1), "year"),
+        (DateTimeBuilder::new().year(2024).day(1), "month"),
+        (DateTimeBuilder::new().year(2024).month(13).day(1), "month"),
+        (DateTimeBuilder::new().year(2023).month(2).day(29), "day"),
+        (DateTimeBuilder::new().year(100_000).month(1).day(1), "year"),
+        (builder.hour(24), "hour"),
+        (builder.minute(60), "minute"),
+        (builder.nanosecond(1_000_000_000), "nanosecond"),
+    ] {
+        match builder.build().unwrap_err() {
+            DateTimeError::InvalidComponent(name, _) => assert_eq!(name, component),
+            err => panic!("unexpected error for {component}: {err}"),
+        }
+    }
+    assert!(matches!(
+        builder.offset_seconds(100_000).build().unwrap_err(),
+        DateTimeError::InvalidOffset(100_000, _)
+    ));
+}
+
+#[test]
+fn test_from_iso_week_date() {
+    assert_eq!(
+        from_iso_week_date(2020, 53, Weekday::Sunday).unwrap(),
+        date!(2021 - 01 - 03)
+    );
+    assert_eq!(
+        from_iso_week_date(2024, 22, Weekday::Friday).unwrap(),
+        date!(2024 - 05 - 31)
+    );
+    for (year, week, component) in [(2021, 53, "week"), (2024, 0, "week"), (10_000, 1, "year")] {
+        assert!(matches!(
+            from_iso_week_date(year, week, Weekday::Monday).unwrap_err(),
+            DateTimeError::InvalidComponent(name, _) if name == component
+        ));
+    }
+}
+
+#[test]
+fn test_from_year_ordinal() {
+    assert_eq!(from_year_ordinal(2024, 1).unwrap(), date!(2024 - 01 - 01));
+    assert_eq!(from_year_ordinal(2024, 60).unwrap(), date!(2024 - 02 - 29));
+    assert_eq!(from_year_ordinal(2023, 60).unwrap(), date!(2023 - 03 - 01));
+    for (year, day, component) in [
+        (2023, 366, "ordinal"),
+        (2024, 0, "ordinal"),
+        (-10_000, 1, "year"),
+    ] {
+        assert!(matches!(
+            from_year_ordinal(year, day).unwrap_err(),
+            DateTimeError::InvalidComponent(name, _) if name == component
+        ));
+    }
+}
@@ -0,0 +1,253 @@
+#![cfg(feature = "business")]
+
+use date_utils::{
+    add_business_days, add_business_time, business_time_between, is_business_day,
+    period_end_business_days, BusinessHours, DateTimeError, Frequency, HolidayCalendar, Weekend,
+};
+use time::{
+    macros::{date, datetime, offset, time},
+    Duration, Weekday,
+};
+
+fn christmas() -> HolidayCalendar {
+    [date!(2024 - 12 - 25), date!(2024 - 12 - 26)]
+        .into_iter()
+        .collect()
+}
+
+#[test]
+fn test_weekend_and_business_hours() {
+    assert!(Weekend::default().contains(Weekday::Sunday));
+    assert!(!Weekend::default().contains(Weekday::Friday));
+    assert!(Weekend::FRIDAY_SATURDAY.contains(Weekday::Friday));
+    assert_eq!(
+        Weekend::new(&[Weekday::Sunday]).unwrap(),
+        Weekend::new(&[Weekday::Sunday, Weekday::Sunday]).unwrap()
+    );
+    assert!(matches!(
+        Weekend::new(&[
+            Weekday::Monday,
+            Weekday::Tuesday,
+            Weekday::Wednesday,
+            Weekday::Thursday,
+            Weekday::Friday,
+            Weekday::Saturday,
+            Weekday::Sunday,
+        ])
+        .unwrap_err(),
+        DateTimeError::InvalidBusinessHours(_)
+    ));
+    assert!(matches!(
+        BusinessHours::new(time!(17:00), time!(9:00), offset!(UTC)).unwrap_err(),
+        DateTimeError::InvalidBusinessHours(_)
+    ));
+}
+
+#[test]
+fn test_holiday_calendar() {
+    let mut calendar = christmas();
+    assert_eq!(calendar.holiday_name(date!(2024 - 12 - 25)), None);
+    calendar.add_named_holiday(date!(2024 - 12 - 25), "Christmas Day");
+    calendar.add_holiday(date!(2024 - 12 - 25));
+    calendar.add_named_holiday(date!(2025 - 01 - 01), "New Year's Day");
+    assert_eq!(
+        calendar.holiday_name(date!(2024 - 12 - 25)),
+        Some("Christmas Day")
+    );
+    assert_eq!(calendar.holiday_name(date!(2024 - 12 - 24)), None);
+    assert_eq!(
+        calendar.upcoming_holidays(date!(2024 - 12 - 24), 10),
+        [
+            (date!(2024 - 12 - 25), Some("Christmas Day")),
+            (date!(2024 - 12 - 26), None),
+            (date!(2025 - 01 - 01), Some("New Year's Day")),
+        ]
+    );
+    assert_eq!(
+        calendar.upcoming_holidays(date!(2024 - 12 - 25), 1),
+        [(date!(2024 - 12 - 26), None)]
+    );
+    assert!(calendar
+        .upcoming_holidays(date!(2025 - 01 - 01), 3)
+        .is_empty());
+    assert!(calendar
+        .upcoming_holidays(date!(2024 - 01 - 01), 0)
+        .is_empty());
+
+    let named: HolidayCalendar = [(date!(2024 - 12 - 25), String::from("Christmas Day"))]
+        .into_iter()
+        .collect();
+    assert!(named.is_holiday(date!(2024 - 12 - 25)));
+    assert_eq!(
+        named.holiday_name(date!(2024 - 12 - 25)),
+        Some("Christmas Day")
+    );
+}
+
+#[test]
+fn test_is_business_day() {
+    let calendar = christmas();
+    assert!(is_business_day(
+        date!(2024 - 12 - 24),
+        &Weekend::default(),
+        &calendar
+    ));
+    assert!(!is_business_day(
+        date!(2024 - 12 - 25),
+        &Weekend::default(),
+        &calendar
+    ));
+    assert!(!is_business_day(
+        date!(2024 - 12 - 28),
+        &Weekend::default(),
+        &calendar
+    ));
+}
+
+#[test]
+fn test_add_business_days() {
+    let calendar = christmas();
+    let weekend = Weekend::default();
+    assert_eq!(
+        add_business_days(date!(2024 - 12 - 24), 1, &weekend, &calendar).unwrap(),
+        date!(2024 - 12 - 27)
+    );
+    assert_eq!(
+        add_business_days(date!(2024 - 12 - 24), 2, &weekend, &calendar).unwrap(),
+        date!(2024 - 12 - 30)
+    );
+    assert_eq!(
+        add_business_days(date!(2024 - 12 - 30), -2, &weekend, &calendar).unwrap(),
+        date!(2024 - 12 - 24)
+    );
+    assert_eq!(
+        add_business_days(date!(2024 - 12 - 28), 0, &weekend, &calendar).unwrap(),
+        date!(2024 - 12 - 28)
+    );
+    assert!(matches!(
+        add_business_days(date!(9999 - 12 - 31), 1, &weekend, &calendar).unwrap_err(),
+        DateTimeError::OutOfRange(_)
+    ));
+}
+
+#[test]
+fn test_business_time_between() {
+    let hours = BusinessHours::new(time!(9:00), time!(17:00), offset!(+1)).unwrap();
+    let calendar = christmas();
+    let weekend = Weekend::default();
+    assert_eq!(
+        business_time_between(
+            datetime!(2024-12-24 15:00 +1),
+            datetime!(2024-12-27 10:30 +1),
+            &hours,
+            &weekend,
+            &calendar
+        ),
+        Duration::minutes(210)
+    );
+    assert_eq!(
+        business_time_between(
+            datetime!(2024-12-24 8:00 UTC),
+            datetime!(2024-12-24 9:00 UTC),
+            &hours,
+            &weekend,
+            &calendar
+        ),
+        Duration::hours(1)
+    );
+    assert_eq!(
+        business_time_between(
+            datetime!(2024-12-27 10:00 +1),
+            datetime!(2024-12-24 10:00 +1),
+            &hours,
+            &weekend,
+            &calendar
+        ),
+        Duration::ZERO
+    );
+}
+
+#[test]
+fn test_add_business_time() {
+    let hours = BusinessHours::new(time!(9:00), time!(17:00), offset!(+1)).unwrap();
+    let calendar = christmas();
+    let weekend = Weekend::default();
+    assert_eq!(
+        add_business_time(
+            datetime!(2024-12-24 15:00 +1),
+            Duration::hours(3),
+            &hours,
+            &weekend,
+            &calendar
+        )
+        .unwrap(),
+        datetime!(2024-12-27 10:00 +1)
+    );
+    assert_eq!(
+        add_business_time(
+            datetime!(2024-12-28 12:00 +1),
+            Duration::ZERO,
+            &hours,
+            &weekend,
+            &calendar
+        )
+        .unwrap(),
+        datetime!(2024-12-30 9:00 +1)
+    );
+    assert_eq!(
+        add_business_time(
+            datetime!(2024-12-24 10:00 UTC),
+            Duration::hours(1),
+            &hours,
+            &weekend,
+            &calendar
+        )
+        .unwrap(),
+        datetime!(2024-12-24 12:00 +1)
+    );
+}
+
+#[test]
+fn test_period_end_business_days() {
+    let calendar: HolidayCalendar = [date!(2024 - 05 - 31), date!(2024 - 12 - 31)]
+        .into_iter()
+        .collect();
+    let weekend = Weekend::default();
+    assert_eq!(
+        period_end_business_days(
+            date!(2024 - 04 - 15),
+            date!(2024 - 08 - 30),
+            Frequency::Monthly,
+            &weekend,
+            &calendar
+        )
+        .unwrap(),
+        vec![
+            date!(2024 - 04 - 30),
+            date!(2024 - 05 - 30),
+            date!(2024 - 06 - 28),
+            date!(2024 - 07 - 31),
+            date!(2024 - 08 - 30),
+        ]
+    );
+    assert_eq!(
+        period_end_business_days(
+            date!(2024 - 10 - 01),
+            date!(2025 - 03 - 27),
+            Frequency::Quarterly,
+            &weekend,
+            &calendar
+        )
+        .unwrap(),
+        vec![date!(2024 - 12 - 30)]
+    );
+    assert!(period_end_business_days(
+        date!(2024 - 05 - 01),
+        date!(2024 - 05 - 29),
+        Frequency::Monthly,
+        &weekend,
+        &calendar
+    )
+    .unwrap()
+    .is_empty());
+}
@@ -0,0 +1,52 @@
+use date_utils::is_stale;
+use time::{macros::datetime, Duration};
+
+#[cfg(feature = "rand")]
+#[test]
+fn test_expiry_with_jitter() {
+    use date_utils::{expiry_with_jitter, DateTimeError, Rng};
+
+    let now = datetime!(2024-05-31 12:00 UTC);
+    let ttl = Duration::hours(1);
+    let mut rng = Rng::new(42);
+    let expiries: Vec<_> = (0..100)
+        .map(|_| expiry_with_jitter(now, ttl, 0.2, &mut rng).unwrap())
+        .collect();
+    assert!(expiries
+        .iter()
+        .all(|expiry| *expiry > now + Duration::minutes(48) && *expiry <= now + ttl));
+    assert!(expiries
+        .iter()
+        .any(|expiry| *expiry < now + Duration::minutes(50)));
+    assert_eq!(
+        expiry_with_jitter(now, ttl, 0.0, &mut rng).unwrap(),
+        now + ttl
+    );
+    assert_eq!(
+        expiry_with_jitter(now, ttl, 0.5, &mut Rng::new(7)).unwrap(),
+        expiry_with_jitter(now, ttl, 0.5, &mut Rng::new(7)).unwrap()
+    );
+    for fraction in [-0.1, 1.5, f64::NAN] {
+        assert!(matches!(
+            expiry_with_jitter(now, ttl, fraction, &mut rng).unwrap_err(),
+            DateTimeError::InvalidComponent("jitter_fraction", _)
+        ));
+    }
+    assert!(matches!(
+        expiry_with_jitter(now, -ttl, 0.1, &mut rng).unwrap_err(),
+        DateTimeError::InvalidComponent("ttl", _)
+    ));
+}
+
+#[test]
+fn test_is_stale() {
+    let fetched_at = datetime!(2024-05-31 12:00 +2);
+    let ttl = Duration::minutes(5);
+    assert!(!is_stale(
+        fetched_at,
+        ttl,
+        datetime!(2024-05-31 10:04:59 UTC)
+    ));
+    assert!(is_stale(fetched_at, ttl, datetime!(2024-05-31 10:05 UTC)));
+    assert!(is_stale(fetched_at, Duration::ZERO, fetched_at));
+}
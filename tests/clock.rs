@@ -0,0 +1,14 @@
+use date_utils::{Clock, FixedClock, SystemClock};
+use time::{macros::datetime, OffsetDateTime};
+
+#[test]
+fn test_clocks() {
+    let fixed = FixedClock::new(datetime!(2024-05-31 12:00 +2));
+    assert_eq!(fixed.now(), datetime!(2024-05-31 10:00 UTC));
+    let by_ref: &dyn Clock = &fixed;
+    assert_eq!(by_ref.now(), fixed.now());
+
+    let before = OffsetDateTime::now_utc();
+    let now = SystemClock.now();
+    assert!(before <= now && now <= OffsetDateTime::now_utc());
+}
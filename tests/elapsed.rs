@@ -0,0 +1,45 @@
+use std::cell::Cell;
+
+use date_utils::{Clock, Elapsed};
+use time::{macros::datetime, Duration, OffsetDateTime};
+
+/// A clock advancing by the given step at each reading.
+struct SteppingClock {
+    now: Cell<OffsetDateTime>,
+    step: Duration,
+}
+
+impl Clock for SteppingClock {
+    fn now(&self) -> OffsetDateTime {
+        let now = self.now.get();
+        self.now.set(now + self.step);
+        now
+    }
+}
+
+fn clock(step: Duration) -> SteppingClock {
+    SteppingClock {
+        now: Cell::new(datetime!(2024-05-31 12:00 UTC)),
+        step,
+    }
+}
+
+#[test]
+fn test_elapsed() {
+    let clock = clock(Duration::seconds(90));
+    let mut stopwatch = Elapsed::start(&clock);
+    assert_eq!(stopwatch.started_at(), datetime!(2024-05-31 12:00 UTC));
+    assert_eq!(stopwatch.lap(), Duration::seconds(90));
+    assert_eq!(stopwatch.lap(), Duration::seconds(90));
+    assert_eq!(stopwatch.elapsed(), Duration::seconds(270));
+    assert_eq!(stopwatch.lap(), Duration::seconds(180));
+}
+
+#[cfg(feature = "formatting")]
+#[test]
+fn test_elapsed_display() {
+    let stopwatch = Elapsed::start(clock(Duration::milliseconds(125_500)));
+    assert_eq!(stopwatch.to_string(), "2m 5s 500ms");
+    let backward = Elapsed::start(clock(Duration::seconds(-1)));
+    assert_eq!(backward.to_string(), "0s");
+}
@@ -0,0 +1,95 @@
+use date_utils::{DateTimeError, DateTimeRange, FiscalCalendar, FiscalPeriod};
+use time::{
+    macros::{date, datetime},
+    Month,
+};
+
+#[test]
+fn test_fiscal_period_parsing() {
+    for (notation, expected) in [
+        ("FY2024", FiscalPeriod::Year(2024)),
+        ("fy24", FiscalPeriod::Year(2024)),
+        ("FY2024Q3", FiscalPeriod::Quarter(2024, 3)),
+        ("FY2024-Q3", FiscalPeriod::Quarter(2024, 3)),
+        ("FY24 Q3", FiscalPeriod::Quarter(2024, 3)),
+        ("Q3 FY24", FiscalPeriod::Quarter(2024, 3)),
+    ] {
+        assert_eq!(
+            notation.parse::<FiscalPeriod>().unwrap(),
+            expected,
+            "{notation}"
+        );
+    }
+    assert_eq!(FiscalPeriod::Quarter(2024, 3).to_string(), "FY2024Q3");
+    assert!(matches!(
+        "FY2024Q5".parse::<FiscalPeriod>().unwrap_err(),
+        DateTimeError::InvalidTimeComponent(_)
+    ));
+    for invalid in ["2024Q3", "FY202", "Q3 FY24 Q4", "FY2024H1"] {
+        assert!(matches!(
+            invalid.parse::<FiscalPeriod>().unwrap_err(),
+            DateTimeError::InvalidDateFormat(_, _)
+        ));
+    }
+}
+
+#[test]
+fn test_fiscal_calendar_dates() {
+    let april = FiscalCalendar::new(Month::April);
+    assert_eq!(
+        april.dates(FiscalPeriod::Year(2024)).unwrap(),
+        (date!(2023 - 04 - 01), date!(2024 - 03 - 31))
+    );
+    assert_eq!(
+        april.dates(FiscalPeriod::Quarter(2024, 4)).unwrap(),
+        (date!(2024 - 01 - 01), date!(2024 - 03 - 31))
+    );
+    let calendar_year = FiscalCalendar::new(Month::January);
+    assert_eq!(
+        calendar_year.dates(FiscalPeriod::Quarter(2024, 2)).unwrap(),
+        (date!(2024 - 04 - 01), date!(2024 - 06 - 30))
+    );
+    assert!(matches!(
+        april.dates(FiscalPeriod::Quarter(2024, 0)).unwrap_err(),
+        DateTimeError::InvalidTimeComponent(_)
+    ));
+    for (calendar, period) in [
+        (april, FiscalPeriod::Year(i32::MIN)),
+        (april, FiscalPeriod::Quarter(i32::MAX, 4)),
+        (calendar_year, FiscalPeriod::Year(i32::MAX)),
+        (calendar_year, FiscalPeriod::Quarter(i32::MIN, 1)),
+    ] {
+        assert!(
+            matches!(
+                calendar.dates(period).unwrap_err(),
+                DateTimeError::OutOfRange(_)
+            ),
+            "{period:?}"
+        );
+    }
+}
+
+#[test]
+fn test_fiscal_calendar_parse_range_and_back() {
+    let october = FiscalCalendar::new(Month::October);
+    let range = october.parse_range("FY24").unwrap();
+    assert_eq!(range.start(), datetime!(2023-10-01 0:00 UTC));
+    assert_eq!(range.end(), datetime!(2024-09-30 23:59:59.999999999 UTC));
+    assert_eq!(october.period_of(&range), Some(FiscalPeriod::Year(2024)));
+
+    let not_a_period = DateTimeRange::new(
+        datetime!(2023-10-02 0:00 UTC),
+        datetime!(2023-12-31 0:00 UTC),
+    )
+    .unwrap();
+    assert_eq!(october.period_of(&not_a_period), None);
+
+    assert_eq!(
+        october.year_of(date!(2023 - 10 - 01)),
+        FiscalPeriod::Year(2024)
+    );
+    assert_eq!(
+        october.quarter_of(date!(2023 - 09 - 30)),
+        FiscalPeriod::Quarter(2023, 4)
+    );
+}
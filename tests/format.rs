@@ -0,0 +1,189 @@
+#![cfg(feature = "formatting")]
+
+use date_utils::{
+    format_datetime, format_many, ordinal_suffix, write_datetime, write_many, DateTimeError,
+    Format, Frequency, IsoDate, Rfc3339, SdmxPeriod,
+};
+use time::macros::datetime;
+
+#[test]
+fn test_format_datetime() {
+    let datetime = datetime!(2024-05-31 12:00:00.5 +2);
+    assert_eq!(
+        format_datetime(datetime, Format::Date).unwrap(),
+        "2024-05-31"
+    );
+    assert_eq!(
+        format_datetime(datetime, Format::DateTime).unwrap(),
+        "2024-05-31 12:00:00"
+    );
+    assert_eq!(
+        format_datetime(datetime, Format::Rfc3339).unwrap(),
+        "2024-05-31T12:00:00.5+02:00"
+    );
+    assert_eq!(
+        format_datetime(datetime!(2024-06-02 0:00 UTC), Format::Long).unwrap(),
+        "June 2, 2024"
+    );
+    assert_eq!(
+        format_datetime(datetime!(2024-06-02 0:00 UTC), Format::LongOrdinal).unwrap(),
+        "June 2nd, 2024"
+    );
+    assert!(matches!(
+        format_datetime(datetime!(2024-05-31 12:00 +0:00:30), Format::Rfc3339).unwrap_err(),
+        DateTimeError::FormatError(_, _)
+    ));
+}
+
+#[test]
+fn test_rfc3339_profiles() {
+    let datetime = datetime!(2024-05-31 12:00:00.123456 +2);
+    let utc = datetime!(2024-05-31 12:00 UTC);
+    for (format, expected, expected_utc) in [
+        (
+            Format::Rfc3339Strict,
+            "2024-05-31T12:00:00+02:00",
+            "2024-05-31T12:00:00+00:00",
+        ),
+        (
+            Format::Rfc3339Millis,
+            "2024-05-31T12:00:00.123+02:00",
+            "2024-05-31T12:00:00.000+00:00",
+        ),
+        (
+            Format::Rfc3339Z,
+            "2024-05-31T10:00:00.123Z",
+            "2024-05-31T12:00:00.000Z",
+        ),
+    ] {
+        assert_eq!(format_datetime(datetime, format).unwrap(), expected);
+        assert_eq!(format_datetime(utc, format).unwrap(), expected_utc);
+    }
+    // RFC 3339 cannot represent offsets with seconds nor negative years.
+    assert!(matches!(
+        format_datetime(datetime!(2024-05-31 12:00 +0:00:30), Format::Rfc3339Millis).unwrap_err(),
+        DateTimeError::FormatError(_, _)
+    ));
+    assert_eq!(
+        format_datetime(datetime!(2024-05-31 12:00 +0:00:30), Format::Rfc3339Z).unwrap(),
+        "2024-05-31T11:59:30.000Z"
+    );
+    assert!(matches!(
+        format_datetime(datetime!(-0001-01-01 0:00 UTC), Format::Rfc3339Strict).unwrap_err(),
+        DateTimeError::FormatError(_, _)
+    ));
+}
+
+#[test]
+fn test_write_datetime() {
+    let mut csv = String::new();
+    for datetime in [
+        datetime!(2024-05-30 0:00 UTC),
+        datetime!(2024-05-31 0:00 UTC),
+    ] {
+        write_datetime(&mut csv, datetime, Format::Date).unwrap();
+        csv.push('\n');
+    }
+    assert_eq!(csv, "2024-05-30\n2024-05-31\n");
+
+    let capacity = csv.capacity();
+    csv.clear();
+    write_datetime(&mut csv, datetime!(2024-05-31 0:00 UTC), Format::Date).unwrap();
+    assert_eq!(csv.capacity(), capacity);
+}
+
+#[test]
+fn test_format_many() {
+    let datetimes = [
+        datetime!(2024-05-30 12:00 UTC),
+        datetime!(2024-05-31 12:00:00.5 +2),
+    ];
+    assert_eq!(
+        format_many(&datetimes, Format::Rfc3339).unwrap(),
+        ["2024-05-30T12:00:00Z", "2024-05-31T12:00:00.5+02:00"]
+    );
+    assert!(format_many(&[], Format::Date).unwrap().is_empty());
+    assert!(matches!(
+        format_many(
+            &[datetimes[0], datetime!(2024-05-31 12:00 +0:00:30)],
+            Format::Rfc3339
+        )
+        .unwrap_err(),
+        DateTimeError::FormatError(_, _)
+    ));
+}
+
+#[test]
+fn test_write_many() {
+    let datetimes = [
+        datetime!(2024-05-30 12:00 UTC),
+        datetime!(2024-05-31 12:00 UTC),
+        datetime!(2024-06-01 12:00 UTC),
+    ];
+    let mut csv = String::from("date\n");
+    write_many(&mut csv, &datetimes, Format::Date, "\n").unwrap();
+    assert_eq!(csv, "date\n2024-05-30\n2024-05-31\n2024-06-01");
+
+    let mut single = String::new();
+    write_many(&mut single, &datetimes[..1], Format::DateTime, ";").unwrap();
+    assert_eq!(single, "2024-05-30 12:00:00");
+}
+
+#[test]
+fn test_display_wrappers() {
+    let datetime = datetime!(2024-12-30 12:00:00.5 +1);
+    assert_eq!(
+        format!("{} {}", IsoDate(datetime), Rfc3339(datetime)),
+        "2024-12-30 2024-12-30T12:00:00.5+01:00"
+    );
+    assert_eq!(
+        Rfc3339(datetime!(2024-05-31 12:00:00.25 -0:00:30)).to_string(),
+        "2024-05-31T12:00:30.25Z"
+    );
+    assert_eq!(
+        Rfc3339(datetime!(-0001-12-31 23:00 -2)).to_string(),
+        "-0001-12-31T23:00:00-02:00"
+    );
+    assert_eq!(
+        Rfc3339(datetime!(9999-12-31 23:59:59 -0:00:30)).to_string(),
+        "9999-12-31T23:59:59-00:00:30"
+    );
+    assert_eq!(
+        Rfc3339(datetime!(2024-05-31 12:00 UTC)).to_string(),
+        "2024-05-31T12:00:00Z"
+    );
+    let periods: Vec<_> = [
+        Frequency::Daily,
+        Frequency::Weekly,
+        Frequency::Monthly,
+        Frequency::Quarterly,
+        Frequency::Semiannual,
+        Frequency::Annual,
+    ]
+    .into_iter()
+    .map(|frequency| SdmxPeriod(datetime, frequency).to_string())
+    .collect();
+    assert_eq!(
+        periods,
+        [
+            "2024-12-30",
+            "2025-W01",
+            "2024-12",
+            "2024-Q4",
+            "2024-H2",
+            "2024"
+        ]
+    );
+}
+
+#[test]
+fn test_ordinal_suffix() {
+    let suffixes: Vec<_> = [1, 2, 3, 4, 11, 12, 13, 21, 22, 23, 31]
+        .into_iter()
+        .map(ordinal_suffix)
+        .collect();
+    assert_eq!(
+        suffixes,
+        ["st", "nd", "rd", "th", "th", "th", "th", "st", "nd", "rd", "st"]
+    );
+}
@@ -0,0 +1,104 @@
+#![cfg(feature = "business")]
+
+use date_utils::{
+    active_contract, roll_dates, ContractSpec, DateTimeError, ExpiryRule, FuturesContract,
+    HolidayCalendar, Roll, Weekend,
+};
+use time::{macros::date, Month};
+
+fn quarterly() -> ContractSpec {
+    ContractSpec::new(
+        &[Month::December, Month::March, Month::June, Month::September],
+        ExpiryRule::ThirdWednesday,
+        5,
+    )
+    .unwrap()
+}
+
+#[test]
+fn test_contract_spec() {
+    let spec = quarterly();
+    let june = FuturesContract {
+        year: 2024,
+        month: Month::June,
+    };
+    assert_eq!(june.to_string(), "M2024");
+    assert_eq!(spec.expiry(june).unwrap(), date!(2024 - 06 - 19));
+    assert_eq!(spec.roll_date(june).unwrap(), date!(2024 - 06 - 12));
+
+    let spec = ContractSpec::new(&[Month::June], ExpiryRule::ThirdFriday, 1)
+        .unwrap()
+        .with_calendar(
+            Weekend::default(),
+            [date!(2024 - 06 - 21)]
+                .into_iter()
+                .collect::<HolidayCalendar>(),
+        );
+    assert_eq!(spec.expiry(june).unwrap(), date!(2024 - 06 - 20));
+    assert_eq!(spec.roll_date(june).unwrap(), date!(2024 - 06 - 19));
+
+    assert!(matches!(
+        ContractSpec::new(&[], ExpiryRule::ThirdFriday, 1).unwrap_err(),
+        DateTimeError::InvalidContractSpec(_)
+    ));
+}
+
+#[test]
+fn test_active_contract() {
+    let spec = quarterly();
+    let contract = |year, month| FuturesContract { year, month };
+    assert_eq!(
+        active_contract(&spec, date!(2024 - 06 - 11)).unwrap(),
+        contract(2024, Month::June)
+    );
+    assert_eq!(
+        active_contract(&spec, date!(2024 - 06 - 12)).unwrap(),
+        contract(2024, Month::September)
+    );
+    assert_eq!(
+        active_contract(&spec, date!(2024 - 12 - 31)).unwrap(),
+        contract(2025, Month::March)
+    );
+    assert_eq!(
+        active_contract(&spec, date!(2024 - 01 - 15)).unwrap(),
+        contract(2024, Month::March)
+    );
+}
+
+#[test]
+fn test_roll_dates() {
+    let spec = quarterly();
+    let rolls = roll_dates(&spec, date!(2024 - 03 - 14), date!(2024 - 09 - 11)).unwrap();
+    assert_eq!(
+        rolls,
+        vec![
+            Roll {
+                date: date!(2024 - 06 - 12),
+                from: FuturesContract {
+                    year: 2024,
+                    month: Month::June
+                },
+                to: FuturesContract {
+                    year: 2024,
+                    month: Month::September
+                },
+            },
+            Roll {
+                date: date!(2024 - 09 - 11),
+                from: FuturesContract {
+                    year: 2024,
+                    month: Month::September
+                },
+                to: FuturesContract {
+                    year: 2024,
+                    month: Month::December
+                },
+            },
+        ]
+    );
+    assert!(
+        roll_dates(&spec, date!(2024 - 06 - 13), date!(2024 - 09 - 10))
+            .unwrap()
+            .is_empty()
+    );
+}
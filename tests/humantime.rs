@@ -0,0 +1,139 @@
+#![cfg(all(feature = "parsing", feature = "formatting"))]
+
+use date_utils::{
+    format_duration, format_rfc3339, parse_duration, parse_rfc3339, write_duration, write_rfc3339,
+    DateTimeError,
+};
+use std::time::Duration;
+use time::macros::datetime;
+
+#[test]
+fn test_format_duration() {
+    assert_eq!(format_duration(Duration::ZERO), "0s");
+    assert_eq!(format_duration(Duration::from_secs(1)), "1s");
+    assert_eq!(format_duration(Duration::from_secs(86_400)), "1day");
+    assert_eq!(
+        format_duration(Duration::from_secs(2 * 86_400 + 5)),
+        "2days 5s"
+    );
+    assert_eq!(
+        format_duration(Duration::from_secs(31_557_600 + 2 * 2_630_016)),
+        "1year 2months"
+    );
+    assert_eq!(format_duration(Duration::new(0, 1_002_003)), "1ms 2us 3ns");
+}
+
+#[test]
+fn test_parse_duration() {
+    assert_eq!(
+        parse_duration("1h 30m").unwrap(),
+        Duration::from_secs(5_400)
+    );
+    assert_eq!(parse_duration("1h30m").unwrap(), Duration::from_secs(5_400));
+    assert_eq!(
+        parse_duration(" 2 weeks ").unwrap(),
+        Duration::from_secs(1_209_600)
+    );
+    assert_eq!(
+        parse_duration("1M").unwrap(),
+        Duration::from_secs(2_630_016)
+    );
+    assert_eq!(parse_duration("15 min").unwrap(), Duration::from_secs(900));
+    assert_eq!(
+        parse_duration("5000000000ns").unwrap(),
+        Duration::from_secs(5)
+    );
+    for (input, expected) in [
+        ("3 secs", Duration::from_secs(3)),
+        ("2 mins", Duration::from_secs(120)),
+        ("2hrs", Duration::from_secs(7_200)),
+        ("250millis", Duration::from_millis(250)),
+        ("7 nanos", Duration::from_nanos(7)),
+        ("12µs", Duration::from_micros(12)),
+        ("1wk", Duration::from_secs(604_800)),
+        ("2wks", Duration::from_secs(1_209_600)),
+        ("1yr 1day", Duration::from_secs(31_557_600 + 86_400)),
+        ("2yrs", Duration::from_secs(2 * 31_557_600)),
+    ] {
+        assert_eq!(parse_duration(input).unwrap(), expected, "{input:?}");
+    }
+    for duration in [
+        Duration::new(9420, 1_000_000),
+        Duration::from_secs(31_557_600 * 3 + 86_400),
+        Duration::new(59, 999_999_999),
+    ] {
+        assert_eq!(
+            parse_duration(&format_duration(duration)).unwrap(),
+            duration
+        );
+    }
+    for input in [
+        "",
+        "h",
+        "10",
+        "10 parsecs",
+        "-1s",
+        "99999999999999999999s",
+        "18446744073709551615y",
+    ] {
+        assert!(
+            matches!(
+                parse_duration(input),
+                Err(DateTimeError::InvalidDuration(_, _))
+            ),
+            "{input:?}"
+        );
+    }
+}
+
+#[test]
+fn test_format_rfc3339() {
+    assert_eq!(
+        format_rfc3339(datetime!(2018-02-14 00:28:07 UTC)),
+        "2018-02-14T00:28:07Z"
+    );
+    assert_eq!(
+        format_rfc3339(datetime!(2018-02-13 20:28:07.999 -4)),
+        "2018-02-14T00:28:07.999000000Z"
+    );
+    assert_eq!(
+        format_rfc3339(datetime!(2018-02-14 00:28:07.000000001 UTC)),
+        "2018-02-14T00:28:07.000000001Z"
+    );
+    // Past the end of year 9999 in UTC, the instant keeps its own offset.
+    assert_eq!(
+        format_rfc3339(datetime!(9999-12-31 23:00 -1)),
+        "9999-12-31T23:00:00-01:00"
+    );
+}
+
+#[test]
+fn test_parse_rfc3339() {
+    for datetime in [
+        datetime!(2018-02-14 00:28:07 UTC),
+        datetime!(2018-02-14 00:28:07.5 UTC),
+    ] {
+        assert_eq!(parse_rfc3339(&format_rfc3339(datetime)).unwrap(), datetime);
+    }
+    assert_eq!(
+        parse_rfc3339("2018-02-14T02:28:07.000000001+02:00").unwrap(),
+        datetime!(2018-02-14 02:28:07.000000001 +2)
+    );
+    assert!(matches!(
+        parse_rfc3339("2018-02-14 00:28:07").unwrap_err(),
+        DateTimeError::InvalidDateFormat(_, _)
+    ));
+}
+
+#[test]
+fn test_write_duration_and_rfc3339() {
+    let mut line = String::new();
+    write_duration(&mut line, Duration::new(3_661, 5)).unwrap();
+    line.push_str(" @ ");
+    write_rfc3339(&mut line, datetime!(2018-02-14 01:28:07 +1)).unwrap();
+    assert_eq!(line, "1h 1m 1s 5ns @ 2018-02-14T00:28:07Z");
+
+    let mut line = String::new();
+    assert!(write_rfc3339(&mut line, datetime!(9999-12-31 23:00 -1)).is_err());
+    assert!(line.is_empty());
+}
@@ -0,0 +1,73 @@
+use date_utils::{DateTimeRange, RangeIndex};
+use time::{macros::datetime, Duration, OffsetDateTime};
+
+/// Generates deterministic pseudo-random ranges within a week.
+fn ranges(count: usize) -> Vec<DateTimeRange> {
+    let origin = datetime!(2024-05-01 0:00 UTC);
+    let mut state: u64 = 42;
+    let mut next = |modulo: u64| {
+        state = state
+            .wrapping_mul(6_364_136_223_846_793_005)
+            .wrapping_add(1_442_695_040_888_963_407);
+        (state >> 33) % modulo
+    };
+    (0..count)
+        .map(|_| {
+            let start = origin + Duration::minutes(next(7 * 24 * 60) as i64);
+            let end = start + Duration::minutes(next(600) as i64);
+            DateTimeRange::new(start, end).unwrap()
+        })
+        .collect()
+}
+
+fn sorted(mut ranges: Vec<DateTimeRange>) -> Vec<DateTimeRange> {
+    ranges.sort_by_key(|range| (range.start(), range.end()));
+    ranges
+}
+
+#[test]
+fn test_ranges_containing() {
+    let all = ranges(500);
+    let index = RangeIndex::new(all.clone());
+    assert_eq!(index.len(), 500);
+    for minutes in (0..7 * 24 * 60).step_by(37) {
+        let instant: OffsetDateTime = datetime!(2024-05-01 0:00 UTC) + Duration::minutes(minutes);
+        let expected = sorted(
+            all.iter()
+                .copied()
+                .filter(|range| range.contains(instant))
+                .collect(),
+        );
+        let found: Vec<_> = index
+            .ranges_containing(instant)
+            .into_iter()
+            .copied()
+            .collect();
+        assert_eq!(sorted(found), expected);
+        assert_eq!(index.any_containing(instant), !expected.is_empty());
+    }
+}
+
+#[test]
+fn test_ranges_overlapping() {
+    let all = ranges(300);
+    let index: RangeIndex = all.iter().copied().collect();
+    for query in ranges(50) {
+        let expected = sorted(
+            all.iter()
+                .copied()
+                .filter(|range| range.overlaps(&query))
+                .collect(),
+        );
+        let found: Vec<_> = index
+            .ranges_overlapping(&query)
+            .into_iter()
+            .copied()
+            .collect();
+        assert_eq!(sorted(found), expected);
+    }
+    assert!(RangeIndex::default().is_empty());
+    assert!(RangeIndex::default()
+        .ranges_containing(datetime!(2024-05-01 0:00 UTC))
+        .is_empty());
+}
@@ -0,0 +1,54 @@
+#![cfg(feature = "interop")]
+
+use date_utils::{
+    datetime_from_system_time, duration_since_epoch, system_time_from_datetime, DateTimeError,
+};
+use std::time::{Duration, UNIX_EPOCH};
+use time::macros::datetime;
+
+#[test]
+fn test_datetime_from_system_time() {
+    assert_eq!(
+        datetime_from_system_time(UNIX_EPOCH + Duration::from_millis(1_717_156_800_500)).unwrap(),
+        datetime!(2024-05-31 12:00:00.5 UTC)
+    );
+    assert_eq!(
+        datetime_from_system_time(UNIX_EPOCH - Duration::from_secs(86_400)).unwrap(),
+        datetime!(1969-12-31 0:00 UTC)
+    );
+}
+
+#[test]
+fn test_system_time_from_datetime() {
+    let datetime = datetime!(2024-05-31 14:00:00.5 +02:00);
+    let system_time = system_time_from_datetime(datetime).unwrap();
+    assert_eq!(
+        system_time.duration_since(UNIX_EPOCH).unwrap(),
+        Duration::from_millis(1_717_156_800_500)
+    );
+    assert_eq!(datetime_from_system_time(system_time).unwrap(), datetime);
+    for datetime in [
+        datetime!(1969-12-31 23:59:59.75 UTC),
+        datetime!(1601-01-01 0:00 UTC),
+        datetime!(9999-12-31 23:59:59.999999999 UTC),
+    ] {
+        let system_time = system_time_from_datetime(datetime).unwrap();
+        assert_eq!(datetime_from_system_time(system_time).unwrap(), datetime);
+    }
+}
+
+#[test]
+fn test_duration_since_epoch() {
+    assert_eq!(
+        duration_since_epoch(datetime!(2024-05-31 12:00 UTC)).unwrap(),
+        Duration::from_secs(1_717_156_800)
+    );
+    assert_eq!(
+        duration_since_epoch(datetime!(1970-01-01 0:00 UTC)).unwrap(),
+        Duration::ZERO
+    );
+    assert!(matches!(
+        duration_since_epoch(datetime!(1969-12-31 23:59:59.999 UTC)).unwrap_err(),
+        DateTimeError::BeforeEpoch(_)
+    ));
+}
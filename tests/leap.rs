@@ -0,0 +1,88 @@
+use std::ops::RangeInclusive;
+
+use date_utils::{add_years, anniversary, yearly_dates, DateTimeError, Feb29Policy};
+use time::{macros::date, Month};
+
+#[test]
+fn test_add_years() {
+    assert_eq!(
+        add_years(date!(2023 - 05 - 31), 2, Feb29Policy::default()).unwrap(),
+        date!(2025 - 05 - 31)
+    );
+    assert_eq!(
+        add_years(date!(2024 - 02 - 29), 1, Feb29Policy::MoveToFeb28).unwrap(),
+        date!(2025 - 02 - 28)
+    );
+    assert_eq!(
+        add_years(date!(2024 - 02 - 29), -1, Feb29Policy::MoveToMar1).unwrap(),
+        date!(2023 - 03 - 01)
+    );
+    assert_eq!(
+        add_years(date!(2024 - 02 - 29), 4, Feb29Policy::Error).unwrap(),
+        date!(2028 - 02 - 29)
+    );
+    assert!(matches!(
+        add_years(date!(2024 - 02 - 29), 1, Feb29Policy::Error).unwrap_err(),
+        DateTimeError::InvalidLeapDay(2025)
+    ));
+    assert!(matches!(
+        add_years(date!(2024 - 01 - 01), 10_000, Feb29Policy::default()).unwrap_err(),
+        DateTimeError::OutOfRange(_)
+    ));
+}
+
+#[test]
+fn test_anniversary() {
+    assert_eq!(
+        anniversary(date!(2000 - 02 - 29), 2100, Feb29Policy::MoveToMar1).unwrap(),
+        date!(2100 - 03 - 01)
+    );
+    assert_eq!(
+        anniversary(date!(2000 - 02 - 29), 2400, Feb29Policy::Error).unwrap(),
+        date!(2400 - 02 - 29)
+    );
+    assert_eq!(
+        anniversary(date!(2019 - 12 - 31), 2020, Feb29Policy::Error).unwrap(),
+        date!(2020 - 12 - 31)
+    );
+}
+
+#[test]
+fn test_yearly_dates() {
+    assert_eq!(
+        yearly_dates((Month::June, 30), 2022..=2024, Feb29Policy::Error).unwrap(),
+        [
+            date!(2022 - 06 - 30),
+            date!(2023 - 06 - 30),
+            date!(2024 - 06 - 30)
+        ]
+    );
+    assert_eq!(
+        yearly_dates((Month::February, 29), 2099..=2100, Feb29Policy::MoveToFeb28).unwrap(),
+        [date!(2099 - 02 - 28), date!(2100 - 02 - 28)]
+    );
+    assert!(yearly_dates(
+        (Month::June, 30),
+        RangeInclusive::new(2025, 2024),
+        Feb29Policy::Error
+    )
+    .unwrap()
+    .is_empty());
+    assert!(matches!(
+        yearly_dates((Month::February, 29), 2024..=2025, Feb29Policy::Error).unwrap_err(),
+        DateTimeError::InvalidLeapDay(2025)
+    ));
+    assert!(matches!(
+        yearly_dates(
+            (Month::April, 31),
+            RangeInclusive::new(2025, 2024),
+            Feb29Policy::Error
+        )
+        .unwrap_err(),
+        DateTimeError::InvalidComponent("day", _)
+    ));
+    assert!(matches!(
+        yearly_dates((Month::June, 30), 9999..=10_000, Feb29Policy::Error).unwrap_err(),
+        DateTimeError::OutOfRange(_)
+    ));
+}
@@ -1,6 +1,8 @@
 use date_utils::{
-    datetime_to_date, parse_response_string_to_datetime, parse_to_datetime, timestamp_to_datetime,
-    DateTimeError, DateType, OffsetType,
+    date_to_epoch_days, datetime_to_date, epoch_days_to_date, parse_datetime,
+    parse_response_string_to_datetime, parse_to_datetime, parse_to_datetime_at,
+    parse_to_datetime_with, timestamp_to_datetime, validate_in_future, validate_not_in_future,
+    DateTimeError, DateType, FixedClock, OffsetHandling, OffsetType, TemporalPolicy,
 };
 use time::macros::{date, datetime};
 
@@ -16,6 +18,90 @@ fn test_parse_to_datetime() {
     );
 }
 
+#[test]
+fn test_parse_to_datetime_keywords() {
+    let clock = FixedClock::new(datetime!(2024-12-31 18:30 +2));
+    let parse = |keyword, date_type| {
+        parse_to_datetime_at(keyword, date_type, OffsetType::Utc, &clock).unwrap()
+    };
+    assert_eq!(parse("now", DateType::End), datetime!(2024-12-31 16:30 UTC));
+    assert_eq!(
+        parse("Today", DateType::Start),
+        datetime!(2024-12-31 0:00 UTC)
+    );
+    assert_eq!(
+        parse("today", DateType::End),
+        datetime!(2024-12-31 23:59:59.999999999 UTC)
+    );
+    assert_eq!(
+        parse("yesterday", DateType::Start),
+        datetime!(2024-12-30 0:00 UTC)
+    );
+    assert_eq!(
+        parse("eom", DateType::Start),
+        datetime!(2024-12-31 0:00 UTC)
+    );
+    assert_eq!(
+        parse("eoy", DateType::Start),
+        datetime!(2024-12-31 0:00 UTC)
+    );
+    assert!(matches!(
+        parse_to_datetime_at("2025-01-01", DateType::Start, OffsetType::Utc, &clock).unwrap_err(),
+        DateTimeError::DateInFuture(_, _)
+    ));
+    assert!(matches!(
+        parse_to_datetime_at("tomorrow", DateType::Start, OffsetType::Utc, &clock).unwrap_err(),
+        DateTimeError::InvalidDateFormat(_, _)
+    ));
+}
+
+#[test]
+fn test_parse_to_datetime_with_policy() {
+    let clock = FixedClock::new(datetime!(2024-05-31 12:00 UTC));
+    let parse = |date, date_type, policy| {
+        parse_to_datetime_with(date, date_type, OffsetType::Utc, policy, &clock)
+    };
+    assert_eq!(
+        parse("2024-06-30", DateType::End, TemporalPolicy::RejectPast).unwrap(),
+        datetime!(2024-06-30 23:59:59.999999999 UTC)
+    );
+    assert_eq!(
+        parse("2024-05-31", DateType::End, TemporalPolicy::RejectPast).unwrap(),
+        datetime!(2024-05-31 23:59:59.999999999 UTC)
+    );
+    assert!(matches!(
+        parse("2024-05-31", DateType::Start, TemporalPolicy::RejectPast).unwrap_err(),
+        DateTimeError::DateInPast(_, _)
+    ));
+    assert!(matches!(
+        parse("2024-06-30", DateType::Start, TemporalPolicy::default()).unwrap_err(),
+        DateTimeError::DateInFuture(_, _)
+    ));
+    assert!(parse("2024-06-30", DateType::Start, TemporalPolicy::Any).is_ok());
+    assert!(parse("2020-01-01", DateType::Start, TemporalPolicy::Any).is_ok());
+    assert_eq!(
+        parse("yesterday", DateType::Start, TemporalPolicy::RejectPast).unwrap(),
+        datetime!(2024-05-30 0:00 UTC)
+    );
+}
+
+#[test]
+fn test_validate_in_future() {
+    let now = datetime!(2024-05-31 12:00 UTC);
+    assert!(validate_in_future(now, now).is_ok());
+    assert!(validate_in_future(datetime!(2024-05-31 14:00 +2), now).is_ok());
+    assert!(matches!(
+        validate_in_future(datetime!(2024-05-31 11:59 UTC), now).unwrap_err(),
+        DateTimeError::DateInPast(datetime, now) if datetime == "2024-05-31 11:59:00.0 +00:00:00"
+            && now == "2024-05-31 12:00:00.0 +00:00:00"
+    ));
+    assert!(validate_not_in_future(now, now).is_ok());
+    assert!(matches!(
+        validate_not_in_future(datetime!(2024-05-31 12:01 UTC), now).unwrap_err(),
+        DateTimeError::DateInFuture(_, _)
+    ));
+}
+
 #[test]
 fn test_parse_to_datetime_error() {
     assert!(matches!(
@@ -68,6 +154,65 @@ fn test_datetime_to_date() {
     assert_eq!(date, date!(2025 - 05 - 09));
 }
 
+#[test]
+fn test_parse_datetime() {
+    for (input, expected) in [
+        ("2024-05-31 12:00:00+02:00", datetime!(2024-05-31 12:00 +2)),
+        (
+            "2024-05-31T12:00:00.25-0330",
+            datetime!(2024-05-31 12:00:00.25 -3:30),
+        ),
+        ("2024-05-31 12:00:00 +02", datetime!(2024-05-31 12:00 +2)),
+        ("2024-05-31T12:00:00Z", datetime!(2024-05-31 12:00 UTC)),
+        (" 2024-05-31 12:00:00 ", datetime!(2024-05-31 12:00 UTC)),
+    ] {
+        let parsed = parse_datetime(input, OffsetHandling::Preserve).unwrap();
+        assert_eq!(parsed, expected, "{input}");
+        assert_eq!(parsed.offset(), expected.offset(), "{input}");
+        let utc = parse_datetime(input, OffsetHandling::ConvertToUtc).unwrap();
+        assert_eq!(utc, expected);
+        assert!(utc.offset().is_utc());
+    }
+    for invalid in [
+        "2024-05-31",
+        "2024-05-31 12:00",
+        "2024-05-31 12:00:00+2",
+        "2024-05-31 12:00:00 UTC",
+        "2024-05-31 12:00:00+02:00:00",
+    ] {
+        assert!(matches!(
+            parse_datetime(invalid, OffsetHandling::Preserve).unwrap_err(),
+            DateTimeError::InvalidDateTimeFormat(_)
+        ));
+    }
+}
+
+#[test]
+fn test_date_to_epoch_days() {
+    assert_eq!(date_to_epoch_days(date!(1970 - 01 - 01)), 0);
+    assert_eq!(date_to_epoch_days(date!(1969 - 12 - 31)), -1);
+    assert_eq!(date_to_epoch_days(date!(2000 - 03 - 01)), 11017);
+}
+
+#[test]
+fn test_epoch_days_to_date() {
+    for date in [
+        date!(1970 - 01 - 01),
+        date!(1900 - 02 - 28),
+        date!(2024 - 02 - 29),
+    ] {
+        assert_eq!(epoch_days_to_date(date_to_epoch_days(date)).unwrap(), date);
+    }
+    assert!(matches!(
+        epoch_days_to_date(i64::MAX).unwrap_err(),
+        DateTimeError::OutOfRange(_)
+    ));
+    assert!(matches!(
+        epoch_days_to_date(10_000 * 366).unwrap_err(),
+        DateTimeError::OutOfRange(_)
+    ));
+}
+
 #[test]
 fn test_parse_response_string_to_datetime() {
     let date = parse_response_string_to_datetime("2024-05-31", OffsetType::Utc).unwrap();
@@ -78,4 +223,16 @@ fn test_parse_response_string_to_datetime() {
 
     let date = parse_response_string_to_datetime("2024-Q2", OffsetType::Utc).unwrap();
     assert_eq!(date.date().month(), time::Month::April); // Q2 starts in April
+    let date = parse_response_string_to_datetime("Q3 2024", OffsetType::Utc).unwrap();
+    assert_eq!(date.date(), time::macros::date!(2024 - 07 - 01));
+    let date = parse_response_string_to_datetime("2024-S2", OffsetType::Utc).unwrap();
+    assert_eq!(date.date(), time::macros::date!(2024 - 07 - 01));
+
+    // The keywords of `parse_to_datetime` are not time periods.
+    for keyword in ["now", "today", "yesterday", "eom", "eoy"] {
+        assert!(
+            parse_response_string_to_datetime(keyword, OffsetType::Utc).is_err(),
+            "{keyword}"
+        );
+    }
 }
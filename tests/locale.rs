@@ -0,0 +1,47 @@
+#![cfg(feature = "locales")]
+
+use date_utils::{HalfYear, Locale, LocalizedPeriod, Period, Quarter};
+use time::{macros::date, Month};
+
+#[test]
+fn test_localized_period() {
+    let labels = |period: Period| {
+        [Locale::English, Locale::Italian, Locale::Japanese]
+            .map(|locale| LocalizedPeriod(period, locale).to_string())
+    };
+    assert_eq!(labels(Period::Year(2024)), ["2024", "2024", "2024年"]);
+    assert_eq!(
+        labels(Period::HalfYear(2024, 2)),
+        ["H2 2024", "2° semestre 2024", "2024年下半期"]
+    );
+    assert_eq!(
+        labels(Period::Quarter(2024, 2)),
+        ["Q2 2024", "2° trimestre 2024", "2024年第2四半期"]
+    );
+    assert_eq!(
+        labels(Period::Month(2024, Month::December)),
+        ["December 2024", "dicembre 2024", "2024年12月"]
+    );
+    assert_eq!(
+        labels(Period::Week(2024, 5)),
+        ["Week 5, 2024", "settimana 5, 2024", "2024年第5週"]
+    );
+    assert_eq!(
+        labels(Period::Day(date!(2024 - 01 - 01))),
+        ["January 1, 2024", "1 gennaio 2024", "2024年1月1日"]
+    );
+}
+
+#[test]
+fn test_localized_quarter_and_half_year() {
+    let quarter: Quarter = "Q4 2023".parse().unwrap();
+    assert_eq!(
+        LocalizedPeriod(quarter.into(), Locale::Italian).to_string(),
+        "4° trimestre 2023"
+    );
+    let half = HalfYear::new(2024, 1).unwrap();
+    assert_eq!(
+        LocalizedPeriod(half.into(), Locale::Japanese).to_string(),
+        "2024年上半期"
+    );
+}
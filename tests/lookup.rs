@@ -0,0 +1,95 @@
+use date_utils::{asof_join, nearest, Direction};
+use time::{macros::datetime, Duration};
+
+#[test]
+fn test_nearest() {
+    let series = [
+        datetime!(2024-05-01 0:00 UTC),
+        datetime!(2024-05-10 0:00 UTC),
+        datetime!(2024-05-10 0:00 UTC),
+        datetime!(2024-05-20 0:00 UTC),
+    ];
+    let at = |index: usize| Some((index, series[index]));
+
+    // Exact matches: last occurrence backward, first occurrence forward.
+    let target = datetime!(2024-05-10 2:00 +2);
+    assert_eq!(nearest(&series, target, Direction::Backward, None), at(2));
+    assert_eq!(nearest(&series, target, Direction::Forward, None), at(1));
+    assert_eq!(nearest(&series, target, Direction::Either, None), at(2));
+
+    let target = datetime!(2024-05-16 0:00 UTC);
+    assert_eq!(nearest(&series, target, Direction::Backward, None), at(2));
+    assert_eq!(nearest(&series, target, Direction::Forward, None), at(3));
+    assert_eq!(nearest(&series, target, Direction::Either, None), at(3));
+    // Ties go to the earlier datetime.
+    let target = datetime!(2024-05-15 0:00 UTC);
+    assert_eq!(nearest(&series, target, Direction::Either, None), at(2));
+
+    // Out of the series.
+    let before = datetime!(2024-04-01 0:00 UTC);
+    assert_eq!(nearest(&series, before, Direction::Backward, None), None);
+    assert_eq!(nearest(&series, before, Direction::Either, None), at(0));
+    let after = datetime!(2024-06-01 0:00 UTC);
+    assert_eq!(nearest(&series, after, Direction::Forward, None), None);
+    assert_eq!(nearest(&[], after, Direction::Either, None), None);
+
+    // Tolerance, inclusive.
+    let tolerance = Some(Duration::days(4));
+    let target = datetime!(2024-05-16 0:00 UTC);
+    assert_eq!(
+        nearest(&series, target, Direction::Backward, tolerance),
+        None
+    );
+    assert_eq!(
+        nearest(&series, target, Direction::Forward, tolerance),
+        at(3)
+    );
+    assert_eq!(
+        nearest(&series, target, Direction::Either, tolerance),
+        at(3)
+    );
+}
+
+#[test]
+fn test_asof_join() {
+    let releases = [
+        datetime!(2024-05-03 12:30 UTC),
+        datetime!(2024-06-07 12:30 UTC),
+    ];
+    let trades = [
+        datetime!(2024-06-07 12:30 UTC),
+        datetime!(2024-05-01 9:00 UTC),
+        datetime!(2024-06-07 9:00 UTC),
+    ];
+    assert_eq!(
+        asof_join(&trades, &releases, None, Direction::Backward),
+        [Some(1), None, Some(0)]
+    );
+    assert_eq!(
+        asof_join(&trades, &releases, None, Direction::Forward),
+        [Some(1), Some(0), Some(1)]
+    );
+    assert_eq!(
+        asof_join(
+            &trades,
+            &releases,
+            Some(Duration::days(7)),
+            Direction::Either
+        ),
+        [Some(1), Some(0), Some(1)]
+    );
+    assert_eq!(
+        asof_join(
+            &trades,
+            &releases,
+            Some(Duration::HOUR),
+            Direction::Backward
+        ),
+        [Some(1), None, None]
+    );
+    assert!(asof_join(&[], &releases, None, Direction::Either).is_empty());
+    assert_eq!(
+        asof_join(&trades[..1], &[], None, Direction::Either),
+        [None]
+    );
+}
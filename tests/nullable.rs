@@ -0,0 +1,44 @@
+use date_utils::{
+    is_null_sentinel, parse_optional, parse_optional_with, parse_to_datetime, DateTimeError,
+    DateType, OffsetType,
+};
+use time::macros::datetime;
+
+#[test]
+fn test_is_null_sentinel() {
+    for value in ["", "  ", "null", "NULL", "NaT", "nat", "-", " - "] {
+        assert!(is_null_sentinel(value, &[]), "{value:?}");
+    }
+    assert!(!is_null_sentinel("N/A", &[]));
+    assert!(is_null_sentinel("n/a", &["N/A"]));
+    assert!(!is_null_sentinel("2024-05-31", &["N/A"]));
+}
+
+#[test]
+fn test_parse_optional() {
+    assert_eq!(parse_optional("", OffsetType::Utc).unwrap(), None);
+    assert_eq!(parse_optional("null", OffsetType::Utc).unwrap(), None);
+    assert_eq!(
+        parse_optional(" 2024-05 ", OffsetType::Utc).unwrap(),
+        Some(datetime!(2024-05-01 0:00 UTC))
+    );
+    assert!(matches!(
+        parse_optional("yesterday-ish", OffsetType::Utc).unwrap_err(),
+        DateTimeError::ParseError(_)
+    ));
+    assert!(parse_optional("today", OffsetType::Utc).is_err());
+}
+
+#[test]
+fn test_parse_optional_with() {
+    let parse = |value: &str| parse_to_datetime(value, DateType::Start, OffsetType::Utc);
+    assert_eq!(parse_optional_with("N/A", &["N/A"], parse).unwrap(), None);
+    assert_eq!(
+        parse_optional_with("2024-05-31", &["N/A"], parse).unwrap(),
+        Some(datetime!(2024-05-31 0:00 UTC))
+    );
+    assert!(matches!(
+        parse_optional_with("N/A", &[], parse).unwrap_err(),
+        DateTimeError::InvalidDateFormat(_, _)
+    ));
+}
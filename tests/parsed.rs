@@ -0,0 +1,67 @@
+use date_utils::{DateTimeError, DetectedFormat, OffsetType, ParsedDateTime};
+use time::macros::datetime;
+
+#[test]
+fn test_parsed_datetime() {
+    for (input, datetime, format) in [
+        (
+            "2024-05-31",
+            datetime!(2024-05-31 23:59:59.999999999 UTC),
+            DetectedFormat::Date,
+        ),
+        (
+            "2024-05",
+            datetime!(2024-05-01 0:00 UTC),
+            DetectedFormat::YearMonth,
+        ),
+        (
+            "Q2 2024",
+            datetime!(2024-04-01 0:00 UTC),
+            DetectedFormat::Quarter,
+        ),
+        (
+            "2024-S2",
+            datetime!(2024-07-01 0:00 UTC),
+            DetectedFormat::HalfYear,
+        ),
+        (
+            "2024-05-31T12:00:00+02:00",
+            datetime!(2024-05-31 12:00 +2),
+            DetectedFormat::DateTime,
+        ),
+    ] {
+        let parsed = ParsedDateTime::parse(input, OffsetType::Utc).unwrap();
+        assert_eq!(parsed.datetime(), datetime, "{input}");
+        assert_eq!(parsed.format(), format, "{input}");
+        assert_eq!(parsed.original(), input);
+        assert_eq!(parsed.to_string(), input);
+    }
+    // The offset of datetimes is kept for auditing.
+    let parsed = ParsedDateTime::parse("2024-05-31 12:00:00-0330", OffsetType::Utc).unwrap();
+    assert_eq!(parsed.datetime().offset().whole_minutes(), -210);
+
+    assert!(matches!(
+        ParsedDateTime::parse("yesterday-ish", OffsetType::Utc).unwrap_err(),
+        DateTimeError::ParseError(_)
+    ));
+    for keyword in ["now", "today"] {
+        assert!(
+            ParsedDateTime::parse(keyword, OffsetType::Utc).is_err(),
+            "{keyword}"
+        );
+    }
+}
+
+#[test]
+fn test_parsed_datetime_into_owned() {
+    let mut line = String::from("2024-05");
+    let parsed = ParsedDateTime::parse(line.as_str(), OffsetType::Utc)
+        .unwrap()
+        .into_owned();
+    line.clear();
+    assert_eq!(parsed.original(), "2024-05");
+    assert_eq!(
+        ParsedDateTime::parse(String::from("2024-05"), OffsetType::Utc).unwrap(),
+        parsed
+    );
+}
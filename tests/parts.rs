@@ -0,0 +1,26 @@
+use date_utils::{decompose, DateParts};
+use time::{macros::datetime, Month, Weekday};
+
+#[test]
+fn test_decompose() {
+    assert_eq!(
+        decompose(datetime!(2021-01-03 23:59:59.5 -5)),
+        DateParts {
+            year: 2021,
+            quarter: 1,
+            month: Month::January,
+            iso_year: 2020,
+            week: 53,
+            day: 3,
+            day_of_year: 3,
+            day_of_quarter: 3,
+            weekday: Weekday::Sunday,
+            hour: 23,
+            minute: 59,
+            second: 59,
+            nanosecond: 500_000_000,
+            offset_seconds: -18_000,
+        }
+    );
+    assert_eq!(decompose(datetime!(2024-08-15 0:00 UTC)).day_of_quarter, 46);
+}
@@ -0,0 +1,60 @@
+use date_utils::{find_dates, DateTimeRange, Pattern};
+use time::{
+    macros::{date, datetime},
+    Month, Weekday,
+};
+
+#[test]
+fn test_pattern_matches() {
+    assert!(Pattern::new().matches(date!(2024 - 05 - 31)));
+
+    let friday_13th = Pattern::new().day(13).weekday(Weekday::Friday);
+    assert!(friday_13th.matches(date!(2024 - 12 - 13)));
+    assert!(!friday_13th.matches(date!(2024 - 11 - 13)));
+
+    let first_monday_of_september = Pattern::new()
+        .month(Month::September)
+        .nth_weekday(1, Weekday::Monday);
+    assert!(first_monday_of_september.matches(date!(2024 - 09 - 02)));
+    assert!(!first_monday_of_september.matches(date!(2024 - 09 - 09)));
+    assert!(!first_monday_of_september.matches(date!(2024 - 07 - 01)));
+
+    let last_monday = Pattern::new().nth_weekday(-1, Weekday::Monday);
+    assert!(last_monday.matches(date!(2024 - 05 - 27)));
+    assert!(!last_monday.matches(date!(2024 - 05 - 20)));
+    assert!(Pattern::new()
+        .nth_weekday(-2, Weekday::Monday)
+        .matches(date!(2024 - 05 - 20)));
+}
+
+#[test]
+fn test_find_dates() {
+    let range = DateTimeRange::new(
+        datetime!(2020-01-01 0:00 UTC),
+        datetime!(2028-12-31 0:00 UTC),
+    )
+    .unwrap();
+    assert_eq!(
+        find_dates(&range, &Pattern::new().month(Month::February).day(29)),
+        vec![
+            date!(2020 - 02 - 29),
+            date!(2024 - 02 - 29),
+            date!(2028 - 02 - 29)
+        ]
+    );
+
+    let range = DateTimeRange::new(
+        datetime!(2024-01-01 0:00 UTC),
+        datetime!(2024-03-31 0:00 UTC),
+    )
+    .unwrap();
+    assert_eq!(
+        find_dates(&range, &Pattern::new().nth_weekday(1, Weekday::Monday)),
+        vec![
+            date!(2024 - 01 - 01),
+            date!(2024 - 02 - 05),
+            date!(2024 - 03 - 04)
+        ]
+    );
+    assert!(find_dates(&range, &Pattern::new().day(31).month(Month::February)).is_empty());
+}
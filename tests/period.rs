@@ -0,0 +1,660 @@
+use date_utils::{
+    canonicalize_period, day_of_quarter, lag_periods, lead_periods, period_length, period_pacing,
+    periods, periods_since, quarter_progress, until_end_of, until_next, week_of_quarter,
+    CompactPeriod, DateTimeError, Feb29Policy, Frequency, HalfYear, Period, PeriodNotation,
+    Quarter, Unit,
+};
+use time::{
+    macros::{date, datetime},
+    Duration, Month,
+};
+
+#[test]
+fn test_period_length() {
+    assert_eq!(
+        period_length(Frequency::Daily, date!(2024 - 02 - 10)),
+        Duration::days(1)
+    );
+    assert_eq!(
+        period_length(Frequency::Weekly, date!(2024 - 02 - 10)),
+        Duration::days(7)
+    );
+    assert_eq!(
+        period_length(Frequency::Monthly, date!(2024 - 02 - 10)),
+        Duration::days(29)
+    );
+    assert_eq!(
+        period_length(Frequency::Monthly, date!(2023 - 02 - 10)),
+        Duration::days(28)
+    );
+    assert_eq!(
+        period_length(Frequency::Monthly, date!(2023 - 07 - 31)),
+        Duration::days(31)
+    );
+    assert_eq!(
+        period_length(Frequency::Quarterly, date!(2024 - 02 - 10)),
+        Duration::days(91)
+    );
+    assert_eq!(
+        period_length(Frequency::Quarterly, date!(2023 - 03 - 31)),
+        Duration::days(90)
+    );
+    assert_eq!(
+        period_length(Frequency::Quarterly, date!(2023 - 11 - 01)),
+        Duration::days(92)
+    );
+    assert_eq!(
+        period_length(Frequency::Annual, date!(2024 - 12 - 31)),
+        Duration::days(366)
+    );
+    assert_eq!(
+        period_length(Frequency::Annual, date!(2100 - 01 - 01)),
+        Duration::days(365)
+    );
+}
+
+#[test]
+fn test_day_of_quarter() {
+    assert_eq!(day_of_quarter(date!(2024 - 01 - 01)), 1);
+    assert_eq!(day_of_quarter(date!(2024 - 03 - 31)), 91);
+    assert_eq!(day_of_quarter(date!(2023 - 03 - 31)), 90);
+    assert_eq!(day_of_quarter(date!(2024 - 08 - 15)), 46);
+    assert_eq!(day_of_quarter(date!(2024 - 12 - 31)), 92);
+}
+
+#[test]
+fn test_week_of_quarter() {
+    assert_eq!(week_of_quarter(date!(2024 - 10 - 01)), 1);
+    assert_eq!(week_of_quarter(date!(2024 - 10 - 07)), 1);
+    assert_eq!(week_of_quarter(date!(2024 - 10 - 08)), 2);
+    assert_eq!(week_of_quarter(date!(2024 - 12 - 31)), 14);
+}
+
+#[test]
+fn test_quarter_progress() {
+    assert_eq!(quarter_progress(date!(2024 - 03 - 31)), 1.0);
+    assert_eq!(quarter_progress(date!(2024 - 07 - 01)), 1.0 / 92.0);
+    assert_eq!(quarter_progress(date!(2023 - 02 - 14)), 45.0 / 90.0);
+}
+
+#[test]
+fn test_quarter_notations() {
+    let expected = Quarter::new(2024, 2).unwrap();
+    for notation in [
+        "2024-Q2", "2024Q2", "2024 Q2", "Q2 2024", "Q2-2024", "q2/2024", "2Q24", "2Q2024", "Q2'24",
+        "Q2 24",
+    ] {
+        assert_eq!(notation.parse::<Quarter>().unwrap(), expected, "{notation}");
+    }
+    assert_eq!(expected.to_string(), "2024-Q2");
+    assert_eq!(expected.first_day(), date!(2024 - 04 - 01));
+    assert_eq!(expected.last_day(), date!(2024 - 06 - 30));
+    assert_eq!(Quarter::containing(date!(2024 - 12 - 31)).quarter(), 4);
+    assert!(matches!(
+        "2024-Q5".parse::<Quarter>().unwrap_err(),
+        DateTimeError::InvalidTimeComponent(_)
+    ));
+    for invalid in ["2024", "Q2", "2024-QX", "Q2 202", "12Q2024"] {
+        assert!(matches!(
+            invalid.parse::<Quarter>().unwrap_err(),
+            DateTimeError::InvalidDateFormat(_, _)
+        ));
+    }
+}
+
+#[test]
+fn test_half_year() {
+    let expected = HalfYear::new(2024, 1).unwrap();
+    for notation in ["2024-H1", "2024-S1", "2024h1", "H1 2024", "1H24"] {
+        assert_eq!(
+            notation.parse::<HalfYear>().unwrap(),
+            expected,
+            "{notation}"
+        );
+    }
+    assert_eq!(expected.to_string(), "2024-H1");
+    assert_eq!(HalfYear::containing(date!(2024 - 07 - 01)).half(), 2);
+    assert_eq!(expected.range().start(), datetime!(2024-01-01 0:00 UTC));
+    assert_eq!(
+        expected.range().end(),
+        datetime!(2024-06-30 23:59:59.999999999 UTC)
+    );
+    assert!(matches!(
+        "2024-H3".parse::<HalfYear>().unwrap_err(),
+        DateTimeError::InvalidTimeComponent(_)
+    ));
+    assert_eq!(
+        period_length(Frequency::Semiannual, date!(2024 - 03 - 01)),
+        Duration::days(182)
+    );
+    assert_eq!(
+        period_length(Frequency::Semiannual, date!(2024 - 09 - 01)),
+        Duration::days(184)
+    );
+}
+
+#[test]
+fn test_periods() {
+    assert_eq!(
+        periods(
+            Frequency::Quarterly,
+            date!(2024 - 02 - 10),
+            date!(2024 - 04 - 01)
+        )
+        .collect::<Vec<_>>(),
+        vec![
+            (date!(2024 - 01 - 01), date!(2024 - 03 - 31)),
+            (date!(2024 - 04 - 01), date!(2024 - 06 - 30)),
+        ]
+    );
+    assert_eq!(
+        periods(
+            Frequency::Weekly,
+            date!(2024 - 05 - 01),
+            date!(2024 - 05 - 01)
+        )
+        .collect::<Vec<_>>(),
+        vec![(date!(2024 - 04 - 29), date!(2024 - 05 - 05))]
+    );
+    assert_eq!(
+        periods(
+            Frequency::Daily,
+            date!(2024 - 05 - 02),
+            date!(2024 - 05 - 01)
+        )
+        .count(),
+        0
+    );
+    assert_eq!(
+        periods(
+            Frequency::Annual,
+            date!(9999 - 01 - 01),
+            date!(9999 - 12 - 31)
+        )
+        .count(),
+        1
+    );
+}
+
+#[test]
+fn test_until_end_of() {
+    let datetime = datetime!(2024-05-31 23:59:30.25 -3);
+    assert_eq!(
+        until_end_of(datetime, Unit::Second),
+        Duration::milliseconds(750)
+    );
+    assert_eq!(
+        until_end_of(datetime, Unit::Minute),
+        Duration::milliseconds(29_750)
+    );
+    assert_eq!(
+        until_end_of(datetime, Unit::Hour),
+        Duration::milliseconds(29_750)
+    );
+    assert_eq!(
+        until_end_of(datetime, Unit::Day),
+        Duration::milliseconds(29_750)
+    );
+    assert_eq!(
+        until_end_of(datetime!(2024-05-31 10:00 UTC), Unit::Hour),
+        Duration::HOUR
+    );
+}
+
+#[test]
+fn test_until_next() {
+    let datetime = datetime!(2024-02-28 12:00 UTC);
+    assert_eq!(
+        until_next(datetime, Frequency::Daily).unwrap(),
+        Duration::hours(12)
+    );
+    assert_eq!(
+        until_next(datetime, Frequency::Weekly).unwrap(),
+        Duration::hours(108)
+    );
+    assert_eq!(
+        until_next(datetime, Frequency::Monthly).unwrap(),
+        Duration::hours(36)
+    );
+    assert_eq!(
+        until_next(datetime, Frequency::Semiannual).unwrap(),
+        Duration::hours(36) + Duration::days(122)
+    );
+    assert!(matches!(
+        until_next(datetime!(9999-12-31 0:00 UTC), Frequency::Annual).unwrap_err(),
+        DateTimeError::OutOfRange(_)
+    ));
+}
+
+#[test]
+fn test_periods_since() {
+    let signup = datetime!(2024-01-31 10:00 +1);
+    let (elapsed, current) = periods_since(
+        signup,
+        datetime!(2024-01-31 9:00 UTC),
+        Frequency::Monthly,
+        Feb29Policy::default(),
+    )
+    .unwrap();
+    assert_eq!(elapsed, 0);
+    assert_eq!(current.start(), signup);
+
+    let (elapsed, current) = periods_since(
+        signup,
+        datetime!(2024-04-30 9:59 +1),
+        Frequency::Monthly,
+        Feb29Policy::default(),
+    )
+    .unwrap();
+    assert_eq!(elapsed, 2);
+    assert_eq!(current.start(), datetime!(2024-03-31 10:00 +1));
+    assert_eq!(current.end(), datetime!(2024-04-30 9:59:59.999999999 +1));
+
+    let (elapsed, _) = periods_since(
+        signup,
+        datetime!(2025-01-31 10:00 +1),
+        Frequency::Annual,
+        Feb29Policy::default(),
+    )
+    .unwrap();
+    assert_eq!(elapsed, 1);
+    let (elapsed, current) = periods_since(
+        signup,
+        datetime!(2024-02-14 10:00 +1),
+        Frequency::Weekly,
+        Feb29Policy::default(),
+    )
+    .unwrap();
+    assert_eq!(elapsed, 2);
+    assert_eq!(current.start(), datetime!(2024-02-14 10:00 +1));
+
+    assert!(matches!(
+        periods_since(
+            signup,
+            datetime!(2024-01-01 0:00 UTC),
+            Frequency::Daily,
+            Feb29Policy::default()
+        )
+        .unwrap_err(),
+        DateTimeError::InvalidRange(_, _)
+    ));
+
+    let leap_signup = datetime!(2024-02-29 10:00 UTC);
+    let renewal = |now, policy| {
+        periods_since(leap_signup, now, Frequency::Annual, policy).map(|(_, current)| current)
+    };
+    let now = datetime!(2025-03-01 9:00 UTC);
+    assert_eq!(
+        renewal(now, Feb29Policy::MoveToFeb28).unwrap().start(),
+        datetime!(2025-02-28 10:00 UTC)
+    );
+    assert_eq!(
+        renewal(now, Feb29Policy::MoveToMar1).unwrap().end(),
+        datetime!(2025-03-01 9:59:59.999999999 UTC)
+    );
+    assert_eq!(
+        renewal(datetime!(2028-03-01 9:00 UTC), Feb29Policy::MoveToMar1)
+            .unwrap()
+            .start(),
+        datetime!(2028-02-29 10:00 UTC)
+    );
+    assert!(matches!(
+        renewal(now, Feb29Policy::Error).unwrap_err(),
+        DateTimeError::InvalidLeapDay(2025)
+    ));
+    // Anchors on other days keep following the end of the month, whatever the policy.
+    for anchor in [
+        datetime!(2023-01-29 10:00 UTC),
+        datetime!(2023-01-30 10:00 UTC),
+        datetime!(2023-01-31 10:00 UTC),
+    ] {
+        let (_, current) = periods_since(
+            anchor,
+            datetime!(2023-02-28 12:00 UTC),
+            Frequency::Monthly,
+            Feb29Policy::Error,
+        )
+        .unwrap();
+        assert_eq!(current.start(), datetime!(2023-02-28 10:00 UTC), "{anchor}");
+    }
+    // A monthly anchor on February 29 follows the policy in February only.
+    let (_, current) = periods_since(
+        leap_signup,
+        datetime!(2025-03-15 12:00 UTC),
+        Frequency::Monthly,
+        Feb29Policy::MoveToMar1,
+    )
+    .unwrap();
+    assert_eq!(current.start(), datetime!(2025-03-01 10:00 UTC));
+    assert_eq!(current.end(), datetime!(2025-03-29 9:59:59.999999999 UTC));
+}
+
+#[test]
+fn test_period_parse() {
+    for (input, expected, canonical) in [
+        ("2024", Period::Year(2024), "2024"),
+        ("2024-A1", Period::Year(2024), "2024"),
+        ("2024-S2", Period::HalfYear(2024, 2), "2024-H2"),
+        ("1H24", Period::HalfYear(2024, 1), "2024-H1"),
+        ("2024-Q2", Period::Quarter(2024, 2), "2024-Q2"),
+        ("Q2 2024", Period::Quarter(2024, 2), "2024-Q2"),
+        ("2024-05", Period::Month(2024, Month::May), "2024-05"),
+        ("2024-m05", Period::Month(2024, Month::May), "2024-05"),
+        ("2024-W05", Period::Week(2024, 5), "2024-W05"),
+        (
+            " 2024-05-31 ",
+            Period::Day(date!(2024 - 05 - 31)),
+            "2024-05-31",
+        ),
+    ] {
+        let period: Period = input.parse().unwrap();
+        assert_eq!(period, expected, "{input}");
+        assert_eq!(period.to_string(), canonical);
+    }
+    for (input, component) in [("2024-13", "month"), ("2024-02-30", "day")] {
+        assert!(matches!(
+            input.parse::<Period>().unwrap_err(),
+            DateTimeError::InvalidComponent(name, _) if name == component
+        ));
+    }
+    assert!(matches!(
+        "2024-W53".parse::<Period>().unwrap_err(),
+        DateTimeError::InvalidTimeComponent(_)
+    ));
+    assert!(matches!(
+        "2024-Q5".parse::<Period>().unwrap_err(),
+        DateTimeError::InvalidTimeComponent(_)
+    ));
+    for invalid in ["24", "2024-5", "2024-M5", "2024-B1", "May 2024"] {
+        assert!(matches!(
+            invalid.parse::<Period>().unwrap_err(),
+            DateTimeError::InvalidDateFormat(_, _)
+        ));
+    }
+}
+
+#[test]
+fn test_period_dates() {
+    assert_eq!(
+        Period::Year(2024).dates().unwrap(),
+        (date!(2024 - 01 - 01), date!(2024 - 12 - 31))
+    );
+    assert_eq!(
+        Period::HalfYear(2024, 2).dates().unwrap(),
+        (date!(2024 - 07 - 01), date!(2024 - 12 - 31))
+    );
+    assert_eq!(
+        Period::Month(2024, Month::February).dates().unwrap(),
+        (date!(2024 - 02 - 01), date!(2024 - 02 - 29))
+    );
+    // ISO week 1 of 2025 starts in 2024.
+    assert_eq!(
+        Period::Week(2025, 1).dates().unwrap(),
+        (date!(2024 - 12 - 30), date!(2025 - 01 - 05))
+    );
+    assert_eq!(
+        Period::Day(date!(2024 - 05 - 31)).range().unwrap().end(),
+        datetime!(2024-05-31 23:59:59.999999999 UTC)
+    );
+    assert!(matches!(
+        Period::Week(9999, 52).dates().unwrap_err(),
+        DateTimeError::OutOfRange(_)
+    ));
+    assert!(matches!(
+        Period::Year(10_000).dates().unwrap_err(),
+        DateTimeError::OutOfRange(_)
+    ));
+    for period in [
+        Period::Year(i32::MAX),
+        Period::Year(i32::MIN),
+        Period::HalfYear(i32::MAX, 2),
+        Period::Quarter(i32::MIN, 1),
+        Period::Month(i32::MAX, Month::December),
+        Period::Month(i32::MIN, Month::January),
+        Period::Week(i32::MAX, 1),
+    ] {
+        assert!(
+            matches!(period.dates().unwrap_err(), DateTimeError::OutOfRange(_)),
+            "{period:?}"
+        );
+    }
+}
+
+#[test]
+fn test_period_containing() {
+    let date = date!(2024 - 12 - 31);
+    for (frequency, expected) in [
+        (Frequency::Daily, Period::Day(date)),
+        (Frequency::Weekly, Period::Week(2025, 1)),
+        (Frequency::Monthly, Period::Month(2024, Month::December)),
+        (Frequency::Quarterly, Period::Quarter(2024, 4)),
+        (Frequency::Semiannual, Period::HalfYear(2024, 2)),
+        (Frequency::Annual, Period::Year(2024)),
+    ] {
+        let period = Period::containing(frequency, date);
+        assert_eq!(period, expected);
+        assert_eq!(period.frequency(), frequency);
+        let (first, last) = period.dates().unwrap();
+        assert!((first..=last).contains(&date));
+    }
+}
+
+#[test]
+fn test_period_ord() {
+    let mut periods = vec![
+        Period::Month(2024, Month::March),
+        Period::Year(2025),
+        Period::Quarter(2024, 1),
+        Period::Month(2024, Month::January),
+        Period::Year(2024),
+    ];
+    periods.sort();
+    assert_eq!(
+        periods,
+        [
+            Period::Year(2024),
+            Period::Quarter(2024, 1),
+            Period::Month(2024, Month::January),
+            Period::Month(2024, Month::March),
+            Period::Year(2025),
+        ]
+    );
+}
+
+#[test]
+fn test_period_stable_id() {
+    assert_eq!(Period::Year(2024).stable_id().unwrap(), 2024 * 8 + 1);
+    assert_eq!(Period::Day(date!(1970 - 01 - 01)).stable_id().unwrap(), 6);
+    assert_eq!(Period::Week(1970, 2).stable_id().unwrap(), 5);
+    assert_eq!(Period::Week(1970, 1).stable_id().unwrap(), -8 + 5);
+    assert_eq!(
+        Period::Month(2024, Month::May).stable_id().unwrap(),
+        (2024 * 12 + 4) * 8 + 4
+    );
+    assert!(matches!(
+        Period::Quarter(2024, 5).stable_id().unwrap_err(),
+        DateTimeError::InvalidTimeComponent(_)
+    ));
+    for period in [
+        Period::Year(-2024),
+        Period::HalfYear(2024, 2),
+        Period::Quarter(-1, 1),
+        Period::Month(2024, Month::December),
+        Period::Week(2020, 53),
+        Period::Week(-9999, 2),
+        Period::Day(date!(-0001 - 12 - 31)),
+        Period::Day(date!(9999 - 12 - 31)),
+    ] {
+        let id = period.stable_id().unwrap();
+        assert_eq!(Period::from_stable_id(id).unwrap(), period);
+    }
+    let ids: Vec<i64> = (1..=4)
+        .map(|quarter| Period::Quarter(2024, quarter).stable_id().unwrap())
+        .collect();
+    assert!(ids.windows(2).all(|pair| pair[1] - pair[0] == 8));
+    for id in [0, 7, 15] {
+        assert!(matches!(
+            Period::from_stable_id(id).unwrap_err(),
+            DateTimeError::InvalidComponent("frequency", _)
+        ));
+    }
+    for id in [10_000 * 8 + 1, i64::MAX - 2, i64::MIN + 5] {
+        assert!(matches!(
+            Period::from_stable_id(id).unwrap_err(),
+            DateTimeError::OutOfRange(_)
+        ));
+    }
+}
+
+#[test]
+fn test_compact_period() {
+    for (period, compact) in [
+        (Period::Year(2024), "2024"),
+        (Period::HalfYear(2024, 1), "2024H1"),
+        (Period::Quarter(2024, 2), "2024Q2"),
+        (Period::Month(2024, Month::May), "202405"),
+        (Period::Week(2024, 5), "2024W05"),
+        (Period::Day(date!(2024 - 05 - 31)), "20240531"),
+    ] {
+        assert_eq!(CompactPeriod(period).to_string(), compact);
+        assert_eq!(compact.parse::<Period>().unwrap(), period);
+    }
+    assert_eq!(
+        "2024m05".parse::<Period>().unwrap(),
+        Period::Month(2024, Month::May)
+    );
+    assert!(matches!(
+        "20240230".parse::<Period>().unwrap_err(),
+        DateTimeError::InvalidComponent("day", _)
+    ));
+    for invalid in [
+        "20245",
+        "2024-0531",
+        "202405-31",
+        "2024053",
+        "20240é1",
+        "2024-0é-1",
+        "2024Mé",
+        "2024Wé",
+    ] {
+        assert!(matches!(
+            invalid.parse::<Period>().unwrap_err(),
+            DateTimeError::InvalidDateFormat(_, _)
+        ));
+    }
+}
+
+#[test]
+fn test_canonicalize_period() {
+    for (input, canonical, compact) in [
+        ("2024-S1", "2024-H1", "2024H1"),
+        ("Q2'24", "2024-Q2", "2024Q2"),
+        ("202405", "2024-05", "202405"),
+        ("2024-m05", "2024-05", "202405"),
+        ("2024-05-31", "2024-05-31", "20240531"),
+    ] {
+        assert_eq!(
+            canonicalize_period(input, PeriodNotation::Canonical).unwrap(),
+            canonical
+        );
+        assert_eq!(
+            canonicalize_period(input, PeriodNotation::Compact).unwrap(),
+            compact
+        );
+    }
+    assert!(canonicalize_period("May 2024", PeriodNotation::Canonical).is_err());
+}
+
+#[test]
+fn test_period_shift() {
+    for (period, n, expected) in [
+        (Period::Year(2024), -30, Period::Year(1994)),
+        (Period::HalfYear(2024, 1), -1, Period::HalfYear(2023, 2)),
+        (Period::Quarter(2024, 2), -4, Period::Quarter(2023, 2)),
+        (Period::Quarter(2024, 4), 1, Period::Quarter(2025, 1)),
+        (
+            Period::Month(2024, Month::January),
+            -13,
+            Period::Month(2022, Month::December),
+        ),
+        (Period::Week(2020, 52), 1, Period::Week(2020, 53)),
+        (
+            Period::Day(date!(2024 - 02 - 28)),
+            2,
+            Period::Day(date!(2024 - 03 - 01)),
+        ),
+    ] {
+        assert_eq!(period.shift(n).unwrap(), expected, "{period} {n}");
+        assert_eq!(expected.shift(-n).unwrap(), period);
+    }
+    assert!(matches!(
+        Period::Month(9999, Month::December).shift(1).unwrap_err(),
+        DateTimeError::OutOfRange(_)
+    ));
+    assert!(matches!(
+        Period::Day(date!(2024 - 01 - 01))
+            .shift(i32::MIN)
+            .unwrap_err(),
+        DateTimeError::OutOfRange(_)
+    ));
+}
+
+#[test]
+fn test_quarter_and_half_year_shift() {
+    let quarter = Quarter::new(2024, 1).unwrap();
+    assert_eq!(quarter.shift(-1).unwrap(), Quarter::new(2023, 4).unwrap());
+    assert_eq!(quarter.shift(7).unwrap(), Quarter::new(2025, 4).unwrap());
+    assert!(matches!(
+        quarter.shift(i32::MAX).unwrap_err(),
+        DateTimeError::OutOfRange(_)
+    ));
+    let half = HalfYear::new(2024, 2).unwrap();
+    assert_eq!(half.shift(1).unwrap(), HalfYear::new(2025, 1).unwrap());
+    assert_eq!(half.shift(-3).unwrap(), HalfYear::new(2023, 1).unwrap());
+}
+
+#[test]
+fn test_lag_and_lead_periods() {
+    let months = [
+        Period::Month(2024, Month::January),
+        Period::Month(2024, Month::December),
+    ];
+    assert_eq!(
+        lag_periods(&months, 1).unwrap(),
+        [
+            Period::Month(2023, Month::December),
+            Period::Month(2024, Month::November)
+        ]
+    );
+    assert_eq!(
+        lead_periods(&months, 1).unwrap(),
+        [
+            Period::Month(2024, Month::February),
+            Period::Month(2025, Month::January)
+        ]
+    );
+    assert_eq!(
+        lag_periods(&months, -1).unwrap(),
+        lead_periods(&months, 1).unwrap()
+    );
+    assert!(lag_periods(&months, i32::MIN).is_err());
+    assert!(lead_periods(&[Period::Quarter(2024, 5)], 1).is_err());
+}
+
+#[test]
+fn test_period_pacing() {
+    let pacing = period_pacing(datetime!(2024-12-31 12:00 UTC), Frequency::Annual).unwrap();
+    assert_eq!(pacing.current_period, Period::Year(2024));
+    assert_eq!(pacing.remaining, Duration::hours(12));
+    assert_eq!(pacing.elapsed_fraction, 1.0 - 0.5 / 366.0);
+
+    let pacing = period_pacing(datetime!(2024-05-27 0:00 +2), Frequency::Weekly).unwrap();
+    assert_eq!(pacing.current_period, Period::Week(2024, 22));
+    assert_eq!(pacing.elapsed_fraction, 0.0);
+    assert_eq!(pacing.remaining, Duration::weeks(1));
+
+    assert!(matches!(
+        period_pacing(datetime!(9999-12-31 0:00 UTC), Frequency::Quarterly).unwrap_err(),
+        DateTimeError::OutOfRange(_)
+    ));
+}
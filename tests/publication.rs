@@ -0,0 +1,85 @@
+#![cfg(feature = "business")]
+
+use date_utils::{
+    apply_publication_lag, latest_published_period, DateTimeError, Frequency, HalfYear,
+    HolidayCalendar, PublicationLag, Quarter, Weekend,
+};
+use time::macros::date;
+
+#[test]
+fn test_apply_publication_lag() {
+    let weekend = Weekend::default();
+    let calendar: HolidayCalendar = [date!(2024 - 05 - 27)].into_iter().collect();
+    let q1 = Quarter::new(2024, 1).unwrap().range();
+    let publish = |lag| apply_publication_lag(&q1, lag, &weekend, &calendar).unwrap();
+    // March 31 + 45 days is Wednesday May 15.
+    assert_eq!(publish(PublicationLag::Days(45)), date!(2024 - 05 - 15));
+    // March 31 + 48 days is Saturday May 18.
+    assert_eq!(publish(PublicationLag::Days(48)), date!(2024 - 05 - 20));
+    // Month ends map to month ends.
+    assert_eq!(publish(PublicationLag::Months(2)), date!(2024 - 05 - 31));
+    assert_eq!(publish(PublicationLag::Months(0)), date!(2024 - 04 - 01));
+    // May 27 is a holiday.
+    assert_eq!(
+        publish(PublicationLag::BusinessDays(41)),
+        date!(2024 - 05 - 28)
+    );
+
+    let h2 = HalfYear::new(2023, 2).unwrap().range();
+    assert_eq!(
+        apply_publication_lag(&h2, PublicationLag::Months(2), &weekend, &calendar).unwrap(),
+        date!(2024 - 02 - 29)
+    );
+    let last = Quarter::new(9999, 4).unwrap().range();
+    assert!(matches!(
+        apply_publication_lag(&last, PublicationLag::Months(1), &weekend, &calendar).unwrap_err(),
+        DateTimeError::OutOfRange(_)
+    ));
+    for months in [i32::MAX as u32, u32::MAX] {
+        assert!(matches!(
+            apply_publication_lag(&q1, PublicationLag::Months(months), &weekend, &calendar)
+                .unwrap_err(),
+            DateTimeError::OutOfRange(_)
+        ));
+    }
+}
+
+#[test]
+fn test_latest_published_period() {
+    let weekend = Weekend::default();
+    let calendar = HolidayCalendar::new();
+    let latest = |as_of, lag| {
+        latest_published_period(as_of, Frequency::Quarterly, lag, &weekend, &calendar).unwrap()
+    };
+    let q1 = (date!(2024 - 01 - 01), date!(2024 - 03 - 31));
+    let q4 = (date!(2023 - 10 - 01), date!(2023 - 12 - 31));
+    assert_eq!(latest(date!(2024 - 05 - 31), PublicationLag::Months(2)), q1);
+    assert_eq!(latest(date!(2024 - 05 - 30), PublicationLag::Months(2)), q4);
+    assert_eq!(latest(date!(2024 - 06 - 30), PublicationLag::Days(0)), q1);
+    // Quarters published long after their end are skipped until available.
+    assert_eq!(
+        latest(date!(2024 - 05 - 31), PublicationLag::Months(7)),
+        (date!(2023 - 07 - 01), date!(2023 - 09 - 30))
+    );
+    for (date, lag) in [
+        (date!(2024 - 03 - 01), PublicationLag::BusinessDays(44)),
+        (date!(2024 - 06 - 01), PublicationLag::BusinessDays(44)),
+    ] {
+        let (_, end) = latest(date, lag);
+        let published =
+            apply_publication_lag(&Quarter::containing(end).range(), lag, &weekend, &calendar)
+                .unwrap();
+        assert!(published <= date);
+    }
+    assert!(matches!(
+        latest_published_period(
+            date!(-9999 - 01 - 31),
+            Frequency::Monthly,
+            PublicationLag::Days(1),
+            &weekend,
+            &calendar
+        )
+        .unwrap_err(),
+        DateTimeError::OutOfRange(_)
+    ));
+}
@@ -0,0 +1,275 @@
+use date_utils::{
+    count_weekdays, exclusive_end, fraction_elapsed, inclusive_end, prorate, stitch_ranges,
+    truncate_range_to, weekday_distribution, Anchor, BoundKind, DateTimeError, DateTimeRange,
+    StitchError,
+};
+use time::{macros::datetime, Duration, Weekday};
+
+#[test]
+fn test_datetime_range() {
+    let range = DateTimeRange::new(
+        datetime!(2025-01-01 0:00 UTC),
+        datetime!(2025-01-31 23:59:59.999999999 UTC),
+    )
+    .unwrap();
+    assert_eq!(range.start(), datetime!(2025-01-01 0:00 UTC));
+    assert_eq!(range.end(), datetime!(2025-01-31 23:59:59.999999999 UTC));
+    assert_eq!(
+        range.duration(),
+        Duration::days(31) - Duration::nanoseconds(1)
+    );
+    assert!(range.contains(datetime!(2025-01-01 0:00 UTC)));
+    assert!(range.contains(datetime!(2025-01-31 23:59:59.999999999 UTC)));
+    assert!(!range.contains(datetime!(2025-02-01 0:00 UTC)));
+
+    let february = DateTimeRange::new(
+        datetime!(2025-02-01 0:00 UTC),
+        datetime!(2025-02-28 23:59:59.999999999 UTC),
+    )
+    .unwrap();
+    assert!(!range.overlaps(&february));
+    assert!(range.overlaps(&DateTimeRange::new(range.end(), february.end()).unwrap()));
+    assert_eq!(range.intersection(&february), None);
+    assert_eq!(
+        range.intersection(
+            &DateTimeRange::new(datetime!(2025-01-31 0:00 UTC), february.end()).unwrap()
+        ),
+        Some(DateTimeRange::new(datetime!(2025-01-31 0:00 UTC), range.end()).unwrap())
+    );
+
+    assert!(matches!(
+        DateTimeRange::new(february.start(), range.start()).unwrap_err(),
+        DateTimeError::InvalidRange(_, _)
+    ));
+}
+
+#[test]
+fn test_datetime_range_bounds() {
+    let start = datetime!(2025-01-01 0:00 UTC);
+    let january =
+        DateTimeRange::from_bounds(start, datetime!(2025-02-01 0:00 UTC), BoundKind::Exclusive)
+            .unwrap();
+    assert_eq!(
+        january,
+        DateTimeRange::from_bounds(
+            start,
+            datetime!(2025-01-31 23:59:59.999999999 UTC),
+            BoundKind::Inclusive
+        )
+        .unwrap()
+    );
+    assert!(!january.contains(datetime!(2025-02-01 0:00 UTC)));
+    assert_eq!(
+        january.end_bound(BoundKind::Inclusive).unwrap(),
+        datetime!(2025-01-31 23:59:59.999999999 UTC)
+    );
+    assert_eq!(
+        january.end_bound(BoundKind::Exclusive).unwrap(),
+        datetime!(2025-02-01 0:00 UTC)
+    );
+    assert_eq!(
+        january.duration(),
+        Duration::days(31) - Duration::nanoseconds(1)
+    );
+
+    let instant = DateTimeRange::from_bounds(start, start, BoundKind::Inclusive).unwrap();
+    assert_eq!(
+        instant.end_bound(BoundKind::Exclusive).unwrap(),
+        start + Duration::nanoseconds(1)
+    );
+    assert!(matches!(
+        DateTimeRange::from_bounds(start, start, BoundKind::Exclusive).unwrap_err(),
+        DateTimeError::InvalidRange(_, _)
+    ));
+    assert!(matches!(
+        DateTimeRange::from_bounds(january.end(), start, BoundKind::Exclusive).unwrap_err(),
+        DateTimeError::InvalidRange(_, _)
+    ));
+}
+
+#[test]
+fn test_exclusive_and_inclusive_end() {
+    let end = datetime!(2024-02-29 23:59:59.999999999 +1);
+    assert_eq!(exclusive_end(end).unwrap(), datetime!(2024-03-01 0:00 +1));
+    assert_eq!(inclusive_end(exclusive_end(end).unwrap()).unwrap(), end);
+    assert!(matches!(
+        exclusive_end(datetime!(9999-12-31 23:59:59.999999999 UTC)).unwrap_err(),
+        DateTimeError::OutOfRange(_)
+    ));
+    assert!(matches!(
+        inclusive_end(datetime!(-9999-01-01 0:00 UTC)).unwrap_err(),
+        DateTimeError::OutOfRange(_)
+    ));
+}
+
+#[test]
+fn test_count_weekdays() {
+    let q3 = DateTimeRange::new(
+        datetime!(2024-07-01 0:00 UTC),
+        datetime!(2024-09-30 23:59:59.999999999 UTC),
+    )
+    .unwrap();
+    assert_eq!(count_weekdays(&q3, Weekday::Monday), 14);
+    assert_eq!(count_weekdays(&q3, Weekday::Tuesday), 13);
+
+    let single_day = DateTimeRange::new(
+        datetime!(2024-07-01 10:00 UTC),
+        datetime!(2024-07-01 12:00 UTC),
+    )
+    .unwrap();
+    assert_eq!(count_weekdays(&single_day, Weekday::Monday), 1);
+    assert_eq!(count_weekdays(&single_day, Weekday::Sunday), 0);
+}
+
+#[test]
+fn test_weekday_distribution() {
+    let q3 = DateTimeRange::new(
+        datetime!(2024-07-01 0:00 UTC),
+        datetime!(2024-09-30 23:59:59.999999999 UTC),
+    )
+    .unwrap();
+    assert_eq!(weekday_distribution(&q3), [14, 13, 13, 13, 13, 13, 13]);
+
+    // The end is evaluated in the offset of the start: 2024-07-02 01:00 +02:00 is still 2024-07-01 UTC.
+    let range = DateTimeRange::new(
+        datetime!(2024-07-01 0:00 UTC),
+        datetime!(2024-07-02 1:00 +02:00),
+    )
+    .unwrap();
+    assert_eq!(weekday_distribution(&range), [1, 0, 0, 0, 0, 0, 0]);
+}
+
+#[test]
+fn test_fraction_elapsed() {
+    let range = DateTimeRange::new(
+        datetime!(2024-01-01 0:00 UTC),
+        datetime!(2024-01-05 0:00 UTC),
+    )
+    .unwrap();
+    assert_eq!(
+        fraction_elapsed(&range, datetime!(2023-12-31 0:00 UTC)),
+        0.0
+    );
+    assert_eq!(
+        fraction_elapsed(&range, datetime!(2024-01-01 0:00 UTC)),
+        0.0
+    );
+    assert_eq!(
+        fraction_elapsed(&range, datetime!(2024-01-04 0:00 UTC)),
+        0.75
+    );
+    assert_eq!(
+        fraction_elapsed(&range, datetime!(2024-01-05 0:00 UTC)),
+        1.0
+    );
+    assert_eq!(
+        fraction_elapsed(&range, datetime!(2024-02-01 0:00 UTC)),
+        1.0
+    );
+
+    let instant = DateTimeRange::new(range.start(), range.start()).unwrap();
+    assert_eq!(fraction_elapsed(&instant, range.start()), 1.0);
+}
+
+#[test]
+fn test_prorate() {
+    let april = DateTimeRange::new(
+        datetime!(2024-04-01 0:00 UTC),
+        datetime!(2024-05-01 0:00 UTC),
+    )
+    .unwrap();
+    let first_week = DateTimeRange::new(
+        datetime!(2024-04-01 0:00 UTC),
+        datetime!(2024-04-07 0:00 UTC),
+    )
+    .unwrap();
+    assert_eq!(prorate(300.0, &april, &first_week), 60.0);
+    assert_eq!(prorate(300.0, &april, &april), 300.0);
+
+    let may = DateTimeRange::new(
+        datetime!(2024-05-02 0:00 UTC),
+        datetime!(2024-05-31 0:00 UTC),
+    )
+    .unwrap();
+    assert_eq!(prorate(300.0, &april, &may), 0.0);
+}
+
+#[test]
+fn test_truncate_range_to() {
+    let start = datetime!(2024-01-01 0:00 UTC);
+    let end = datetime!(2024-01-31 0:00 UTC);
+    let (range, discarded) = truncate_range_to(start, end, Duration::days(7), Anchor::End).unwrap();
+    assert_eq!(
+        range,
+        DateTimeRange::new(datetime!(2024-01-24 0:00 UTC), end).unwrap()
+    );
+    assert_eq!(
+        discarded,
+        Some(DateTimeRange::new(start, datetime!(2024-01-23 23:59:59.999999999 UTC)).unwrap())
+    );
+
+    let (range, discarded) =
+        truncate_range_to(start, end, Duration::days(7), Anchor::Start).unwrap();
+    assert_eq!(range.end(), datetime!(2024-01-08 0:00 UTC));
+    assert_eq!(
+        discarded.unwrap().start(),
+        datetime!(2024-01-08 0:00:00.000000001 UTC)
+    );
+
+    // Ranges within the maximum span are kept whole.
+    let (range, discarded) =
+        truncate_range_to(start, end, Duration::days(30), Anchor::End).unwrap();
+    assert_eq!((range.start(), range.end(), discarded), (start, end, None));
+
+    assert!(matches!(
+        truncate_range_to(end, start, Duration::days(7), Anchor::End).unwrap_err(),
+        DateTimeError::InvalidRange(_, _)
+    ));
+    assert!(matches!(
+        truncate_range_to(start, end, Duration::days(-1), Anchor::End).unwrap_err(),
+        DateTimeError::InvalidComponent("max_span", _)
+    ));
+}
+
+#[test]
+fn test_stitch_ranges() {
+    let chunk = |start, end| DateTimeRange::new(start, end).unwrap();
+    let january = chunk(
+        datetime!(2024-01-01 0:00 UTC),
+        datetime!(2024-01-31 23:59:59.999999999 UTC),
+    );
+    let february = chunk(
+        datetime!(2024-02-01 0:00 UTC),
+        datetime!(2024-02-29 23:59:59.999999999 UTC),
+    );
+    let march = chunk(
+        datetime!(2024-03-01 0:00 UTC),
+        datetime!(2024-03-31 23:59:59.999999999 UTC),
+    );
+    assert_eq!(
+        stitch_ranges(vec![march, january, february], Duration::ZERO).unwrap(),
+        chunk(january.start(), march.end())
+    );
+
+    // A missing chunk and a chunk overlapping its predecessor by one hour.
+    let late_march = chunk(datetime!(2024-03-01 0:00 UTC), march.end());
+    let overlapping = chunk(
+        datetime!(2024-03-31 23:00 UTC),
+        datetime!(2024-04-30 0:00 UTC),
+    );
+    assert_eq!(
+        stitch_ranges(vec![january, late_march, overlapping], Duration::MINUTE).unwrap_err(),
+        StitchError::NotContiguous {
+            gaps: vec![chunk(february.start(), february.end())],
+            overlaps: vec![chunk(overlapping.start(), march.end())],
+        }
+    );
+    // Within the tolerance, holes and overlaps are accepted.
+    assert!(stitch_ranges(vec![january, march], Duration::days(29)).is_ok());
+    assert!(stitch_ranges(vec![march, overlapping], Duration::HOUR).is_ok());
+
+    assert_eq!(
+        stitch_ranges(Vec::new(), Duration::ZERO).unwrap_err(),
+        StitchError::Empty
+    );
+}
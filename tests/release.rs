@@ -0,0 +1,102 @@
+use date_utils::{DateTimeError, FixedClock, ReleaseDay, ReleaseSchedule};
+use time::{
+    macros::{datetime, offset, time},
+    Month, Weekday,
+};
+
+#[test]
+fn test_release_schedule_new() {
+    for (day, component) in [
+        (ReleaseDay::NthWeekday(0, Weekday::Friday), "occurrence"),
+        (ReleaseDay::NthWeekday(6, Weekday::Friday), "occurrence"),
+        (ReleaseDay::DayOfMonth(32), "day"),
+    ] {
+        assert!(matches!(
+            ReleaseSchedule::new(day, time!(8:30), offset!(UTC)).unwrap_err(),
+            DateTimeError::InvalidComponent(name, _) if name == component
+        ));
+    }
+}
+
+#[test]
+fn test_release_in() {
+    let schedule = |day| ReleaseSchedule::new(day, time!(8:30), offset!(-4)).unwrap();
+    assert_eq!(
+        schedule(ReleaseDay::NthWeekday(1, Weekday::Friday)).release_in(2024, Month::June),
+        Some(datetime!(2024-06-07 8:30 -4))
+    );
+    assert_eq!(
+        schedule(ReleaseDay::NthWeekday(5, Weekday::Friday)).release_in(2024, Month::June),
+        None
+    );
+    assert_eq!(
+        schedule(ReleaseDay::LastWeekday(Weekday::Sunday)).release_in(2024, Month::June),
+        Some(datetime!(2024-06-30 8:30 -4))
+    );
+    assert_eq!(
+        schedule(ReleaseDay::DayOfMonth(31)).release_in(2024, Month::June),
+        None
+    );
+}
+
+#[test]
+fn test_next_release() {
+    let schedule = ReleaseSchedule::new(
+        ReleaseDay::NthWeekday(2, Weekday::Friday),
+        time!(8:30),
+        offset!(-4),
+    )
+    .unwrap();
+    // The release instant itself is not after itself.
+    assert_eq!(
+        schedule.next_release(datetime!(2024-06-14 12:30 UTC)),
+        Some(datetime!(2024-07-12 8:30 -4))
+    );
+    assert_eq!(
+        schedule.next_release(datetime!(2024-06-14 12:29 UTC)),
+        Some(datetime!(2024-06-14 8:30 -4))
+    );
+    // Fifth weekdays skip months.
+    let fifth = ReleaseSchedule::new(
+        ReleaseDay::NthWeekday(5, Weekday::Friday),
+        time!(8:30),
+        offset!(UTC),
+    )
+    .unwrap();
+    assert_eq!(
+        fifth.next_release(datetime!(2024-05-31 9:00 UTC)),
+        Some(datetime!(2024-08-30 8:30 UTC))
+    );
+    assert_eq!(fifth.next_release(datetime!(9999-12-31 9:00 UTC)), None);
+    let eastern =
+        ReleaseSchedule::new(ReleaseDay::DayOfMonth(1), time!(8:30), offset!(+2)).unwrap();
+    assert_eq!(eastern.next_release(datetime!(9999-12-31 23:00 UTC)), None);
+
+    let clock = FixedClock::new(datetime!(2024-06-01 0:00 UTC));
+    assert_eq!(
+        schedule.next_release_at(&clock),
+        Some(datetime!(2024-06-14 8:30 -4))
+    );
+}
+
+#[test]
+fn test_releases() {
+    let schedule =
+        ReleaseSchedule::new(ReleaseDay::DayOfMonth(31), time!(0:00), offset!(UTC)).unwrap();
+    let releases: Vec<_> = schedule
+        .releases(datetime!(2024-01-31 0:00 UTC))
+        .take(3)
+        .collect();
+    assert_eq!(
+        releases,
+        [
+            datetime!(2024-03-31 0:00 UTC),
+            datetime!(2024-05-31 0:00 UTC),
+            datetime!(2024-07-31 0:00 UTC)
+        ]
+    );
+    assert_eq!(
+        schedule.releases(datetime!(9999-12-31 0:00 UTC)).next(),
+        None
+    );
+}
@@ -0,0 +1,70 @@
+use date_utils::{DateTimeError, FixedClock, RetentionPolicy};
+use time::{macros::datetime, Duration};
+
+#[test]
+fn test_retention_policy_expires_at() {
+    let policy = RetentionPolicy::new(Duration::days(7)).unwrap();
+    assert_eq!(policy.max_age(), Duration::days(7));
+    assert_eq!(
+        policy.expires_at(datetime!(2024-05-31 12:00 +2)).unwrap(),
+        Some(datetime!(2024-06-07 12:00 +2))
+    );
+    let grandfathered = policy
+        .clone()
+        .grandfather_before(datetime!(2024-01-01 0:00 UTC));
+    assert_eq!(
+        grandfathered
+            .expires_at(datetime!(2023-12-31 23:59 UTC))
+            .unwrap(),
+        None
+    );
+    assert_eq!(
+        grandfathered
+            .expires_at(datetime!(2024-01-01 0:00 UTC))
+            .unwrap(),
+        Some(datetime!(2024-01-08 0:00 UTC))
+    );
+    assert!(matches!(
+        policy
+            .expires_at(datetime!(9999-12-30 0:00 UTC))
+            .unwrap_err(),
+        DateTimeError::OutOfRange(_)
+    ));
+    assert!(matches!(
+        RetentionPolicy::new(Duration::days(-1)).unwrap_err(),
+        DateTimeError::InvalidComponent("max_age", _)
+    ));
+}
+
+#[cfg(feature = "business")]
+#[test]
+fn test_retention_policy_business_days() {
+    use date_utils::{HolidayCalendar, Weekend};
+    use time::macros::date;
+
+    let calendar: HolidayCalendar = [date!(2024 - 06 - 03)].into_iter().collect();
+    let policy = RetentionPolicy::new(Duration::days(2) + Duration::hours(6))
+        .unwrap()
+        .business_days(Weekend::default(), calendar);
+    // Friday + 2 business days, skipping the weekend and Monday's holiday.
+    assert_eq!(
+        policy.expires_at(datetime!(2024-05-31 12:00 UTC)).unwrap(),
+        Some(datetime!(2024-06-05 18:00 UTC))
+    );
+}
+
+#[test]
+fn test_retention_policy_is_expired() {
+    let policy = RetentionPolicy::new(Duration::hours(1)).unwrap();
+    let clock = FixedClock::new(datetime!(2024-05-31 12:00 UTC));
+    assert!(policy
+        .is_expired(datetime!(2024-05-31 11:00 UTC), &clock)
+        .unwrap());
+    assert!(!policy
+        .is_expired(datetime!(2024-05-31 11:00:01 UTC), &clock)
+        .unwrap());
+    let policy = policy.grandfather_before(datetime!(2024-05-01 0:00 UTC));
+    assert!(!policy
+        .is_expired(datetime!(2024-04-30 0:00 UTC), &clock)
+        .unwrap());
+}
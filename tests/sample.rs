@@ -0,0 +1,81 @@
+#![cfg(feature = "rand")]
+
+use date_utils::{
+    sample_dates, sample_dates_stratified, DateTimeError, DateTimeRange, Rng, Strata,
+};
+use time::{macros::datetime, Month};
+
+fn year_2024() -> DateTimeRange {
+    DateTimeRange::new(
+        datetime!(2024-01-01 0:00 UTC),
+        datetime!(2024-12-31 23:59:59.999999999 UTC),
+    )
+    .unwrap()
+}
+
+#[test]
+fn test_rng() {
+    let mut rng = Rng::new(0);
+    let first: Vec<u64> = (0..3).map(|_| rng.next_u64()).collect();
+    let mut same = Rng::new(0);
+    assert_eq!(first, (0..3).map(|_| same.next_u64()).collect::<Vec<_>>());
+    assert_ne!(first[0], Rng::new(1).next_u64());
+}
+
+#[test]
+fn test_sample_dates() {
+    let range = DateTimeRange::new(
+        datetime!(2024-05-30 12:00 UTC),
+        datetime!(2024-06-02 0:00 UTC),
+    )
+    .unwrap();
+    let dates = sample_dates(&mut Rng::new(3), &range, 100);
+    assert_eq!(dates.len(), 100);
+    assert!(dates.is_sorted());
+    assert_eq!(dates[0], datetime!(2024-05-30 0:00 UTC).date());
+    assert_eq!(dates[99], datetime!(2024-06-02 0:00 UTC).date());
+    assert!(sample_dates(&mut Rng::new(3), &range, 0).is_empty());
+}
+
+#[test]
+fn test_sample_dates_stratified() {
+    let production = [30.0, 20.0, 20.0, 15.0, 10.0, 5.0, 0.0];
+    let dates = sample_dates_stratified(
+        &mut Rng::new(11),
+        &year_2024(),
+        &Strata::Weekday(production),
+        100,
+    )
+    .unwrap();
+    let mut counts = [0; 7];
+    for date in &dates {
+        counts[date.weekday().number_days_from_monday() as usize] += 1;
+    }
+    assert_eq!(counts, [30, 20, 20, 15, 10, 5, 0]);
+
+    // Months the range does not cover are dropped before allocating the sample.
+    let summer = DateTimeRange::new(
+        datetime!(2024-06-01 0:00 UTC),
+        datetime!(2024-07-31 0:00 UTC),
+    )
+    .unwrap();
+    let dates =
+        sample_dates_stratified(&mut Rng::new(11), &summer, &Strata::Month([1.0; 12]), 7).unwrap();
+    let june = dates
+        .iter()
+        .filter(|date| date.month() == Month::June)
+        .count();
+    assert_eq!((dates.len(), june), (7, 4));
+
+    assert!(matches!(
+        sample_dates_stratified(&mut Rng::new(11), &summer, &Strata::Month([-1.0; 12]), 7)
+            .unwrap_err(),
+        DateTimeError::InvalidComponent("weight", _)
+    ));
+    let mut winter = [0.0; 12];
+    winter[0] = 1.0;
+    assert!(matches!(
+        sample_dates_stratified(&mut Rng::new(11), &summer, &Strata::Month(winter), 7).unwrap_err(),
+        DateTimeError::InvalidComponent("weight", _)
+    ));
+}
@@ -0,0 +1,82 @@
+#![cfg(feature = "parsing")]
+
+use date_utils::{DateTimeError, LogFormat, TimestampScanner};
+use std::io::{self, BufRead, BufReader, Read};
+use time::{macros::datetime, OffsetDateTime};
+
+fn scan(log: &str, format: LogFormat) -> Vec<(usize, OffsetDateTime)> {
+    TimestampScanner::new(log.as_bytes(), format)
+        .collect::<Result<_, _>>()
+        .unwrap()
+}
+
+#[test]
+fn test_scanner_rfc3339() {
+    let log = "2024-05-31T12:00:00Z start\n\
+               2024-05-31T14:00:00.123456789123+02:00 nanos\n\
+               2024-05-31 12:00:00 wrong separator\n\
+               2024-05-31T12:00:00 missing offset\n\
+               2024-02-30T12:00:00Z invalid date\n\
+               2024-05-31t12:00:01-01:30";
+    assert_eq!(
+        scan(log, LogFormat::Rfc3339),
+        vec![
+            (1, datetime!(2024-05-31 12:00 UTC)),
+            (2, datetime!(2024-05-31 14:00:00.123456789 +2)),
+        ]
+    );
+}
+
+#[test]
+fn test_scanner_iso8601_space() {
+    let log = "2024-05-31 12:00:00,INFO\n\
+               \tat handler\n\
+               2024-05-31 12:00:00.25+01:00 WARN\n";
+    assert_eq!(
+        scan(log, LogFormat::Iso8601Space),
+        vec![
+            (1, datetime!(2024-05-31 12:00 UTC)),
+            (3, datetime!(2024-05-31 12:00:00.25 +1)),
+        ]
+    );
+}
+
+#[test]
+fn test_scanner_common_log() {
+    let log = "127.0.0.1 - - [10/Oct/2000:13:55:36 -0700] \"GET / HTTP/1.0\" 200\n\
+               127.0.0.1 - - [10/Foo/2000:13:55:36 -0700] \"GET / HTTP/1.0\" 200\n";
+    assert_eq!(
+        scan(log, LogFormat::CommonLog),
+        vec![(1, datetime!(2000-10-10 13:55:36 -7))]
+    );
+}
+
+#[test]
+fn test_scanner_unix_seconds() {
+    let log = "1717156800 a\nfoo\n1717156800.5 b\n";
+    assert_eq!(
+        scan(log, LogFormat::UnixSeconds),
+        vec![
+            (1, datetime!(2024-05-31 12:00 UTC)),
+            (3, datetime!(2024-05-31 12:00:00.5 UTC)),
+        ]
+    );
+}
+
+struct FailingReader;
+
+impl Read for FailingReader {
+    fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+        Err(io::Error::other("disk unplugged"))
+    }
+}
+
+#[test]
+fn test_scanner_io_error() {
+    let reader: Box<dyn BufRead> = Box::new(BufReader::new(FailingReader));
+    let mut scanner = TimestampScanner::new(reader, LogFormat::Rfc3339);
+    assert!(matches!(
+        scanner.next().unwrap().unwrap_err(),
+        DateTimeError::Io(_)
+    ));
+}
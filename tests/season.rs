@@ -0,0 +1,91 @@
+use date_utils::{season_of, solar_event, Hemisphere, Season, SeasonRule, SolarEvent};
+use time::{
+    macros::{date, datetime},
+    Duration, Month,
+};
+
+#[test]
+fn test_season_of_meteorological() {
+    let season = |date| season_of(date, Hemisphere::Northern, SeasonRule::Meteorological);
+    assert_eq!(season(date!(2024 - 02 - 29)), Season::Winter);
+    assert_eq!(season(date!(2024 - 03 - 01)), Season::Spring);
+    assert_eq!(season(date!(2024 - 06 - 01)), Season::Summer);
+    assert_eq!(season(date!(2024 - 11 - 30)), Season::Autumn);
+    assert_eq!(season(date!(2024 - 12 - 01)), Season::Winter);
+    assert_eq!(
+        season_of(
+            date!(2024 - 12 - 01),
+            Hemisphere::Southern,
+            SeasonRule::Meteorological
+        ),
+        Season::Summer
+    );
+}
+
+#[test]
+fn test_season_of_astronomical() {
+    let season = |date| season_of(date, Hemisphere::Northern, SeasonRule::Astronomical);
+    assert_eq!(season(date!(2024 - 03 - 19)), Season::Winter);
+    assert_eq!(season(date!(2024 - 03 - 20)), Season::Spring);
+    // The June solstice falls on June 20 in 2024 and on June 21 in 2023.
+    assert_eq!(season(date!(2024 - 06 - 19)), Season::Spring);
+    assert_eq!(season(date!(2024 - 06 - 20)), Season::Summer);
+    assert_eq!(season(date!(2023 - 06 - 20)), Season::Spring);
+    assert_eq!(season(date!(2023 - 06 - 21)), Season::Summer);
+    assert_eq!(season(date!(2024 - 09 - 22)), Season::Autumn);
+    assert_eq!(season(date!(2024 - 12 - 20)), Season::Autumn);
+    assert_eq!(season(date!(2024 - 12 - 21)), Season::Winter);
+    assert_eq!(
+        season_of(
+            date!(2024 - 07 - 15),
+            Hemisphere::Southern,
+            SeasonRule::Astronomical
+        ),
+        Season::Winter
+    );
+}
+
+#[test]
+fn test_solar_event() {
+    // Published instants, to within a few minutes.
+    for (year, event, expected) in [
+        (
+            2024,
+            SolarEvent::MarchEquinox,
+            datetime!(2024-03-20 3:06 UTC),
+        ),
+        (
+            2024,
+            SolarEvent::JuneSolstice,
+            datetime!(2024-06-20 20:51 UTC),
+        ),
+        (
+            2024,
+            SolarEvent::SeptemberEquinox,
+            datetime!(2024-09-22 12:44 UTC),
+        ),
+        (
+            2024,
+            SolarEvent::DecemberSolstice,
+            datetime!(2024-12-21 9:20 UTC),
+        ),
+        (
+            2000,
+            SolarEvent::MarchEquinox,
+            datetime!(2000-03-20 7:35 UTC),
+        ),
+        (
+            2000,
+            SolarEvent::DecemberSolstice,
+            datetime!(2000-12-21 13:37 UTC),
+        ),
+    ] {
+        let instant = solar_event(year, event).unwrap();
+        assert!((instant - expected).whole_minutes().abs() <= 5, "{instant}");
+    }
+    // Both series of mean events agree around year 1000: the equinox moves by about 6 hours a year.
+    let before = solar_event(999, SolarEvent::MarchEquinox).unwrap();
+    let after = solar_event(1000, SolarEvent::MarchEquinox).unwrap();
+    assert_eq!((before.month(), before.day()), (Month::March, 20));
+    assert_eq!((after - before - Duration::days(365)).whole_hours(), 5);
+}
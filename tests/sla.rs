@@ -0,0 +1,38 @@
+#![cfg(feature = "business")]
+
+use date_utils::{BusinessHours, HolidayCalendar, Sla, Weekend};
+use time::{
+    macros::{date, datetime, offset, time},
+    Duration,
+};
+
+fn sla() -> Sla {
+    let hours = BusinessHours::new(time!(9:00), time!(17:00), offset!(UTC)).unwrap();
+    let calendar: HolidayCalendar = [date!(2025 - 05 - 12)].into_iter().collect();
+    Sla::new(hours, Weekend::default(), calendar, Duration::hours(4))
+}
+
+#[test]
+fn test_sla() {
+    let sla = sla();
+    let opened_at = datetime!(2025-05-09 15:00 UTC);
+    assert_eq!(sla.target(), Duration::hours(4));
+    assert_eq!(
+        sla.deadline(opened_at).unwrap(),
+        datetime!(2025-05-13 11:00 UTC)
+    );
+    assert_eq!(
+        sla.elapsed(opened_at, datetime!(2025-05-13 10:00 UTC)),
+        Duration::hours(3)
+    );
+    assert_eq!(
+        sla.remaining(opened_at, datetime!(2025-05-13 10:00 UTC)),
+        Duration::hours(1)
+    );
+    assert!(!sla.breached(opened_at, datetime!(2025-05-13 11:00 UTC)));
+    assert!(sla.breached(opened_at, datetime!(2025-05-13 11:01 UTC)));
+    assert_eq!(
+        sla.remaining(opened_at, datetime!(2025-05-14 9:00 UTC)),
+        Duration::ZERO
+    );
+}
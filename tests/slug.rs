@@ -0,0 +1,73 @@
+use date_utils::{parse_timestamp_slug, timestamp_slug, DateTimeError, Resolution};
+use time::macros::datetime;
+
+#[test]
+fn test_timestamp_slug() {
+    let datetime = datetime!(2024-06-01 1:30:05.25 +2);
+    for (resolution, expected) in [
+        (Resolution::Day, "20240531"),
+        (Resolution::Minute, "20240531T2330Z"),
+        (Resolution::Second, "20240531T233005Z"),
+        (Resolution::Millisecond, "20240531T233005250Z"),
+    ] {
+        assert_eq!(timestamp_slug(datetime, resolution).unwrap(), expected);
+    }
+    assert!(matches!(
+        timestamp_slug(datetime!(-0001-12-31 0:00 UTC), Resolution::Day).unwrap_err(),
+        DateTimeError::InvalidComponent("year", _)
+    ));
+    assert!(matches!(
+        timestamp_slug(datetime!(9999-12-31 23:00 -01:00), Resolution::Day).unwrap_err(),
+        DateTimeError::InvalidComponent("year", message) if message.starts_with("10000 ")
+    ));
+}
+
+#[test]
+fn test_parse_timestamp_slug() {
+    for (name, expected, resolution) in [
+        (
+            "20240531.csv",
+            datetime!(2024-05-31 0:00 UTC),
+            Resolution::Day,
+        ),
+        (
+            "backup-20240531T233005Z",
+            datetime!(2024-05-31 23:30:05 UTC),
+            Resolution::Second,
+        ),
+        (
+            "run_20240531t233005250z_v2.log",
+            datetime!(2024-05-31 23:30:05.25 UTC),
+            Resolution::Millisecond,
+        ),
+        // Invalid candidates are skipped.
+        (
+            "id123456789_20241340_20240531T2330Z",
+            datetime!(2024-05-31 23:30 UTC),
+            Resolution::Minute,
+        ),
+    ] {
+        assert_eq!(
+            parse_timestamp_slug(name).unwrap(),
+            (expected, resolution),
+            "{name}"
+        );
+    }
+    let datetime = datetime!(2024-05-31 12:00:00.999 UTC);
+    for resolution in [Resolution::Second, Resolution::Millisecond] {
+        let slug = timestamp_slug(datetime, resolution).unwrap();
+        assert_eq!(parse_timestamp_slug(&slug).unwrap().1, resolution);
+    }
+    for invalid in [
+        "report.csv",
+        "2024053112",
+        "20240531T12Z",
+        "20240531T1230",
+        "20241301",
+    ] {
+        assert!(matches!(
+            parse_timestamp_slug(invalid).unwrap_err(),
+            DateTimeError::InvalidDateTimeFormat(_)
+        ));
+    }
+}
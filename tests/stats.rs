@@ -0,0 +1,194 @@
+use date_utils::{
+    bucket_by_time_of_day, estimate_offset, histogram, mean_datetime, series_stats,
+    weighted_mean_datetime, DateTimeError, Dimension,
+};
+use time::{
+    macros::{datetime, offset, time},
+    Duration,
+};
+
+#[test]
+fn test_series_stats() {
+    let stats = series_stats(&[
+        datetime!(2025-01-04 0:00 UTC),
+        datetime!(2025-01-01 0:00 UTC),
+        datetime!(2025-01-02 0:00 UTC),
+        datetime!(2025-01-02 2:00 +02:00),
+        datetime!(2025-01-02 0:00 UTC),
+    ])
+    .unwrap();
+    assert_eq!(stats.min, datetime!(2025-01-01 0:00 UTC));
+    assert_eq!(stats.max, datetime!(2025-01-04 0:00 UTC));
+    assert_eq!(stats.span, Duration::days(3));
+    assert_eq!(stats.median_spacing, Duration::hours(12));
+    assert_eq!(stats.mean_spacing, Duration::hours(18));
+    assert_eq!(stats.duplicates, 2);
+
+    let stats = series_stats(&[datetime!(2025-01-01 0:00 UTC)]).unwrap();
+    assert_eq!(stats.span, Duration::ZERO);
+    assert_eq!(stats.mean_spacing, Duration::ZERO);
+
+    assert!(matches!(
+        series_stats(&[]).unwrap_err(),
+        DateTimeError::EmptySeries
+    ));
+}
+
+#[test]
+fn test_histogram() {
+    let datetimes = [
+        datetime!(2025-05-12 9:00 UTC),
+        datetime!(2025-05-12 23:30 -02:00),
+        datetime!(2025-05-18 9:15 UTC),
+        datetime!(2025-12-31 0:00 UTC),
+    ];
+    assert_eq!(
+        histogram(&datetimes, Dimension::Weekday),
+        vec![2, 0, 1, 0, 0, 0, 1]
+    );
+
+    let hours = histogram(&datetimes, Dimension::HourOfDay);
+    assert_eq!(hours.len(), 24);
+    assert_eq!(hours[0], 1);
+    assert_eq!(hours[9], 2);
+    assert_eq!(hours[23], 1);
+
+    let months = histogram(&datetimes, Dimension::MonthOfYear);
+    assert_eq!(months[4], 3);
+    assert_eq!(months[11], 1);
+    assert!(histogram(&[], Dimension::MonthOfYear)
+        .iter()
+        .all(|count| *count == 0));
+}
+
+#[test]
+fn test_mean_datetime() {
+    let mean = mean_datetime(&[
+        datetime!(2025-01-03 0:00 +1),
+        datetime!(2025-01-01 0:00 UTC),
+        datetime!(2025-01-02 0:00 UTC),
+    ])
+    .unwrap();
+    assert_eq!(mean, datetime!(2025-01-02 0:40 +1));
+    assert_eq!(mean.offset(), datetime!(2025-01-03 0:00 +1).offset());
+
+    // Extreme datetimes do not overflow.
+    let extremes = [
+        datetime!(-9999-01-01 0:00 UTC),
+        datetime!(9999-12-31 23:59:59.999999999 UTC),
+    ];
+    assert_eq!(
+        mean_datetime(&extremes).unwrap().date(),
+        datetime!(0000-07-01 0:00 UTC).date()
+    );
+    assert!(matches!(
+        mean_datetime(&[]).unwrap_err(),
+        DateTimeError::EmptySeries
+    ));
+}
+
+#[test]
+fn test_weighted_mean_datetime() {
+    // Cash flows of 5, 5 and 105 yearly: 682.4 days after the first one.
+    let flows = [
+        (datetime!(2026-01-01 0:00 UTC), 5.0),
+        (datetime!(2027-01-01 0:00 UTC), 5.0),
+        (datetime!(2028-01-01 0:00 UTC), 105.0),
+    ];
+    let mean = weighted_mean_datetime(&flows).unwrap();
+    assert_eq!(mean.date(), datetime!(2027-11-14 0:00 UTC).date());
+    assert_eq!(
+        weighted_mean_datetime(&[
+            (datetime!(2025-01-01 0:00 UTC), 0.0),
+            (datetime!(2025-01-02 0:00 UTC), 2.0)
+        ])
+        .unwrap(),
+        datetime!(2025-01-02 0:00 UTC)
+    );
+
+    assert!(matches!(
+        weighted_mean_datetime(&[]).unwrap_err(),
+        DateTimeError::EmptySeries
+    ));
+    for weight in [-1.0, f64::NAN, 0.0] {
+        assert!(matches!(
+            weighted_mean_datetime(&[(datetime!(2025-01-01 0:00 UTC), weight)]).unwrap_err(),
+            DateTimeError::InvalidComponent("weight", _)
+        ));
+    }
+}
+
+#[test]
+fn test_bucket_by_time_of_day() {
+    let buckets = [
+        (time!(22:00), time!(6:00)),
+        (time!(6:00), time!(12:00)),
+        (time!(12:00), time!(18:00)),
+    ];
+    let events = [
+        datetime!(2024-05-31 23:30 UTC),
+        datetime!(2024-05-31 4:00 UTC),
+        datetime!(2024-05-31 6:00 UTC),
+        datetime!(2024-05-31 11:59:59 UTC),
+        datetime!(2024-05-31 18:00 UTC),
+        datetime!(2024-05-31 12:00 +3),
+    ];
+    assert_eq!(
+        bucket_by_time_of_day(&events, &buckets, offset!(UTC)).unwrap(),
+        [Some(0), Some(0), Some(1), Some(1), None, Some(1)]
+    );
+    // The same instants seen from New York.
+    assert_eq!(
+        bucket_by_time_of_day(&events, &buckets, offset!(-4)).unwrap(),
+        [None, Some(0), Some(0), Some(1), Some(2), Some(0)]
+    );
+    // A bucket starting and ending at the same time covers the whole day.
+    assert_eq!(
+        bucket_by_time_of_day(&events[..1], &[(time!(9:00), time!(9:00))], offset!(UTC)).unwrap(),
+        [Some(0)]
+    );
+    assert!(matches!(
+        bucket_by_time_of_day(&[datetime!(9999-12-31 23:00 UTC)], &buckets, offset!(+2))
+            .unwrap_err(),
+        DateTimeError::OutOfRange(_)
+    ));
+}
+
+#[test]
+fn test_estimate_offset() {
+    let start = datetime!(2024-05-31 9:00 UTC);
+    let series_a: Vec<_> = (0..9).map(|i| start + Duration::seconds(i)).collect();
+    let mut series_b: Vec<_> = series_a
+        .iter()
+        .enumerate()
+        .map(|(i, datetime)| {
+            (*datetime + Duration::milliseconds(-250 + i as i64 % 3)).to_offset(offset!(+2))
+        })
+        .collect();
+    assert_eq!(
+        estimate_offset(&series_a, &series_b).unwrap(),
+        Duration::milliseconds(-249)
+    );
+    series_b[2] += Duration::minutes(5);
+    series_b[7] -= Duration::hours(1);
+    assert_eq!(
+        estimate_offset(&series_a, &series_b).unwrap(),
+        Duration::milliseconds(-249)
+    );
+    assert_eq!(
+        estimate_offset(&series_b, &series_a).unwrap(),
+        Duration::milliseconds(249)
+    );
+    assert_eq!(
+        estimate_offset(&series_a[..2], &series_b[..2]).unwrap(),
+        Duration::microseconds(-249_500)
+    );
+    assert!(matches!(
+        estimate_offset(&[], &[]).unwrap_err(),
+        DateTimeError::EmptySeries
+    ));
+    assert!(matches!(
+        estimate_offset(&series_a, &series_b[1..]).unwrap_err(),
+        DateTimeError::InvalidComponent("series_b", _)
+    ));
+}
@@ -0,0 +1,121 @@
+#![cfg(feature = "parsing")]
+
+use date_utils::{
+    parse_range, parse_range_with, parse_textual_date, BoundKind, DateTimeError, DateTimeRange,
+};
+use time::macros::{date, datetime};
+
+#[test]
+fn test_parse_textual_date() {
+    for input in [
+        "May 31st, 2024",
+        "May 31, 2024",
+        "may 31 2024",
+        "Friday, May 31st, 2024",
+        "Fri May 31st 2024",
+        "31st May 2024",
+        "31 May, 2024",
+        "the 31st of May 2024",
+        "31st of MAY 2024",
+    ] {
+        assert_eq!(
+            parse_textual_date(input).unwrap(),
+            date!(2024 - 05 - 31),
+            "{input}"
+        );
+    }
+    assert!(matches!(
+        parse_textual_date("February 30th, 2024").unwrap_err(),
+        DateTimeError::InvalidComponent("day", _)
+    ));
+    for invalid in [
+        "2024-05-31",
+        "May 2024",
+        "31st 2024 May",
+        "Mayday 31, 2024",
+        "May 31st, 24x",
+    ] {
+        assert!(matches!(
+            parse_textual_date(invalid).unwrap_err(),
+            DateTimeError::InvalidDateFormat(_, _)
+        ));
+    }
+}
+
+#[test]
+fn test_parse_range() {
+    let q1 = DateTimeRange::new(
+        datetime!(2024-01-01 0:00 UTC),
+        datetime!(2024-03-31 23:59:59.999999999 UTC),
+    )
+    .unwrap();
+    for input in [
+        "2024-01-01..2024-03-31",
+        "2024-01-01/2024-03-31",
+        "2024-01-01 - 2024-03-31",
+        "2024-01 to 2024-03",
+        "Jan 2024 to Mar 2024",
+        "January 2024 TO march 2024",
+        "2024-Q1..2024-Q1",
+        "01/01/2024 - 31/03/2024",
+    ] {
+        assert_eq!(parse_range(input).unwrap(), q1, "{input}");
+    }
+    assert_eq!(
+        parse_range("2024 - 2025").unwrap().end(),
+        datetime!(2025-12-31 23:59:59.999999999 UTC)
+    );
+    // Only the day/month/year reading yields a valid range.
+    assert_eq!(
+        parse_range("03/02/2024 - 01/03/2024").unwrap().end(),
+        datetime!(2024-03-01 23:59:59.999999999 UTC)
+    );
+    // The start is only valid as day/month/year, so the end is read the same way.
+    assert_eq!(
+        parse_range("13/01/2024 - 01/02/2024").unwrap().end(),
+        datetime!(2024-02-01 23:59:59.999999999 UTC)
+    );
+    assert!(matches!(
+        parse_range("01/02/2024 - 03/04/2024").unwrap_err(),
+        DateTimeError::AmbiguousRange(_, _)
+    ));
+    assert!(matches!(
+        parse_range("2024-03-31..2024-01-01").unwrap_err(),
+        DateTimeError::InvalidRange(_, _)
+    ));
+    assert!(matches!(
+        parse_range("2024-01-01").unwrap_err(),
+        DateTimeError::InvalidDateFormat(_, _)
+    ));
+}
+
+#[test]
+fn test_parse_range_with() {
+    let q1 = parse_range("2024-Q1..2024-Q1").unwrap();
+    for input in [
+        "2024-01-01..2024-04-01",
+        "Jan 2024 to Apr 2024",
+        "2024-Q1..2024-Q2",
+        "2024-H1 to 2024-Q2",
+    ] {
+        assert_eq!(
+            parse_range_with(input, BoundKind::Exclusive).unwrap(),
+            q1,
+            "{input}"
+        );
+    }
+    assert_eq!(
+        parse_range_with("2024-01-01..2024-03-31", BoundKind::Inclusive).unwrap(),
+        q1
+    );
+    assert_eq!(
+        parse_range_with("2024-01-01..2024-01-02", BoundKind::Exclusive)
+            .unwrap()
+            .end(),
+        datetime!(2024-01-01 23:59:59.999999999 UTC)
+    );
+    assert!(matches!(
+        parse_range_with("2024-01-01..2024-01-01", BoundKind::Exclusive).unwrap_err(),
+        DateTimeError::InvalidRange(_, _)
+    ));
+}
@@ -0,0 +1,98 @@
+#[cfg(feature = "business")]
+use date_utils::{business_day_ticks, HolidayCalendar, Weekend};
+#[cfg(feature = "rand")]
+use date_utils::{jittered_ticks, Rng};
+use date_utils::{ticks, DateTimeError};
+use time::{macros::datetime, Duration};
+
+#[test]
+fn test_ticks() {
+    let start = datetime!(2024-05-31 23:00 +2);
+    assert_eq!(
+        ticks(start, Duration::hours(12), 3).unwrap(),
+        [
+            start,
+            datetime!(2024-06-01 11:00 +2),
+            datetime!(2024-06-01 23:00 +2)
+        ]
+    );
+    assert!(ticks(start, Duration::HOUR, 0).unwrap().is_empty());
+    // The last tick is the last datetime of the supported range.
+    let end = datetime!(9999-12-31 23:59:59.999999999 UTC);
+    assert_eq!(ticks(end, Duration::HOUR, 1).unwrap(), [end]);
+    assert!(matches!(
+        ticks(end, Duration::HOUR, 2).unwrap_err(),
+        DateTimeError::OutOfRange(_)
+    ));
+    for step in [Duration::ZERO, -Duration::HOUR] {
+        assert!(matches!(
+            ticks(start, step, 3).unwrap_err(),
+            DateTimeError::InvalidComponent("step", _)
+        ));
+    }
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn test_jittered_ticks() {
+    let start = datetime!(2024-05-31 9:00 UTC);
+    let jitter = Duration::seconds(30);
+    let axis = jittered_ticks(&mut Rng::new(5), start, Duration::MINUTE, jitter, 100).unwrap();
+    for (tick, base) in axis
+        .iter()
+        .zip(ticks(start, Duration::MINUTE, 100).unwrap())
+    {
+        assert!(base <= *tick && *tick < base + jitter, "{tick}");
+    }
+    assert!(axis.iter().any(|tick| tick.second() != 0));
+    assert_eq!(
+        jittered_ticks(&mut Rng::new(5), start, Duration::MINUTE, jitter, 100).unwrap(),
+        axis
+    );
+    assert_eq!(
+        jittered_ticks(&mut Rng::new(5), start, Duration::MINUTE, Duration::ZERO, 2).unwrap(),
+        [start, start + Duration::MINUTE]
+    );
+    for jitter in [Duration::MINUTE, -Duration::SECOND] {
+        assert!(matches!(
+            jittered_ticks(&mut Rng::new(5), start, Duration::MINUTE, jitter, 2).unwrap_err(),
+            DateTimeError::InvalidComponent("jitter", _)
+        ));
+    }
+}
+
+#[cfg(feature = "business")]
+#[test]
+fn test_business_day_ticks() {
+    let calendar: HolidayCalendar = [datetime!(2024-06-04 0:00 UTC).date()]
+        .into_iter()
+        .collect();
+    let axis = business_day_ticks(
+        datetime!(2024-05-31 18:00 UTC),
+        Duration::hours(12),
+        4,
+        &Weekend::default(),
+        &calendar,
+    )
+    .unwrap();
+    assert_eq!(
+        axis,
+        [
+            datetime!(2024-05-31 18:00 UTC),
+            datetime!(2024-06-03 6:00 UTC),
+            datetime!(2024-06-03 18:00 UTC),
+            datetime!(2024-06-05 6:00 UTC),
+        ]
+    );
+    assert!(matches!(
+        business_day_ticks(
+            datetime!(9999-12-04 0:00 UTC),
+            Duration::WEEK,
+            2,
+            &Weekend::default(),
+            &calendar
+        )
+        .unwrap_err(),
+        DateTimeError::OutOfRange(_)
+    ));
+}
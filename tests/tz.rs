@@ -0,0 +1,129 @@
+#![cfg(feature = "tz")]
+
+use date_utils::{
+    local_date_in_tz, weekday_in_tz, with_same_instant, with_same_wall_clock, DateTimeError,
+};
+use time::{
+    macros::{date, datetime, offset},
+    Weekday,
+};
+
+#[test]
+fn test_with_same_wall_clock() {
+    let datetime = datetime!(2024-05-31 23:30 +2);
+    let moved = with_same_wall_clock(datetime, offset!(-5));
+    assert_eq!(moved, datetime!(2024-05-31 23:30 -5));
+    assert_eq!(moved.time(), datetime.time());
+    assert_ne!(moved, datetime);
+}
+
+#[test]
+fn test_with_same_instant() {
+    let datetime = datetime!(2024-05-31 23:30 +2);
+    let moved = with_same_instant(datetime, offset!(-5)).unwrap();
+    assert_eq!(moved, datetime);
+    assert_eq!(moved.date(), datetime!(2024-05-31 16:30 -5).date());
+    assert_eq!(moved.offset(), offset!(-5));
+    assert!(matches!(
+        with_same_instant(datetime!(9999-12-31 23:00 UTC), offset!(+2)).unwrap_err(),
+        DateTimeError::OutOfRange(_)
+    ));
+}
+
+#[test]
+fn test_local_date_in_tz() {
+    let datetime = datetime!(2024-12-31 23:30 -5);
+    assert_eq!(
+        local_date_in_tz(datetime, offset!(-5)).unwrap(),
+        date!(2024 - 12 - 31)
+    );
+    assert_eq!(
+        local_date_in_tz(datetime, offset!(UTC)).unwrap(),
+        date!(2025 - 01 - 01)
+    );
+    assert_eq!(
+        local_date_in_tz(datetime, offset!(+9)).unwrap(),
+        date!(2025 - 01 - 01)
+    );
+    assert_eq!(
+        local_date_in_tz(datetime!(2025-01-01 0:30 +1), offset!(UTC)).unwrap(),
+        date!(2024 - 12 - 31)
+    );
+}
+
+#[test]
+fn test_weekday_in_tz() {
+    let datetime = datetime!(2024-05-31 16:00 UTC);
+    assert_eq!(
+        weekday_in_tz(datetime, offset!(-10)).unwrap(),
+        Weekday::Friday
+    );
+    assert_eq!(
+        weekday_in_tz(datetime, offset!(+8)).unwrap(),
+        Weekday::Saturday
+    );
+    assert_eq!(
+        weekday_in_tz(datetime!(2024-06-03 1:00 +2), offset!(-4)).unwrap(),
+        Weekday::Sunday
+    );
+}
+
+#[test]
+fn test_local_date_in_tz_out_of_range() {
+    for (datetime, tz) in [
+        (datetime!(9999-12-31 23:00 UTC), offset!(+2)),
+        (datetime!(-9999-01-01 0:30 UTC), offset!(-1)),
+    ] {
+        assert!(matches!(
+            local_date_in_tz(datetime, tz).unwrap_err(),
+            DateTimeError::OutOfRange(_)
+        ));
+        assert!(matches!(
+            weekday_in_tz(datetime, tz).unwrap_err(),
+            DateTimeError::OutOfRange(_)
+        ));
+    }
+}
+
+#[cfg(feature = "business")]
+#[test]
+fn test_is_business_day_in_tz() {
+    use date_utils::{is_business_day_in_tz, HolidayCalendar, Weekend};
+
+    let calendar: HolidayCalendar = [date!(2024 - 06 - 03)].into_iter().collect();
+    let weekend = Weekend::default();
+    let datetime = datetime!(2024-06-02 23:00 UTC);
+    assert!(!is_business_day_in_tz(datetime, offset!(UTC), &weekend, &calendar).unwrap());
+    assert!(!is_business_day_in_tz(datetime, offset!(+2), &weekend, &calendar).unwrap());
+    assert!(is_business_day_in_tz(
+        datetime!(2024-06-04 1:00 +2),
+        offset!(+2),
+        &weekend,
+        &calendar
+    )
+    .unwrap());
+    assert!(!is_business_day_in_tz(
+        datetime!(2024-06-04 1:00 +2),
+        offset!(-4),
+        &weekend,
+        &calendar
+    )
+    .unwrap());
+    assert!(is_business_day_in_tz(
+        datetime!(2024-06-02 23:00 UTC),
+        offset!(+2),
+        &Weekend::FRIDAY_SATURDAY,
+        &HolidayCalendar::new()
+    )
+    .unwrap());
+    assert!(matches!(
+        is_business_day_in_tz(
+            datetime!(9999-12-31 23:00 UTC),
+            offset!(+2),
+            &weekend,
+            &calendar
+        )
+        .unwrap_err(),
+        DateTimeError::OutOfRange(_)
+    ));
+}
@@ -0,0 +1,50 @@
+use date_utils::{DateTimeError, Period, VintagePoint};
+use time::{macros::datetime, Month};
+
+#[test]
+fn test_vintage_point_parse() {
+    let point = VintagePoint::parse("2024-M03", " 2024-04-12 ").unwrap();
+    assert_eq!(point.reference_period, Period::Month(2024, Month::March));
+    assert_eq!(point.vintage, datetime!(2024-04-12 0:00 UTC));
+
+    let point = VintagePoint::parse("2024-Q1", "2024-05-30T10:00:00+02:00").unwrap();
+    assert_eq!(point.vintage, datetime!(2024-05-30 10:00 +2));
+    assert_eq!(point.vintage.offset().whole_hours(), 2);
+
+    assert!(matches!(
+        VintagePoint::parse("2024-Q1", "30/05/2024").unwrap_err(),
+        DateTimeError::InvalidDateTimeFormat(_)
+    ));
+    assert!(matches!(
+        VintagePoint::parse("2024Z1", "2024-05-30").unwrap_err(),
+        DateTimeError::InvalidDateFormat(_, _)
+    ));
+}
+
+#[test]
+fn test_vintage_point_ord() {
+    let rows = [
+        ("2024-Q1", "2024-07-30"),
+        ("2024-Q2", "2024-07-30"),
+        ("2024-Q1", "2024-04-30"),
+        ("2023-Q4", "2024-04-30T09:00:00Z"),
+    ];
+    let mut points: Vec<VintagePoint> = rows
+        .iter()
+        .map(|(period, publication)| VintagePoint::parse(period, publication).unwrap())
+        .collect();
+    points.sort();
+    let order: Vec<String> = points
+        .iter()
+        .map(|point| format!("{} {}", point.reference_period, point.vintage.date()))
+        .collect();
+    assert_eq!(
+        order,
+        [
+            "2024-Q1 2024-04-30",
+            "2023-Q4 2024-04-30",
+            "2024-Q1 2024-07-30",
+            "2024-Q2 2024-07-30"
+        ]
+    );
+}
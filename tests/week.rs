@@ -0,0 +1,70 @@
+use date_utils::{parse_week_label, week_label, DateTimeError, LabelStyle, WeekRule};
+use time::{macros::date, Weekday};
+
+#[test]
+fn test_week_rule() {
+    assert_eq!(WeekRule::default(), WeekRule::MONDAY);
+    assert_eq!(WeekRule::SUNDAY.last_day(), Weekday::Saturday);
+    let rule = WeekRule::starting_on(Weekday::Saturday);
+    assert_eq!(
+        rule.week_of(date!(2024 - 05 - 31)).unwrap(),
+        (date!(2024 - 05 - 25), date!(2024 - 05 - 31))
+    );
+    assert!(matches!(
+        WeekRule::MONDAY.week_of(date!(9999 - 12 - 31)).unwrap_err(),
+        DateTimeError::OutOfRange(_)
+    ));
+}
+
+#[test]
+fn test_week_label() {
+    let label = |date, rule, style| week_label(date, rule, style).unwrap();
+    assert_eq!(
+        label(
+            date!(2024 - 05 - 27),
+            WeekRule::MONDAY,
+            LabelStyle::Commencing
+        ),
+        "w/c 2024-05-27"
+    );
+    assert_eq!(
+        label(date!(2024 - 06 - 02), WeekRule::MONDAY, LabelStyle::Ending),
+        "w/e 2024-06-02"
+    );
+    assert_eq!(
+        label(
+            date!(2024 - 06 - 02),
+            WeekRule::SUNDAY,
+            LabelStyle::Commencing
+        ),
+        "w/c 2024-06-02"
+    );
+}
+
+#[test]
+fn test_parse_week_label() {
+    let week = (date!(2024 - 05 - 26), date!(2024 - 06 - 01));
+    for input in [
+        "w/c 2024-05-26",
+        "WC 2024-05-26",
+        " Week Commencing 2024-05-26 ",
+        "w.e. 2024-06-01",
+        "week ending 2024-06-01",
+    ] {
+        assert_eq!(parse_week_label(input, WeekRule::SUNDAY).unwrap(), week);
+    }
+    for style in [LabelStyle::Commencing, LabelStyle::Ending] {
+        let label = week_label(date!(2024 - 05 - 29), WeekRule::SUNDAY, style).unwrap();
+        assert_eq!(parse_week_label(&label, WeekRule::SUNDAY).unwrap(), week);
+    }
+    assert!(matches!(
+        parse_week_label("w/c 2024-05-26", WeekRule::MONDAY).unwrap_err(),
+        DateTimeError::InvalidComponent("weekday", _)
+    ));
+    for invalid in ["2024-05-26", "w/x 2024-05-26", "w/c 26/05/2024"] {
+        assert!(matches!(
+            parse_week_label(invalid, WeekRule::SUNDAY).unwrap_err(),
+            DateTimeError::InvalidDateFormat(_, _)
+        ));
+    }
+}
@@ -0,0 +1,84 @@
+use date_utils::{current_window, current_window_at, DateTimeError, FixedClock, WindowSpec};
+use time::{
+    macros::{datetime, offset},
+    Duration,
+};
+
+#[test]
+fn test_current_window_fixed() {
+    let window = current_window(
+        datetime!(2024-05-31 12:34:56 +2),
+        WindowSpec::Fixed(Duration::hours(1)),
+    )
+    .unwrap();
+    // Windows are aligned on the Unix epoch, whatever the offset of the instant.
+    assert_eq!(window.start, datetime!(2024-05-31 12:00 +2));
+    assert_eq!(window.end, datetime!(2024-05-31 13:00 +2));
+    assert_eq!(window.remaining, Duration::seconds(25 * 60 + 4));
+    let window = current_window(
+        datetime!(1969-12-31 23:59:59 UTC),
+        WindowSpec::Fixed(Duration::minutes(1)),
+    )
+    .unwrap();
+    assert_eq!(window.start, datetime!(1969-12-31 23:59 UTC));
+    // An instant on a boundary starts a window.
+    let window = current_window(
+        datetime!(2024-05-31 12:00 UTC),
+        WindowSpec::Fixed(Duration::minutes(30)),
+    )
+    .unwrap();
+    assert_eq!(window.start, datetime!(2024-05-31 12:00 UTC));
+    assert!(matches!(
+        current_window(
+            datetime!(2024-05-31 12:00 UTC),
+            WindowSpec::Fixed(Duration::ZERO)
+        )
+        .unwrap_err(),
+        DateTimeError::InvalidComponent("duration", _)
+    ));
+}
+
+#[test]
+fn test_current_window_calendar() {
+    let now = datetime!(2024-05-31 20:00 -5);
+    let day = current_window(now, WindowSpec::CalendarDay(offset!(UTC))).unwrap();
+    assert_eq!(day.start, datetime!(2024-06-01 0:00 UTC));
+    assert_eq!(day.remaining, Duration::hours(23));
+    let month = current_window(now, WindowSpec::CalendarMonth(offset!(-5))).unwrap();
+    assert_eq!(month.start, datetime!(2024-05-01 0:00 -5));
+    assert_eq!(month.end, datetime!(2024-06-01 0:00 -5));
+    assert_eq!(month.remaining, Duration::hours(4));
+    assert!(matches!(
+        current_window(
+            datetime!(9999-12-31 12:00 UTC),
+            WindowSpec::CalendarMonth(offset!(UTC))
+        )
+        .unwrap_err(),
+        DateTimeError::OutOfRange(_)
+    ));
+    for spec in [
+        WindowSpec::CalendarDay(offset!(+9)),
+        WindowSpec::CalendarMonth(offset!(+9)),
+    ] {
+        assert!(matches!(
+            current_window(datetime!(9999-12-31 23:00 UTC), spec).unwrap_err(),
+            DateTimeError::OutOfRange(_)
+        ));
+    }
+    assert!(matches!(
+        current_window(
+            datetime!(-9999-01-01 1:00 UTC),
+            WindowSpec::CalendarDay(offset!(-9))
+        )
+        .unwrap_err(),
+        DateTimeError::OutOfRange(_)
+    ));
+}
+
+#[test]
+fn test_current_window_at() {
+    let clock = FixedClock::new(datetime!(2024-02-29 12:00 UTC));
+    let window = current_window_at(WindowSpec::CalendarMonth(offset!(UTC)), &clock).unwrap();
+    assert_eq!(window.end, datetime!(2024-03-01 0:00 UTC));
+    assert_eq!(window.remaining, Duration::hours(12));
+}